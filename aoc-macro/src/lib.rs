@@ -11,7 +11,9 @@ use syn::parse_macro_input;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::ItemFn;
-use syn::{Expr, Ident, Path, Token};
+use syn::{DeriveInput, Expr, Ident, Path, Token};
+
+mod pattern;
 
 #[derive(Debug)]
 struct TestParams {
@@ -64,7 +66,6 @@ pub fn generator(_attr: TokenStream, input: TokenStream) -> TokenStream {
 
 mod kw {
     use syn::custom_keyword;
-    custom_keyword!(expect);
     custom_keyword!(example);
     custom_keyword!(example_input);
 }
@@ -74,7 +75,6 @@ struct SolutionParams {
     part: Ident,
     example_input: Expr,
     example: Expr,
-    expected: Option<Expr>,
 }
 
 impl Parse for SolutionParams {
@@ -88,19 +88,10 @@ impl Parse for SolutionParams {
         input.parse::<kw::example>()?;
         input.parse::<Token![=]>()?;
         let example = input.parse()?;
-        let expected = if input.peek2(kw::expect) {
-            input.parse::<Token![,]>()?;
-            input.parse::<kw::expect>()?;
-            input.parse::<Token![=]>()?;
-            Some(input.parse()?)
-        } else {
-            None
-        };
         Ok(SolutionParams {
             part,
             example_input,
             example,
-            expected,
         })
     }
 }
@@ -120,7 +111,6 @@ pub fn solution(attr: TokenStream, input: TokenStream) -> TokenStream {
         part,
         example_input,
         example,
-        expected,
     } = parse_macro_input!(attr as SolutionParams);
     if part == "part1" || part == "part2" {
         let example_ident = Ident::new(&format!("{}_example", part), part.span());
@@ -132,26 +122,44 @@ pub fn solution(attr: TokenStream, input: TokenStream) -> TokenStream {
                 assert_eq!(out, #example);
             }
         };
-        let r = match ret {
+        let r = match ret.clone() {
             syn::ReturnType::Default => quote!(-> Option<()>),
             syn::ReturnType::Type(arrow, ty) => quote!(#arrow Option<#ty>),
         };
-        let complete_func = if expected.is_none() {
-            quote! {
-                pub fn #complete_ident() #r {
-                    #example_ident();
-                    let out = #fn_name(generator(INPUT), #normal_arg);
-                    Some(out)
-                }
+        let complete_func = quote! {
+            pub fn #complete_ident() #r {
+                #example_ident();
+                let out = #fn_name(generator(INPUT), #normal_arg);
+                Some(out)
             }
-        } else {
-            quote! {
-                pub fn #complete_ident() #r {
-                    #example_ident();
-                    let out = #fn_name(generator(INPUT), #normal_arg);
-                    assert_eq!(out, #expected);
-                    Some(out)
-                }
+        };
+
+        // Parses the input up front, for the `aoc` runner to time only the
+        // solution itself.
+        let prepared_ident = Ident::new(&format!("{}_prepared", part), part.span());
+        let prepared_func = quote! {
+            pub fn #prepared_ident() -> impl FnOnce() #ret {
+                let data = generator(INPUT);
+                move || #fn_name(data, #normal_arg)
+            }
+        };
+
+        // Checks the answer against `answers.txt`, as `gen!` does for the
+        // other years.
+        let answer_ident = Ident::new(&format!("{}_answer", part), part.span());
+        let part_n = if part == "part1" { 1u8 } else { 2u8 };
+        let answer_func = quote! {
+            #[test]
+            fn #answer_ident() {
+                let f = file!();
+                let day = f.find("/day").unwrap();
+                let day = f[day + 4..day + 6].parse::<u8>().unwrap();
+                advent_of_code::answers::Answers::embedded().assert_embedded(
+                    #YEAR,
+                    day,
+                    #part_n,
+                    || advent_of_code::answers::debug_answer(&#complete_ident().unwrap()),
+                );
             }
         };
 
@@ -172,6 +180,8 @@ pub fn solution(attr: TokenStream, input: TokenStream) -> TokenStream {
             }
             #example_func
             #complete_func
+            #prepared_func
+            #answer_func
             #input
         };
 
@@ -185,13 +195,31 @@ pub fn solution(attr: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+/// Implements `FromStr` by matching a line against the format string in
+/// `#[pattern = "..."]`, on the type or on each variant of an enum; see the
+/// `pattern` module for the syntax.
+#[proc_macro_derive(Pattern, attributes(pattern))]
+pub fn derive_pattern(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match pattern::derive(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// The year of the solutions these macros are used for, as keyed in
+/// `answers.txt`.
+const YEAR: u16 = 2018;
+
 #[proc_macro]
 pub fn day(_: TokenStream) -> TokenStream {
     let res = quote! {
+        // Unused when the day is built into the `y2018` library via `days!`.
+        #[allow(dead_code)]
         fn main() {
             let f = file!();
             let day = f.find("/day").unwrap();
-            let day = f[day + 4..day + 6].parse::<usize>().unwrap();
+            let day = f[day + 4..day + 6].parse::<u8>().unwrap();
             let arg = std::env::args().nth(1);
             match arg.as_ref().map(|s| s.as_str()) {
                 Some("run") => {
@@ -200,11 +228,14 @@ pub fn day(_: TokenStream) -> TokenStream {
                     if args.len() == 2 {
                         args.push("both".into());
                     }
+                    let answers = advent_of_code::answers::Answers::embedded();
                     for arg in args {
                         if arg.contains("part1") || arg.contains("both") {
                             match part1_complete() {
                                 Some(x) => {
-                                    eprintln!("Day {} Part 1: {:?}", day, x);
+                                    let x = advent_of_code::answers::debug_answer(&x);
+                                    let verdict = answers.check(#YEAR, day, 1, None, &x);
+                                    eprintln!("Day {} Part 1: {} [{}]", day, x, verdict);
                                 }
                                 None => {
                                     eprintln!("Day {} Part 1: Not yet implemented", day);
@@ -214,7 +245,9 @@ pub fn day(_: TokenStream) -> TokenStream {
                         if arg.contains("part2") || arg.contains("both") {
                             match part2_complete() {
                                 Some(x) => {
-                                    eprintln!("Day {} Part 2: {:?}", day, x);
+                                    let x = advent_of_code::answers::debug_answer(&x);
+                                    let verdict = answers.check(#YEAR, day, 2, None, &x);
+                                    eprintln!("Day {} Part 2: {} [{}]", day, x, verdict);
                                 }
                                 None => {
                                     eprintln!("Day {} Part 2: Not yet implemented", day);
@@ -238,6 +271,8 @@ pub fn day(_: TokenStream) -> TokenStream {
     TokenStream::from(res)
 }
 
+/// Declares the given day modules and a `solutions` function registering both
+/// parts of each with the `aoc` runner, with answers formatted by `Debug`.
 #[proc_macro]
 pub fn days(input: TokenStream) -> TokenStream {
     let parser = Punctuated::<Path, Token![,]>::parse_terminated;
@@ -248,100 +283,52 @@ pub fn days(input: TokenStream) -> TokenStream {
         .iter()
         .map(|path| {
             let module = path.segments.first().unwrap().value().ident.clone();
-            quote_spanned!(module.span()=> mod #module;)
+            quote_spanned!(module.span()=> pub mod #module;)
         })
         .collect::<TokenStream2>();
 
-    let run_parts = paths
+    let register_parts = paths
         .clone()
         .iter()
         .map(|path| {
+            if path.segments.len() != 1 {
+                path.span()
+                    .unstable()
+                    .error("only one segments allowed")
+                    .emit();
+                return TokenStream2::new();
+            }
             let day_name = path.segments.first().unwrap().value().ident.clone();
             let day_n = day_name.to_string()[3..].parse::<u8>().unwrap();
-            let body = match path.segments.len() {
-                1 => quote! {
-                    let args = std::env::args().collect::<Vec<_>>();
-                    for window in args.windows(2) {
-                        let arg1 = &window[0];
-                        let arg2 = &window[1];
-                        if arg1.contains(stringify!(#day_name)) {
-                            if arg2.contains("part1") || arg2.contains("both") {
-                                match #path::part1_complete() {
-                                    Some(x) => {
-                                        eprintln!("Day {} Part 1: {:?}", #day_n, x);
-                                    }
-                                    None => {
-                                        eprintln!("Day {} Part 1: Not yet implemented", #day_n);
-                                    }
-                                }
-                            }
-                            if arg2.contains("part2") || arg2.contains("both") {
-                                match #path::part2_complete() {
-                                    Some(x) => {
-                                        eprintln!("Day {} Part 2: {:?}", #day_n, x);
-                                    }
-                                    None => {
-                                        eprintln!("Day {} Part 2: Not yet implemented", #day_n);
-                                    }
-                                }
-                            }
-                        }
+            let parts = [(1u8, "part1_prepared"), (2u8, "part2_prepared")];
+            parts
+                .iter()
+                .map(|&(part, prepared)| {
+                    let prepared = Ident::new(prepared, day_name.span());
+                    quote! {
+                        solutions.push(advent_of_code::runner::Solution {
+                            year: #YEAR,
+                            day: #day_n,
+                            part: #part,
+                            load: Box::new(|_| Ok(Box::new(|| {
+                                let run = #day_name::#prepared();
+                                Box::new(move || advent_of_code::answers::debug_answer(&run()))
+                                    as advent_of_code::runner::Answer
+                            }))),
+                        });
                     }
-                },
-                _ => {
-                    path.span()
-                        .unstable()
-                        .error("only one segments allowed")
-                        .emit();
-                    unimplemented!()
-                }
-            };
-            quote!(fn #day_name() { #body })
-        })
-        .collect::<TokenStream2>();
-
-    let call_days = paths
-        .clone()
-        .iter()
-        .map(|path| {
-            let ident = path.segments.first().unwrap().value().ident.clone();
-            quote! {
-                #ident();
-            }
-        })
-        .collect::<TokenStream2>();
-
-    let bench_days = paths
-        .clone()
-        .iter()
-        .map(|path| {
-            let ident = path.segments.first().unwrap().value().ident.clone();
-            quote! {
-                #ident::bench_gen(&format!("{}::generator", stringify!(#ident)), &mut c);
-                #ident::part1_bench_soln(&format!("{}::part1", stringify!(#ident)), &mut c);
-                #ident::part2_bench_soln(&format!("{}::part2", stringify!(#ident)), &mut c);
-            }
+                })
+                .collect::<TokenStream2>()
         })
         .collect::<TokenStream2>();
 
     let res = quote! {
         #modules
 
-        #run_parts
-
-        fn main() {
-            let arg = std::env::args().nth(1);
-            match arg.as_ref().map(|s| s.as_str()) {
-                Some("run") => {
-                    #call_days
-                }
-                Some(_) | None => {
-                    criterion::init_logging();
-                    let mut c = criterion::Criterion::default()
-                        .configure_from_args();
-                    #bench_days
-                }
-            }
+        pub fn solutions() -> Vec<advent_of_code::runner::Solution> {
+            let mut solutions = Vec::new();
+            #register_parts
+            solutions
         }
     };
 
@@ -0,0 +1,244 @@
+//! `#[derive(Pattern)]`: a `FromStr` implementation from a format string
+//! such as `"pos=<{x},{y},{z}>, r={r}"`.
+//!
+//! Each `{field}` is read with `Parser::field` up to the first byte of the
+//! text after it, so fields must be separated by some text. `{_}` skips a
+//! field, and `{{` and `}}` stand for braces. Whitespace in the pattern
+//! matches any amount of whitespace. Enums are parsed with the pattern of
+//! the first variant that matches.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, Lit, LitStr, Meta, Result};
+
+#[derive(Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Field(String),
+}
+
+fn segments(pattern: &str) -> std::result::Result<Vec<Segment>, String> {
+    let mut out = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("unclosed `{{` in {:?}", pattern)),
+                    }
+                }
+                let name = name.trim();
+                if name.is_empty() {
+                    return Err(format!("empty field in {:?}", pattern));
+                }
+                if !literal.is_empty() {
+                    out.push(Segment::Literal(literal.split_off(0)));
+                } else if let Some(Segment::Field(_)) = out.last() {
+                    return Err(format!("no text between fields before {{{}}}", name));
+                }
+                out.push(Segment::Field(name.to_string()));
+            }
+            '}' => return Err(format!("unmatched `}}` in {:?}", pattern)),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        out.push(Segment::Literal(literal));
+    }
+    Ok(out)
+}
+
+fn pattern_attr(attrs: &[Attribute], span: Span) -> Result<LitStr> {
+    for attr in attrs {
+        if !attr.path.is_ident("pattern") {
+            continue;
+        }
+        return match attr.parse_meta()? {
+            Meta::NameValue(ref meta) => match meta.lit {
+                Lit::Str(ref s) => Ok(s.clone()),
+                ref lit => Err(Error::new(lit.span(), "expected a string")),
+            },
+            meta => Err(Error::new(meta.span(), "expected #[pattern = \"...\"]")),
+        };
+    }
+    Err(Error::new(span, "missing #[pattern = \"...\"]"))
+}
+
+/// Statements that parse `pattern` with `p` and evaluate to `constructor`
+/// filled in with `fields`.
+fn parse_fields(
+    pattern: &LitStr,
+    constructor: TokenStream,
+    fields: &Fields,
+) -> Result<TokenStream> {
+    let segments = segments(&pattern.value()).map_err(|e| Error::new(pattern.span(), e))?;
+    let names = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match field.ident {
+            Some(ref ident) => ident.to_string(),
+            None => i.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let binding = |i: usize| Ident::new(&format!("__field{}", i), Span::call_site());
+    let mut seen = vec![false; names.len()];
+    let mut statements = TokenStream::new();
+    for (i, segment) in segments.iter().enumerate() {
+        match *segment {
+            Segment::Literal(ref text) => {
+                let mut rest = text.as_str();
+                while !rest.is_empty() {
+                    let space = rest.starts_with(char::is_whitespace);
+                    let len = rest
+                        .find(|c: char| c.is_whitespace() != space)
+                        .unwrap_or(rest.len());
+                    let (run, tail) = rest.split_at(len);
+                    statements.extend(if space {
+                        quote!(p.skip_whitespace();)
+                    } else {
+                        quote!(p.literal(#run)?;)
+                    });
+                    rest = tail;
+                }
+            }
+            Segment::Field(ref name) => {
+                let stop = match segments.get(i + 1) {
+                    Some(Segment::Literal(text)) => {
+                        let byte = text.as_bytes()[0];
+                        quote!(Some(#byte))
+                    }
+                    _ => quote!(None),
+                };
+                if name == "_" {
+                    statements.extend(quote!(p.field_text(#stop)?;));
+                    continue;
+                }
+                let index = match names.iter().position(|n| n == name) {
+                    Some(index) => index,
+                    None => {
+                        let msg = format!("no field named `{}`", name);
+                        return Err(Error::new(pattern.span(), msg));
+                    }
+                };
+                if seen[index] {
+                    let msg = format!("`{}` appears more than once", name);
+                    return Err(Error::new(pattern.span(), msg));
+                }
+                seen[index] = true;
+                let binding = binding(index);
+                statements.extend(quote!(let #binding = p.field(#stop)?;));
+            }
+        }
+    }
+    if let Some(index) = seen.iter().position(|&seen| !seen) {
+        let msg = format!("`{}` is not in the pattern", names[index]);
+        return Err(Error::new(pattern.span(), msg));
+    }
+    let value = match *fields {
+        Fields::Named(_) => {
+            let fields = fields.iter().enumerate().map(|(i, field)| {
+                let name = &field.ident;
+                let binding = binding(i);
+                quote!(#name: #binding)
+            });
+            quote!(#constructor { #(#fields),* })
+        }
+        Fields::Unnamed(_) => {
+            let bindings = (0..names.len()).map(binding);
+            quote!(#constructor(#(#bindings),*))
+        }
+        Fields::Unit => constructor,
+    };
+    Ok(quote! {
+        #statements
+        #value
+    })
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let body = match input.data {
+        Data::Struct(ref data) => {
+            let pattern = pattern_attr(&input.attrs, name.span())?;
+            let value = parse_fields(&pattern, quote!(#name), &data.fields)?;
+            quote!({ #value })
+        }
+        Data::Enum(ref data) => {
+            let mut variants = Vec::new();
+            for variant in &data.variants {
+                let pattern = pattern_attr(&variant.attrs, variant.ident.span())?;
+                let ident = &variant.ident;
+                variants.push(parse_fields(
+                    &pattern,
+                    quote!(#name::#ident),
+                    &variant.fields,
+                )?);
+            }
+            // The parser passed to each closure gets its type from `alt`.
+            let mut closures = variants.iter().map(|value| quote!(|p| Ok({ #value })));
+            match (closures.next(), closures.next_back()) {
+                (None, _) => return Err(Error::new(name.span(), "no variants to parse")),
+                (Some(_), None) => {
+                    let value = &variants[0];
+                    quote!({ #value })
+                }
+                (Some(first), Some(last)) => {
+                    let rest = closures
+                        .rev()
+                        .fold(last, |rest, variant| quote!(|p| p.alt(#variant, #rest)));
+                    quote!(p.alt(#first, #rest)?)
+                }
+            }
+        }
+        Data::Union(_) => return Err(Error::new(name.span(), "unions are not supported")),
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::advent_of_code::ParserError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                let mut parser = ::advent_of_code::Parser::new(s.as_bytes());
+                let p = &mut parser;
+                p.skip_whitespace();
+                let value = #body;
+                p.skip_whitespace();
+                p.end()?;
+                Ok(value)
+            }
+        }
+    })
+}
+
+#[test]
+fn pattern_segments() {
+    use self::Segment::*;
+    assert_eq!(
+        segments("pos=<{x},{ y }>, {{r}}={_}"),
+        Ok(vec![
+            Literal("pos=<".into()),
+            Field("x".into()),
+            Literal(",".into()),
+            Field("y".into()),
+            Literal(">, {r}=".into()),
+            Field("_".into()),
+        ])
+    );
+    assert!(segments("{x}{y}").is_err());
+    assert!(segments("{x").is_err());
+    assert!(segments("x}").is_err());
+}
@@ -0,0 +1,55 @@
+#![feature(test)]
+
+#[macro_use]
+extern crate advent_of_code;
+extern crate fnv;
+extern crate itertools;
+extern crate itoa;
+extern crate md5;
+extern crate memchr;
+extern crate petgraph;
+extern crate primal;
+#[macro_use]
+extern crate quickcheck;
+extern crate smallvec;
+extern crate test;
+extern crate y2018;
+
+use advent_of_code::runner::{self, Excluded};
+
+#[path = "../../src/bin/y2016"]
+mod y2016 {
+    solutions!(2016;
+        day01, day02, day03, day04, day05, day09, day10, day11, day12, day13, day14, day15, day16,
+        day17, day18, day19, day20, day21, day22, day23, day24, day25,
+    );
+}
+
+/// Day 15 is left out: it needs `packed_simd`, so it stays the
+/// `y2017-day15` binary.
+#[path = "../../src/bin/y2017"]
+mod y2017 {
+    solutions!(2017;
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+        day14, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+    );
+}
+
+fn main() {
+    let mut solutions = y2016::solutions();
+    solutions.extend(y2017::solutions());
+    solutions.extend(y2018::days::solutions());
+    let excluded = [
+        Excluded {
+            year: 2017,
+            day: 15,
+            reason: "it needs packed_simd; run the y2017-day15 binary",
+        },
+        Excluded {
+            year: 2018,
+            day: 10,
+            reason: "it has no solution, as the answer is read off a picture",
+        },
+    ];
+    runner::main(solutions, &excluded);
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+use std::hash::Hasher;
+
+use fnv::FnvHasher;
+
+/// The answers recorded in `answers.txt` at the root of the repository.
+const ANSWERS: &str = include_str!("../answers.txt");
+
+#[derive(Fail, Debug, PartialEq)]
+#[fail(display = "answers.txt:{}: {}", _0, _1)]
+pub struct AnswersError(usize, String);
+
+#[derive(Debug, PartialEq)]
+struct Entry {
+    year: u16,
+    day: u8,
+    part: u8,
+    fingerprint: Option<u64>,
+    answer: String,
+}
+
+/// Known answers, keyed by year, day, part and input.
+///
+/// Each line reads `YEAR DAY PART [FINGERPRINT] = ANSWER`; blank lines and
+/// lines starting with `#` are ignored. An answer without a fingerprint is
+/// for the input embedded in the repository, and one with a fingerprint is
+/// for the input loaded at runtime that has it.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: Vec<Entry>,
+}
+
+impl Answers {
+    /// The answers file built into the crate.
+    pub fn embedded() -> Answers {
+        Answers::parse(ANSWERS).unwrap()
+    }
+
+    pub fn parse(s: &str) -> Result<Answers, AnswersError> {
+        let mut entries = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = |msg: &str| AnswersError(i + 1, format!("{}: {:?}", msg, line));
+            let eq = line.find(" = ").ok_or_else(|| err("expected ` = `"))?;
+            let answer = line[eq + 3..].trim();
+            let key = line[..eq].split_whitespace().collect::<Vec<_>>();
+            if key.len() != 3 && key.len() != 4 {
+                return Err(err("expected YEAR DAY PART [FINGERPRINT]"));
+            }
+            let entry = Entry {
+                year: key[0].parse().map_err(|_| err("invalid year"))?,
+                day: key[1].parse().map_err(|_| err("invalid day"))?,
+                part: key[2].parse().map_err(|_| err("invalid part"))?,
+                fingerprint: match key.get(3) {
+                    Some(f) => {
+                        Some(u64::from_str_radix(f, 16).map_err(|_| err("invalid fingerprint"))?)
+                    }
+                    None => None,
+                },
+                answer: answer.to_string(),
+            };
+            if entries.iter().any(|e: &Entry| {
+                (e.year, e.day, e.part, e.fingerprint)
+                    == (entry.year, entry.day, entry.part, entry.fingerprint)
+            }) {
+                return Err(err("duplicate answer"));
+            }
+            entries.push(entry);
+        }
+        Ok(Answers { entries })
+    }
+
+    /// The answer for the given part, for the embedded input if `fingerprint`
+    /// is `None`.
+    pub fn get(&self, year: u16, day: u8, part: u8, fingerprint: Option<u64>) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| (e.year, e.day, e.part, e.fingerprint) == (year, day, part, fingerprint))
+            .map(|e| e.answer.as_str())
+    }
+
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        fingerprint: Option<u64>,
+        answer: &str,
+    ) -> Verdict {
+        match self.get(year, day, part, fingerprint) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Panics if the answer for the embedded input is known and `answer`
+    /// differs; `answer` is only computed if it is known.
+    pub fn assert_embedded<F: FnOnce() -> String>(&self, year: u16, day: u8, part: u8, answer: F) {
+        if let Some(expected) = self.get(year, day, part, None) {
+            assert_eq!(answer(), expected, "{} day {} part {}", year, day, part);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// Holds the expected answer.
+    Wrong(String),
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(ref expected) => write!(f, "wrong, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Identifies a puzzle input, ignoring surrounding whitespace.
+pub fn fingerprint(input: &str) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(input.trim().as_bytes());
+    hasher.finish()
+}
+
+/// Formats an answer by `Debug`, without the quotes around strings, as the
+/// 2018 answers are recorded.
+pub fn debug_answer<T: fmt::Debug>(answer: &T) -> String {
+    let s = format!("{:?}", answer);
+    if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+        s[1..s.len() - 1].to_string()
+    } else {
+        s
+    }
+}
+
+#[test]
+fn answers_embedded_parse() {
+    assert!(Answers::parse(ANSWERS).is_ok());
+}
+
+#[test]
+fn answers_lookup() {
+    let answers = Answers::parse(
+        "# comment\n\n2016 1 1 = 241\n2016 1 1 00000000000000ff = 12\n2018 11 1 = (33, 54)\n",
+    )
+    .unwrap();
+    assert_eq!(answers.get(2016, 1, 1, None), Some("241"));
+    assert_eq!(answers.get(2016, 1, 1, Some(0xff)), Some("12"));
+    assert_eq!(answers.get(2016, 1, 1, Some(1)), None);
+    assert_eq!(answers.get(2016, 1, 2, None), None);
+    assert_eq!(
+        answers.check(2018, 11, 1, None, "(33, 54)"),
+        Verdict::Correct
+    );
+    assert_eq!(
+        answers.check(2016, 1, 1, None, "240"),
+        Verdict::Wrong(String::from("241"))
+    );
+    assert_eq!(answers.check(2017, 1, 1, None, "1"), Verdict::Unknown);
+}
+
+#[test]
+fn answers_errors() {
+    assert_eq!(Answers::parse("2016 1 = 5").unwrap_err().0, 1);
+    assert_eq!(Answers::parse("\n2016 1 1 5").unwrap_err().0, 2);
+    assert!(Answers::parse("2016 1 1 = 5\n2016 1 1 = 6").is_err());
+}
+
+#[test]
+fn answers_debug_answer() {
+    assert_eq!(debug_answer(&"abc"), "abc");
+    assert_eq!(debug_answer(&(33, 54)), "(33, 54)");
+}
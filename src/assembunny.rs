@@ -0,0 +1,386 @@
+//! The assembunny language of 2016 days 12, 23 and 25.
+//!
+//! Loops that add one register into another, alone or nested to multiply,
+//! run in a single step when their counters are positive, so programs built
+//! around them (day 23 computing a factorial) finish quickly.
+
+use std::str::FromStr;
+
+use {parse_lines, Pattern};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl FromStr for Register {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Register, String> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            "c" => Ok(Register::C),
+            "d" => Ok(Register::D),
+            _ => Err(format!("expected a register, found {:?}", s)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Value {
+    Register(Register),
+    Constant(i64),
+}
+
+impl FromStr for Value {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Value, String> {
+        match s.parse::<i64>() {
+            Ok(value) => Ok(Value::Constant(value)),
+            Err(_) => s.parse().map(Value::Register),
+        }
+    }
+}
+
+/// An instruction; `tgl` can make any operand a constant, which makes the
+/// instruction do nothing when it would write to it.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Pattern)]
+pub enum Instruction {
+    #[pattern = "cpy {0} {1}"]
+    Copy(Value, Value),
+    #[pattern = "jnz {0} {1}"]
+    Jump(Value, Value),
+    #[pattern = "inc {0}"]
+    Increment(Value),
+    #[pattern = "dec {0}"]
+    Decrement(Value),
+    #[pattern = "tgl {0}"]
+    Toggle(Value),
+    #[pattern = "out {0}"]
+    Out(Value),
+}
+
+impl Instruction {
+    /// The instruction `tgl` turns this one into.
+    pub fn toggled(self) -> Instruction {
+        match self {
+            Instruction::Increment(v) => Instruction::Decrement(v),
+            Instruction::Decrement(v) | Instruction::Toggle(v) | Instruction::Out(v) => {
+                Instruction::Increment(v)
+            }
+            Instruction::Jump(a, b) => Instruction::Copy(a, b),
+            Instruction::Copy(a, b) => Instruction::Jump(a, b),
+        }
+    }
+}
+
+pub fn parse(s: &str) -> Vec<Instruction> {
+    parse_lines(s).unwrap_or_else(|e| panic!("{}", e.render(s)))
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct Memory {
+    pub a: i64,
+    pub b: i64,
+    pub c: i64,
+    pub d: i64,
+}
+
+impl Memory {
+    pub fn resolve(&self, value: Value) -> i64 {
+        match value {
+            Value::Constant(v) => v,
+            Value::Register(Register::A) => self.a,
+            Value::Register(Register::B) => self.b,
+            Value::Register(Register::C) => self.c,
+            Value::Register(Register::D) => self.d,
+        }
+    }
+
+    pub fn get_mut(&mut self, to: Register) -> &mut i64 {
+        match to {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+            Register::C => &mut self.c,
+            Register::D => &mut self.d,
+        }
+    }
+}
+
+/// A loop the VM runs in one step.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Idiom {
+    /// `inc x; dec y; jnz y -2`, in either order: `x += y; y = 0`.
+    Add { x: Register, y: Register },
+    /// `cpy src y; <Add x y>; dec z; jnz z -5`: `x += src * z; y = 0; z = 0`.
+    Multiply {
+        src: Value,
+        x: Register,
+        y: Register,
+        z: Register,
+    },
+}
+
+impl Idiom {
+    fn find(program: &[Instruction], at: usize) -> Option<Idiom> {
+        use self::Instruction::*;
+        use self::Value::Register as R;
+
+        let w = program.get(at..)?;
+        match w {
+            [Increment(R(x)), Decrement(R(y)), Jump(R(y2), Value::Constant(-2)), ..]
+            | [Decrement(R(y)), Increment(R(x)), Jump(R(y2), Value::Constant(-2)), ..]
+                if y == y2 && x != y =>
+            {
+                Some(Idiom::Add { x: *x, y: *y })
+            }
+            [Copy(src, R(y)), _, _, _, Decrement(R(z)), Jump(R(z2), Value::Constant(-5)), ..]
+                if z == z2 =>
+            {
+                match Idiom::find(program, at + 1)? {
+                    Idiom::Add { x, y: y2 } if y2 == *y && x != *z && y2 != *z => {
+                        let distinct = |r| r != x && r != *y && r != *z;
+                        match *src {
+                            R(r) if !distinct(r) => None,
+                            src => Some(Idiom::Multiply {
+                                src,
+                                x,
+                                y: *y,
+                                z: *z,
+                            }),
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Runs the loop, returning how many instructions it spans, unless its
+    /// counters are not positive and it would not end normally.
+    fn run(self, memory: &mut Memory) -> Option<i64> {
+        match self {
+            Idiom::Add { x, y } => {
+                let count = *memory.get_mut(y);
+                if count <= 0 {
+                    return None;
+                }
+                *memory.get_mut(x) += count;
+                *memory.get_mut(y) = 0;
+                Some(3)
+            }
+            Idiom::Multiply { src, x, y, z } => {
+                let (inner, outer) = (memory.resolve(src), *memory.get_mut(z));
+                if inner <= 0 || outer <= 0 {
+                    return None;
+                }
+                *memory.get_mut(x) += inner * outer;
+                *memory.get_mut(y) = 0;
+                *memory.get_mut(z) = 0;
+                Some(6)
+            }
+        }
+    }
+}
+
+/// Why `Vm::resume` returned.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Event {
+    Output(i64),
+    Halted,
+}
+
+#[derive(Clone, Debug)]
+pub struct Vm {
+    pub memory: Memory,
+    pub ip: i64,
+    program: Vec<Instruction>,
+    idioms: Vec<Option<Idiom>>,
+}
+
+impl Vm {
+    pub fn new(program: Vec<Instruction>) -> Vm {
+        let mut vm = Vm {
+            memory: Memory::default(),
+            ip: 0,
+            program,
+            idioms: Vec::new(),
+        };
+        vm.find_idioms();
+        vm
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    fn find_idioms(&mut self) {
+        self.idioms = (0..self.program.len())
+            .map(|at| Idiom::find(&self.program, at))
+            .collect();
+    }
+
+    /// Runs until the next `out` or the end of the program.
+    pub fn resume(&mut self) -> Event {
+        while self.ip >= 0 && (self.ip as usize) < self.program.len() {
+            let at = self.ip as usize;
+            if let Some(len) = self.idioms[at].and_then(|i| i.run(&mut self.memory)) {
+                self.ip += len;
+                continue;
+            }
+            let mut next = self.ip + 1;
+            match self.program[at] {
+                Instruction::Copy(value, Value::Register(to)) => {
+                    *self.memory.get_mut(to) = self.memory.resolve(value)
+                }
+                Instruction::Increment(Value::Register(r)) => *self.memory.get_mut(r) += 1,
+                Instruction::Decrement(Value::Register(r)) => *self.memory.get_mut(r) -= 1,
+                Instruction::Jump(value, offset) => {
+                    if self.memory.resolve(value) != 0 {
+                        next = self.ip + self.memory.resolve(offset);
+                    }
+                }
+                Instruction::Toggle(offset) => {
+                    let target = self.ip + self.memory.resolve(offset);
+                    if target >= 0 && (target as usize) < self.program.len() {
+                        let target = target as usize;
+                        self.program[target] = self.program[target].toggled();
+                        self.find_idioms();
+                    }
+                }
+                Instruction::Out(value) => {
+                    self.ip = next;
+                    return Event::Output(self.memory.resolve(value));
+                }
+                Instruction::Copy(..) | Instruction::Increment(_) | Instruction::Decrement(_) => {}
+            }
+            self.ip = next;
+        }
+        Event::Halted
+    }
+
+    /// Runs to the end of the program, ignoring its output.
+    pub fn run(&mut self) -> Memory {
+        while self.resume() != Event::Halted {}
+        self.memory
+    }
+
+    /// The values the program outputs; take a bounded number of them from
+    /// programs that never halt.
+    pub fn outputs(&mut self) -> Outputs {
+        Outputs { vm: self }
+    }
+}
+
+pub struct Outputs<'a> {
+    vm: &'a mut Vm,
+}
+
+impl<'a> Iterator for Outputs<'a> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        match self.vm.resume() {
+            Event::Output(v) => Some(v),
+            Event::Halted => None,
+        }
+    }
+}
+
+#[test]
+fn assembunny_run() {
+    let program = parse(
+        "cpy 41 a
+        inc a
+        inc a
+        dec a
+        jnz a 2
+        dec a",
+    );
+    assert_eq!(Vm::new(program).run().a, 42);
+}
+
+#[test]
+fn assembunny_parse() {
+    assert_eq!(
+        parse("jnz 1 -2\nout c"),
+        [
+            Instruction::Jump(Value::Constant(1), Value::Constant(-2)),
+            Instruction::Out(Value::Register(Register::C)),
+        ]
+    );
+    let s = "inc a\ncpy 1 e\n";
+    let error = parse_lines::<Instruction>(s).unwrap_err();
+    assert_eq!(
+        error.render(s),
+        "line 2, column 7: invalid Value: expected a register, found \"e\"\ncpy 1 e\n      ^"
+    );
+}
+
+#[test]
+fn assembunny_toggle() {
+    let program = parse(
+        "cpy 2 a
+        tgl a
+        tgl a
+        tgl a
+        cpy 1 a
+        dec a
+        dec a",
+    );
+    assert_eq!(Vm::new(program).run().a, 3);
+}
+
+#[test]
+fn assembunny_idioms() {
+    let program = parse(
+        "cpy b c
+        inc a
+        dec c
+        jnz c -2
+        dec d
+        jnz d -5
+        out a
+        cpy 3 c
+        dec c
+        inc a
+        jnz c -2
+        out a",
+    );
+    let mut vm = Vm::new(program);
+    assert_eq!(
+        vm.idioms[..2],
+        [
+            Some(Idiom::Multiply {
+                src: Value::Register(Register::B),
+                x: Register::A,
+                y: Register::C,
+                z: Register::D,
+            }),
+            Some(Idiom::Add {
+                x: Register::A,
+                y: Register::C,
+            }),
+        ]
+    );
+    assert_eq!(
+        vm.idioms[8],
+        Some(Idiom::Add {
+            x: Register::A,
+            y: Register::C,
+        })
+    );
+    vm.memory.b = 6;
+    vm.memory.d = 7;
+    let mut plain = vm.clone();
+    plain.idioms = vec![None; plain.program.len()];
+    assert_eq!(vm.outputs().collect::<Vec<_>>(), vec![42, 45]);
+    assert_eq!(plain.outputs().collect::<Vec<_>>(), vec![42, 45]);
+    assert_eq!(vm.memory, plain.memory);
+}
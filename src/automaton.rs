@@ -0,0 +1,257 @@
+//! Cellular automata on a `Grid`, such as 2018 day 18's lumber area.
+//!
+//! Each generation computes every cell from its previous state and those of
+//! its neighbours into a second grid, and then swaps the two. A bounded
+//! automaton only ever updates the cells its grid started with; an unbounded
+//! one grows to cover every cell that may become something other than the
+//! default, which must then stay the default while all its neighbours are.
+
+use cycle;
+use {Grid, Matrix, VecLike};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The eight surrounding cells.
+    Moore,
+    /// The four orthogonally adjacent cells.
+    VonNeumann,
+    /// The cells up to this far to the left and right, on a single row.
+    Window(usize),
+}
+
+impl Neighbourhood {
+    /// The offsets of the neighbours, in reading order.
+    pub fn offsets(self) -> Vec<(isize, isize)> {
+        match self {
+            Neighbourhood::Moore => (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                .filter(|&d| d != (0, 0))
+                .collect(),
+            Neighbourhood::VonNeumann => vec![(0, -1), (-1, 0), (1, 0), (0, 1)],
+            Neighbourhood::Window(r) => {
+                let r = r as isize;
+                (-r..=r).filter(|&dx| dx != 0).map(|dx| (dx, 0)).collect()
+            }
+        }
+    }
+
+    fn radius(self) -> isize {
+        match self {
+            Neighbourhood::Moore | Neighbourhood::VonNeumann => 1,
+            Neighbourhood::Window(r) => r as isize,
+        }
+    }
+}
+
+/// An automaton whose `rule` computes a cell's next state from its current
+/// one and its neighbours', in the order of `Neighbourhood::offsets`.
+#[derive(Clone)]
+pub struct Automaton<T, C, F> {
+    current: Grid<T, C>,
+    next: Grid<T, C>,
+    offsets: Vec<(isize, isize)>,
+    radius: isize,
+    bounded: bool,
+    rule: F,
+    generation: u64,
+}
+
+impl<T, C, F> Automaton<T, C, F>
+where
+    T: Copy + Default + PartialEq,
+    C: VecLike<T>,
+    F: FnMut(T, &[T]) -> T,
+{
+    pub fn bounded(grid: Grid<T, C>, neighbourhood: Neighbourhood, rule: F) -> Self {
+        Automaton::new(grid, neighbourhood, rule, true)
+    }
+
+    pub fn unbounded(grid: Grid<T, C>, neighbourhood: Neighbourhood, rule: F) -> Self {
+        Automaton::new(grid, neighbourhood, rule, false)
+    }
+
+    fn new(grid: Grid<T, C>, neighbourhood: Neighbourhood, rule: F, bounded: bool) -> Self {
+        let (left, top, right, bottom) = grid.bounds();
+        let next = Grid::from_matrix(
+            Matrix::new((bottom - top) as usize, (right - left) as usize),
+            (left, top),
+        );
+        Automaton {
+            current: grid,
+            next,
+            offsets: neighbourhood.offsets(),
+            radius: neighbourhood.radius(),
+            bounded,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T, C> {
+        &self.current
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The region whose cells may change in the next generation, if any.
+    fn region(&self) -> Option<(isize, isize, isize, isize)> {
+        let bounds = self.current.bounds();
+        if self.bounded {
+            return Some(bounds);
+        }
+        let (l, t, r, b) = self.current.extent()?;
+        let radius = self.radius;
+        let (dy, dx) = if self.offsets.iter().all(|&(_, dy)| dy == 0) {
+            (0, radius)
+        } else {
+            (radius, radius)
+        };
+        Some((l - dx, t - dy, r + dx, b + dy))
+    }
+
+    pub fn step(&mut self) {
+        // Cells left in `next` from two generations ago are rewritten too.
+        let (nl, nt, nr, nb) = self.next.bounds();
+        let (l, t, r, b) = match self.region() {
+            Some((l, t, r, b)) => (l.min(nl), t.min(nt), r.max(nr), b.max(nb)),
+            None => (nl, nt, nr, nb),
+        };
+        let mut neighbours = vec![T::default(); self.offsets.len()];
+        for y in t..b {
+            for x in l..r {
+                for (n, &(dx, dy)) in neighbours.iter_mut().zip(&self.offsets) {
+                    *n = self.current.get(x + dx, y + dy);
+                }
+                let cell = (self.rule)(self.current.get(x, y), &neighbours);
+                let stored = x >= nl && x < nr && y >= nt && y < nb;
+                if stored || cell != T::default() {
+                    self.next.set(x, y, cell);
+                }
+            }
+        }
+        ::std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+    }
+
+    /// Runs `generations` more generations, skipping whole cycles once the
+    /// automaton repeats a state; it must eventually do so, or this never
+    /// returns.
+    pub fn skip(&mut self, generations: u64)
+    where
+        Self: Clone + Eq,
+    {
+        let cycle = cycle::find(self.clone(), |mut a| {
+            a.step();
+            a
+        });
+        let start = cycle.start() as u64;
+        let steps = if generations <= start {
+            generations
+        } else {
+            start + (generations - start) % cycle.length() as u64
+        };
+        for _ in 0..steps {
+            self.step();
+        }
+        self.generation += generations - steps;
+    }
+
+    /// How many cells are `state`.
+    pub fn count(&self, state: T) -> usize {
+        self.current.cells().filter(|&(_, v)| v == state).count()
+    }
+}
+
+/// Automata are equal when their cells are, wherever they are stored.
+impl<T, C, F> PartialEq for Automaton<T, C, F>
+where
+    T: Copy + Default + PartialEq,
+    C: VecLike<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let (l1, t1, r1, b1) = self.current.bounds();
+        let (l2, t2, r2, b2) = other.current.bounds();
+        (t1.min(t2)..b1.max(b2)).all(|y| {
+            (l1.min(l2)..r1.max(r2)).all(|x| self.current.get(x, y) == other.current.get(x, y))
+        })
+    }
+}
+
+impl<T, C, F> Eq for Automaton<T, C, F>
+where
+    T: Copy + Default + Eq,
+    C: VecLike<T>,
+{
+}
+
+#[cfg(test)]
+fn life(alive: bool, neighbours: &[bool]) -> bool {
+    let n = neighbours.iter().filter(|&&b| b).count();
+    n == 3 || (alive && n == 2)
+}
+
+#[test]
+fn automaton_blinker() {
+    use BitVec;
+
+    let grid: Grid<bool, BitVec> =
+        Grid::from_matrix(Matrix::interpret_bool(".../###/..."), (-1, -1));
+    let mut automaton = Automaton::unbounded(grid, Neighbourhood::Moore, life);
+    automaton.step();
+    let vertical = (-1..=1).all(|y| automaton.grid().get(0, y));
+    assert!(vertical);
+    assert_eq!(automaton.count(true), 3);
+    automaton.skip(1_000_000_001);
+    assert_eq!(automaton.generation(), 1_000_000_002);
+    assert!(!automaton.grid().get(0, -1));
+    assert!((-1..=1).all(|x| automaton.grid().get(x, 0)));
+}
+
+#[test]
+fn automaton_glider_grows() {
+    use BitVec;
+
+    let grid: Grid<bool, BitVec> = Grid::from_matrix(Matrix::interpret_bool(".#./..#/###"), (0, 0));
+    let mut automaton = Automaton::unbounded(grid, Neighbourhood::Moore, life);
+    for _ in 0..40 {
+        automaton.step();
+    }
+    // A glider moves one cell diagonally every four generations.
+    for &(x, y) in &[(11, 10), (12, 11), (10, 12), (11, 12), (12, 12)] {
+        assert!(automaton.grid().get(x, y), "{:?}", (x, y));
+    }
+    assert_eq!(automaton.count(true), 5);
+}
+
+#[test]
+fn automaton_neighbourhoods() {
+    // Rule 90 on a row: each cell is the xor of its two neighbours.
+    let mut row: Grid<bool, Vec<bool>> = Grid::from_matrix(Matrix::new(1, 1), (0, 0));
+    row.set(0, 0, true);
+    let mut automaton =
+        Automaton::unbounded(row, Neighbourhood::Window(1), |_, n: &[bool]| n[0] != n[1]);
+    for _ in 0..3 {
+        automaton.step();
+    }
+    let cells = (-4..=4)
+        .map(|x| automaton.grid().get(x, 0))
+        .collect::<Vec<_>>();
+    let expected = [false, true, false, true, false, true, false, true, false];
+    assert_eq!(cells, expected);
+    assert_eq!(automaton.grid().bounds().1, 0);
+
+    // Cells with a live orthogonal neighbour come alive, on a bounded grid.
+    let grid: Grid<bool, Vec<bool>> =
+        Grid::from_matrix(Matrix::interpret(".../.#./...", false, true), (0, 0));
+    let mut automaton = Automaton::bounded(grid, Neighbourhood::VonNeumann, |c, n: &[bool]| {
+        c || n.iter().any(|&b| b)
+    });
+    automaton.step();
+    assert_eq!(automaton.count(true), 5);
+    automaton.step();
+    assert_eq!(automaton.count(true), 9);
+    automaton.step();
+    assert_eq!(automaton.grid().bounds(), (0, 0, 3, 3));
+}
@@ -19,17 +19,16 @@ enum Output {
 
 impl Output {
     fn parse(p: &mut Parser) -> Result<Output, ParserError> {
-        let c = if let Ok(true) = p.expect(b"bot ") {
-            Output::Bot
-        } else if let Ok(true) = p.expect(b"output ") {
-            Output::Output
-        } else {
-            return Err(ParserError::Unexpected(
-                format!("Output"),
-                p.cur().map(|c| c as char),
-            ));
-        };
-        Ok(c(p.consume_number()? as u8))
+        p.alt(
+            |p| {
+                p.literal("bot ")?;
+                Ok(Output::Bot(p.unsigned()?))
+            },
+            |p| {
+                p.literal("output ")?;
+                Ok(Output::Output(p.unsigned()?))
+            },
+        )
     }
 
     fn put(self, value: u8, bots: &mut Vec<Bot>, outputs: &mut Vec<Option<u8>>) -> Option<u8> {
@@ -49,29 +48,63 @@ impl Output {
     }
 }
 
-fn parse_input(s: &str) -> Vec<Instruction> {
-    s.lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let mut p = Parser::new(line.as_bytes());
-            if p.expect(b"value ")? {
-                let value = p.consume_number()? as u8;
-                assert!(p.expect(b" goes to bot ")?);
-                let to = p.consume_number()? as u8;
+impl Instruction {
+    // value 5 goes to bot 2
+    // bot 2 gives low to bot 1 and high to bot 0
+    fn parse(p: &mut Parser) -> Result<Instruction, ParserError> {
+        p.alt(
+            |p| {
+                p.literal("value ")?;
+                let value = p.unsigned()?;
+                p.literal(" goes to bot ")?;
+                let to = p.unsigned()?;
                 Ok(Instruction::ToBot { value, to })
-            } else if p.expect(b"bot ")? {
-                let bot = p.consume_number()? as u8;
-                assert!(p.expect(b" gives low to ")?);
-                let low = Output::parse(&mut p)?;
-                assert!(p.expect(b" and high to ")?);
-                let high = Output::parse(&mut p)?;
+            },
+            |p| {
+                p.literal("bot ")?;
+                let bot = p.unsigned()?;
+                p.literal(" gives low to ")?;
+                let low = Output::parse(p)?;
+                p.literal(" and high to ")?;
+                let high = Output::parse(p)?;
                 Ok(Instruction::State { bot, low, high })
-            } else {
-                panic!("unexpected line: {}", line);
-            }
-        })
-        .collect::<Result<_, ParserError>>()
-        .unwrap()
+            },
+        )
+    }
+}
+
+fn parse_input(s: &str) -> Vec<Instruction> {
+    let mut p = Parser::new(s.as_bytes());
+    let mut instructions = Vec::new();
+    p.skip_whitespace();
+    while !p.at_end() {
+        match Instruction::parse(&mut p) {
+            Ok(instruction) => instructions.push(instruction),
+            Err(e) => panic!("{}", e.render(s)),
+        }
+        p.skip_whitespace();
+    }
+    instructions
+}
+
+#[test]
+fn parse_errors() {
+    let error = |s: &str| {
+        let mut p = Parser::new(s.as_bytes());
+        Instruction::parse(&mut p).unwrap_err().render(s)
+    };
+    assert_eq!(
+        error("bot 2 gives low to bto 1 and high to bot 0"),
+        "line 1, column 20: expected \"bot \" or \"output \", found 'b'\n\
+         bot 2 gives low to bto 1 and high to bot 0\n\
+         \x20                  ^"
+    );
+    assert_eq!(
+        error("value 300 goes to bot 2"),
+        "line 1, column 7: number does not fit in u8\n\
+         value 300 goes to bot 2\n\
+         \x20     ^^^"
+    );
 }
 
 #[derive(Debug)]
@@ -159,16 +192,6 @@ pub fn part2(s: &str) -> usize {
     (outputs[0].unwrap() as usize) * (outputs[1].unwrap() as usize) * (outputs[2].unwrap() as usize)
 }
 
-#[test]
-fn part1_real() {
-    assert_eq!(part1(INPUT), 118);
-}
-
-#[test]
-fn part2_real() {
-    assert_eq!(part2(INPUT), 143153);
-}
-
 pub const INPUT: &str = "
 bot 76 gives low to bot 191 and high to bot 21
 bot 193 gives low to bot 118 and high to bot 145
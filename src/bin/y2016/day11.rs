@@ -3,378 +3,381 @@
 // Stops on each floor; must have both generator and microchip on floor.
 // Goal: Bring all RTGs and microchips to 4th floor.
 
-use std::cmp::{self, Ord, Ordering, PartialOrd};
 use std::fmt;
-use std::usize;
-use std::u16;
-use std::collections::HashSet;
-use std::collections::BinaryHeap;
-use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
+use advent_of_code::search::{self, Reduced, Symmetric};
 use itertools::Itertools;
 
-struct Executor {
-    min_steps: usize,
-    visited: HashSet<Floors>,
-    // We use a BinaryHeap here because it's critical that we visit states with less steps before
-    // states with more steps. Since we can reach an equivalent floorplan via more steps, if we
-    // traverse the potential states otherwise we may not visit the lower-step state before the
-    // floorplan-equivalent higher-step state, and as such forget about it.
-    potential_states: BinaryHeap<State>,
+fn exec(floors: Floors) -> usize {
+    let search = search::bfs(
+        Reduced::new(floors),
+        |f: &Reduced<Floors>| f.state.successors().into_iter().map(Reduced::new),
+        |f: &Reduced<Floors>| f.state.is_done(),
+    );
+    search.goal_cost().expect("cannot move everything up")
 }
 
-impl Executor {
-    fn new() -> Executor {
-        Executor {
-            min_steps: usize::MAX,
-            visited: HashSet::new(),
-            potential_states: Default::default(),
-        }
-    }
-
-    fn push_state(&mut self, state: State) {
-        if state.steps > self.min_steps {
-            return;
-        }
-        if state.is_done() {
-            self.min_steps = cmp::min(self.min_steps, state.steps);
-            return;
-        }
-
-        if self.visited.insert(state.floors) {
-            self.potential_states.push(state);
-        }
-    }
-
-    fn next_state(&mut self) -> Option<State> {
-        self.potential_states.pop()
-    }
+pub fn part1(s: &str) -> usize {
+    exec(parse(s))
 }
 
-fn exec(floors: Floors) -> usize {
-    let mut executor = Executor::new();
-    executor.push_state(State {
-        steps: 0,
-        floors: floors,
-    });
-    while let Some(state) = executor.next_state() {
-        let i = state.elevator_floor_idx();
-        let elevator_floor = state.floors.0[i].0;
-
-        let mut move_two_up = false;
-        let mut move_one_down = false;
-        for a in elevator_floor.into_iter() {
-            if let Some(floors) = state.floors.move_down(i, a, None) {
-                move_one_down = true;
-                executor.push_state(state.with_floors(floors));
-            }
-        }
-        for (a, b) in elevator_floor.into_iter().tuple_combinations() {
-            if let Some(floors) = state.floors.move_up(i, a, Some(b)) {
-                move_two_up = true;
-                executor.push_state(state.with_floors(floors));
-            }
-
-            if !move_one_down {
-                if let Some(floors) = state.floors.move_down(i, a, Some(b)) {
-                    executor.push_state(state.with_floors(floors));
-                }
-            }
-        }
-        if !move_two_up {
-            for a in elevator_floor.into_iter() {
-                if let Some(floors) = state.floors.move_up(i, a, None) {
-                    executor.push_state(state.with_floors(floors));
-                }
-            }
-        }
+pub fn part2(s: &str) -> usize {
+    let mut floors = parse(s);
+    // An elerium generator and microchip, and a dilithium generator and
+    // microchip.
+    for _ in 0..2 {
+        floors.add_pair().expect("no room for another element");
     }
-    executor.min_steps
+    exec(floors)
 }
 
-pub fn part1(s: &[Item]) -> usize {
-    exec(Floors::new(s))
+fn parse(s: &str) -> Floors {
+    s.parse().unwrap_or_else(|e| panic!("{}", e))
 }
 
-pub fn part2(s: &[Item]) -> usize {
-    let mut floors = Floors::new(s);
-    floors.0[0].0.insert(Item::ELERIUM_GENERATOR);
-    floors.0[0].0.insert(Item::ELERIUM_MICROCHIP);
-    floors.0[0].0.insert(Item::DILITHIUM_GENERATOR);
-    floors.0[0].0.insert(Item::DILITHIUM_MICROCHIP);
-    exec(floors)
-}
+/// Elements beyond this many don't fit in `Items`.
+const MAX_ELEMENTS: usize = 8;
 
-#[derive(Clone, PartialEq, Eq)]
-struct State {
-    steps: usize,
-    floors: Floors,
-}
+/// A set of generators and microchips: element `e`'s generator is bit `2e`
+/// and its microchip bit `2e + 1`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Items(u16);
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.steps.cmp(&other.steps).reverse()
-    }
-}
+impl Items {
+    const GENERATORS: u16 = 0x5555;
 
-impl State {
-    fn elevator_floor_idx(&self) -> usize {
-        self.floors.0.iter().position(|f| f.has_elevator()).unwrap()
+    fn generator(element: usize) -> Items {
+        Items(1 << (2 * element))
     }
 
-    fn with_floors(&self, floors: Floors) -> Self {
-        State {
-            steps: self.steps + 1,
-            floors: floors,
-        }
+    fn microchip(element: usize) -> Items {
+        Items(1 << (2 * element + 1))
     }
 
-    fn is_done(&self) -> bool {
-        self.floors.0[0..self.floors.0.len() - 1]
-            .iter()
-            .all(|f| f.0.is_empty())
+    fn contains(self, items: Items) -> bool {
+        self.0 & items.0 == items.0
     }
-}
 
-#[derive(Copy, Clone)]
-pub struct Floor(Item);
-
-impl Floor {
-    #[inline(always)]
-    fn set_elevator(&mut self, e: bool) {
-        self.0.set(Item::ELEVATOR, e)
+    fn insert(&mut self, items: Items) {
+        self.0 |= items.0;
     }
-    #[inline(always)]
-    fn has_elevator(&self) -> bool {
-        self.0.contains(Item::ELEVATOR)
+
+    fn remove(&mut self, items: Items) {
+        self.0 &= !items.0;
     }
 
-    // Use the fact that any pair of (generator, microchip) is
-    // interchangeable. Therefore, floors should compare/hash as equivalent so
-    // long as the same quantity of pairs and the same non-paired items are on
-    // the floor.
-    fn to_hashable(self) -> (u8, Item) {
-        let generator = self.0 & Item::GENERATOR;
-        let microchip = self.0.to_generator();
-        let pairs = generator & microchip;
-        let paired = pairs.bits.count_ones() as u8;
-        let unpaired = self.0 & !(pairs | pairs.to_microchip());
-
-        (paired, unpaired)
+    fn is_empty(self) -> bool {
+        self.0 == 0
     }
 
-    fn departing_with(self, a: Item, b: Option<Item>) -> Floor {
-        let mut f = self;
-        f.set_elevator(false);
-        f.0.remove(a);
-        if let Some(b) = b {
-            f.0.remove(b);
-        }
-        f
+    /// Each item on its own.
+    fn iter(self) -> impl Iterator<Item = Items> + Clone {
+        (0..2 * MAX_ELEMENTS)
+            .map(|bit| Items(1 << bit))
+            .filter(move |&item| self.contains(item))
     }
 
-    // Either all microchips have a generator, or there are no generators
-    fn is_valid(self) -> bool {
-        if self.0.is_empty() {
-            return true;
-        }
-        if (self.0 & Item::GENERATOR).is_empty() {
-            return true;
-        }
-        // Shift all microchips to generators, then check that all of those generators are present
-        self.0.to_generator().contains(self.0 & Item::GENERATOR)
+    // Either all microchips have their generator, or there are no generators
+    fn is_safe(self) -> bool {
+        let generators = self.0 & Items::GENERATORS;
+        let microchips = (self.0 >> 1) & Items::GENERATORS;
+        generators == 0 || microchips & !generators == 0
     }
 }
 
-// Space for 4 floors.
-#[derive(Copy, Clone)]
-struct Floors([Floor; 4]);
-
-impl Hash for Floors {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0[0].to_hashable().hash(state);
-        self.0[1].to_hashable().hash(state);
-        self.0[2].to_hashable().hash(state);
-        self.0[3].to_hashable().hash(state);
+impl fmt::Debug for Items {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = (0..MAX_ELEMENTS).flat_map(|e| {
+            let g = if self.contains(Items::generator(e)) {
+                Some(format!("{}G", e))
+            } else {
+                None
+            };
+            let m = if self.contains(Items::microchip(e)) {
+                Some(format!("{}M", e))
+            } else {
+                None
+            };
+            g.into_iter().chain(m)
+        });
+        write!(f, "[{}]", names.format(" "))
     }
 }
 
-impl PartialEq for Floors {
-    fn eq(&self, other: &Self) -> bool {
-        self.0[0].to_hashable() == other.0[0].to_hashable()
-            && self.0[1].to_hashable() == other.0[1].to_hashable()
-            && self.0[2].to_hashable() == other.0[2].to_hashable()
-            && self.0[3].to_hashable() == other.0[3].to_hashable()
-    }
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Floors {
+    elevator: usize,
+    floors: [Items; 4],
 }
 
-impl Eq for Floors {}
+/// Any two elements are interchangeable, so what matters is only how many
+/// elements have their generator and microchip on each pair of floors.
+impl Symmetric for Floors {
+    type Canonical = u64;
+
+    fn canonical(&self) -> u64 {
+        let mut elements = [0u8; MAX_ELEMENTS];
+        for (e, code) in elements.iter_mut().enumerate() {
+            let floor_of = |item| self.floors.iter().position(|f| f.contains(item));
+            if let (Some(g), Some(m)) =
+                (floor_of(Items::generator(e)), floor_of(Items::microchip(e)))
+            {
+                *code = 0x10 | (g as u8) << 2 | m as u8;
+            }
+        }
+        elements.sort();
+        elements
+            .iter()
+            .fold(self.elevator as u64, |key, &code| key << 5 | code as u64)
+    }
+}
 
 impl fmt::Debug for Floors {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "F4: {:?}\n", self.0[3].0)?;
-        write!(f, "F3: {:?}\n", self.0[2].0)?;
-        write!(f, "F2: {:?}\n", self.0[1].0)?;
-        write!(f, "F1: {:?}", self.0[0].0)
+        for i in (0..self.floors.len()).rev() {
+            let elevator = if i == self.elevator { "E" } else { " " };
+            write!(f, "F{} {} {:?}", i + 1, elevator, self.floors[i])?;
+            if i > 0 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
     }
 }
 
 impl Floors {
-    fn new(s: &[Item]) -> Floors {
-        debug_assert_eq!(s.len(), 4);
-        Floors([Floor(s[0]), Floor(s[1]), Floor(s[2]), Floor(s[3])])
+    /// Puts the generator and microchip of another element on the first
+    /// floor.
+    fn add_pair(&mut self) -> Result<(), String> {
+        let all = self.floors.iter().fold(Items::default(), |mut all, &f| {
+            all.insert(f);
+            all
+        });
+        let element = (0..MAX_ELEMENTS)
+            .find(|&e| !all.contains(Items::generator(e)))
+            .ok_or_else(|| format!("more than {} elements", MAX_ELEMENTS))?;
+        self.floors[0].insert(Items::generator(element));
+        self.floors[0].insert(Items::microchip(element));
+        Ok(())
     }
 
-    fn move_down(self, from: usize, a: Item, b: Option<Item>) -> Option<Floors> {
-        if from == 0 {
-            return None;
-        }
+    fn is_done(&self) -> bool {
+        self.floors[0..self.floors.len() - 1]
+            .iter()
+            .all(|f| f.is_empty())
+    }
 
-        // Don't move things down to empty floors
-        if self.0[from - 1].0.is_empty() {
-            return None;
-        }
+    fn successors(&self) -> Vec<Floors> {
+        let i = self.elevator;
+        let elevator_floor = self.floors[i];
+        let mut next = Vec::new();
 
-        let floors = self.moving(from, from - 1, a, b);
+        let mut move_two_up = false;
+        let mut move_one_down = false;
+        for a in elevator_floor.iter() {
+            if let Some(floors) = self.move_down(a) {
+                move_one_down = true;
+                next.push(floors);
+            }
+        }
+        for (a, b) in elevator_floor.iter().tuple_combinations() {
+            let both = Items(a.0 | b.0);
+            if let Some(floors) = self.move_up(both) {
+                move_two_up = true;
+                next.push(floors);
+            }
 
-        if floors.0[from - 1].is_valid() {
-            Some(floors)
-        } else {
-            None
+            if !move_one_down {
+                if let Some(floors) = self.move_down(both) {
+                    next.push(floors);
+                }
+            }
+        }
+        if !move_two_up {
+            for a in elevator_floor.iter() {
+                if let Some(floors) = self.move_up(a) {
+                    next.push(floors);
+                }
+            }
         }
+        next
     }
 
-    fn move_up(self, from: usize, a: Item, b: Option<Item>) -> Option<Floors> {
-        if from + 1 >= self.0.len() {
+    fn move_down(self, items: Items) -> Option<Floors> {
+        if self.elevator == 0 {
             return None;
         }
 
-        let floors = self.moving(from, from + 1, a, b);
+        self.moving(self.elevator - 1, items)
+    }
 
-        if floors.0[from + 1].is_valid() {
-            Some(floors)
-        } else {
-            None
+    fn move_up(self, items: Items) -> Option<Floors> {
+        if self.elevator + 1 >= self.floors.len() {
+            return None;
         }
+
+        self.moving(self.elevator + 1, items)
     }
 
-    fn moving(self, from: usize, to: usize, a: Item, b: Option<Item>) -> Floors {
+    /// Takes `items` to floor `to`, if that leaves both floors safe.
+    fn moving(self, to: usize, items: Items) -> Option<Floors> {
         let mut floors = self;
-        let new_current_floor = floors.0[from].departing_with(a, b);
-        floors.0[from] = new_current_floor;
-        floors.0[to].set_elevator(true);
-        floors.0[to].0.insert(a);
-        if let Some(b) = b {
-            floors.0[to].0.insert(b);
+        floors.floors[self.elevator].remove(items);
+        floors.floors[to].insert(items);
+        floors.elevator = to;
+        if floors.floors[self.elevator].is_safe() && floors.floors[to].is_safe() {
+            Some(floors)
+        } else {
+            None
         }
-        floors
     }
 }
 
-#[derive(Copy, Clone)]
-struct ItemIter {
-    item: Item,
-}
-
-impl Iterator for ItemIter {
-    type Item = Item;
-    fn next(&mut self) -> Option<Self::Item> {
-        macro_rules! remove_return {
-            ($($flag:expr),+) => {
-                $(
-                    {
-                        let actual_flag = $flag;
-                        if self.item.contains(actual_flag) {
-                            self.item.remove(actual_flag);
-                            return Some(actual_flag);
-                        }
+const FLOORS: &[&str] = &["first", "second", "third", "fourth"];
+
+/// Reads a line per floor, such as
+///
+/// ```text
+/// The first floor contains a hydrogen-compatible microchip and a lithium generator.
+/// ```
+///
+/// giving each element a slot in `Items` as it is first named.
+impl FromStr for Floors {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Floors, String> {
+        let mut elements: Vec<&str> = Vec::new();
+        let mut floors = [Items::default(); 4];
+        let mut seen = [false; 4];
+        let lines = s.lines().map(str::trim).enumerate();
+        for (i, line) in lines.filter(|&(_, line)| !line.is_empty()) {
+            let error = |e: String| format!("line {}: {}: {:?}", i + 1, e, line);
+            if !line.starts_with("The ") {
+                return Err(error(String::from("expected \"The\"")));
+            }
+            let rest = line["The ".len()..].trim_end_matches('.');
+            let mut words = rest.splitn(3, ' ');
+            let ordinal = words.next().unwrap_or("");
+            let floor = match FLOORS.iter().position(|&f| f == ordinal) {
+                Some(floor) => floor,
+                None => return Err(error(format!("unsupported floor {:?}", ordinal))),
+            };
+            if seen[floor] {
+                return Err(error(format!("the {} floor is listed twice", ordinal)));
+            }
+            seen[floor] = true;
+            let contents = match (words.next(), words.next()) {
+                (Some("floor"), Some(contents)) if contents.starts_with("contains ") => {
+                    &contents["contains ".len()..]
+                }
+                _ => return Err(error(String::from("expected \"floor contains\""))),
+            };
+            if contents == "nothing relevant" {
+                continue;
+            }
+            let descriptions = contents
+                .split(", ")
+                .flat_map(|d| d.split(" and "))
+                .map(|d| d.trim_start_matches("and "))
+                .filter(|d| !d.is_empty());
+            for description in descriptions {
+                let item = description.trim_start_matches("a ");
+                let (name, microchip) = if item.ends_with(" generator") {
+                    (&item[..item.len() - " generator".len()], false)
+                } else if item.ends_with("-compatible microchip") {
+                    (&item[..item.len() - "-compatible microchip".len()], true)
+                } else {
+                    ("", false)
+                };
+                if name.is_empty() || name.contains(' ') {
+                    return Err(error(format!("unknown item {:?}", description)));
+                }
+                let element = match elements.iter().position(|&e| e == name) {
+                    Some(element) => element,
+                    None if elements.len() == MAX_ELEMENTS => {
+                        return Err(error(format!("more than {} elements", MAX_ELEMENTS)));
+                    }
+                    None => {
+                        elements.push(name);
+                        elements.len() - 1
                     }
-                )+
+                };
+                let item = if microchip {
+                    Items::microchip(element)
+                } else {
+                    Items::generator(element)
+                };
+                if floors.iter().any(|f| f.contains(item)) {
+                    return Err(error(format!("{:?} is listed twice", description)));
+                }
+                floors[floor].insert(item);
             }
         }
-
-        remove_return!(
-            Item::THULIUM_GENERATOR,
-            Item::THULIUM_MICROCHIP,
-            Item::PLUTONIUM_GENERATOR,
-            Item::PLUTONIUM_MICROCHIP,
-            Item::STRONTIUM_GENERATOR,
-            Item::STRONTIUM_MICROCHIP,
-            Item::PROMETHIUM_GENERATOR,
-            Item::PROMETHIUM_MICROCHIP,
-            Item::RUTHENIUM_GENERATOR,
-            Item::RUTHENIUM_MICROCHIP,
-            Item::ELERIUM_GENERATOR,
-            Item::ELERIUM_MICROCHIP,
-            Item::DILITHIUM_GENERATOR,
-            Item::DILITHIUM_MICROCHIP
-        );
-        None
+        for (e, name) in elements.iter().enumerate() {
+            for &(item, kind) in &[
+                (Items::generator(e), "generator"),
+                (Items::microchip(e), "microchip"),
+            ] {
+                if !floors.iter().any(|f| f.contains(item)) {
+                    return Err(format!("there is no {} {}", name, kind));
+                }
+            }
+        }
+        Ok(Floors {
+            elevator: 0,
+            floors,
+        })
     }
 }
 
-bitflags! {
-    pub struct Item: u16 {
-        const THULIUM_GENERATOR    = 0b00000000_00000001;
-        const THULIUM_MICROCHIP    = 0b00000000_00000010;
-        const PLUTONIUM_GENERATOR  = 0b00000000_00000100;
-        const PLUTONIUM_MICROCHIP  = 0b00000000_00001000;
-        const STRONTIUM_GENERATOR  = 0b00000000_00010000;
-        const STRONTIUM_MICROCHIP  = 0b00000000_00100000;
-        const PROMETHIUM_GENERATOR = 0b00000000_01000000;
-        const PROMETHIUM_MICROCHIP = 0b00000000_10000000;
-        const RUTHENIUM_GENERATOR  = 0b00000001_00000000;
-        const RUTHENIUM_MICROCHIP  = 0b00000010_00000000;
-        const ELERIUM_GENERATOR    = 0b00000100_00000000;
-        const ELERIUM_MICROCHIP    = 0b00001000_00000000;
-        const DILITHIUM_GENERATOR  = 0b00010000_00000000;
-        const DILITHIUM_MICROCHIP  = 0b00100000_00000000;
-
-        const MICROCHIP            = 0b00101010_10101010;
-        const GENERATOR            = 0b00010101_01010101;
-        const ELEVATOR             = 0b01000000_00000000;
-    }
+#[test]
+fn parse_floors() {
+    let floors = "
+        The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+        The second floor contains a hydrogen generator.
+        The third floor contains a lithium generator.
+        The fourth floor contains nothing relevant.
+    "
+    .parse::<Floors>()
+    .unwrap();
+    assert_eq!(
+        floors.floors,
+        [Items(0b1010), Items(0b0001), Items(0b0100), Items(0)]
+    );
+    assert_eq!(exec(floors), 11);
+
+    let error = |s: &str| s.parse::<Floors>().unwrap_err();
+    assert_eq!(
+        error("The fifth floor contains nothing relevant."),
+        "line 1: unsupported floor \"fifth\": \"The fifth floor contains nothing relevant.\""
+    );
+    assert_eq!(
+        error("The first floor contains a hydrogen generator."),
+        "there is no hydrogen microchip"
+    );
+    let crowded = (0..9).map(|i| format!("a e{0} generator, a e{0}-compatible microchip", i));
+    let crowded = format!("The first floor contains {}.", crowded.format(", "));
+    assert!(error(&crowded).starts_with("line 1: more than 8 elements"));
 }
 
-impl Item {
-    fn into_iter(self) -> ItemIter {
-        ItemIter { item: self }
-    }
-
-    fn to_microchip(self) -> Item {
-        Item::from_bits((self & Item::GENERATOR).bits() << 1).unwrap()
-    }
-
-    fn to_generator(self) -> Item {
-        Item::from_bits((self & Item::MICROCHIP).bits() >> 1).unwrap()
-    }
+#[test]
+fn interchangeable_elements() {
+    let new = |floors| Floors {
+        elevator: 0,
+        floors,
+    };
+    let a = new([Items(0b0110), Items(0b1001), Items(0), Items(0)]);
+    let b = new([Items(0b1001), Items(0b0110), Items(0), Items(0)]);
+    assert_ne!(a, b);
+    assert_eq!(a.canonical(), b.canonical());
+    let c = new([Items(0b0011), Items(0b1100), Items(0), Items(0)]);
+    assert_ne!(a.canonical(), c.canonical());
 }
 
-// The first floor contains a thulium generator, a thulium-compatible microchip, a plutonium
-// generator, and a strontium generator.
-//
-// The second floor contains a plutonium-compatible microchip and a strontium-compatible microchip.
-//
-// The third floor contains a promethium generator, a promethium-compatible microchip, a ruthenium
-// generator, and a ruthenium-compatible microchip.
-//
-// The fourth floor contains nothing relevant.
-pub static INPUT: &[Item] = &[
-    Item {
-        bits: Item::ELEVATOR.bits | Item::THULIUM_GENERATOR.bits | Item::THULIUM_MICROCHIP.bits
-            | Item::PLUTONIUM_GENERATOR.bits | Item::STRONTIUM_GENERATOR.bits,
-    },
-    Item {
-        bits: Item::PLUTONIUM_MICROCHIP.bits | Item::STRONTIUM_MICROCHIP.bits,
-    },
-    Item {
-        bits: Item::PROMETHIUM_GENERATOR.bits | Item::PROMETHIUM_MICROCHIP.bits
-            | Item::RUTHENIUM_GENERATOR.bits | Item::RUTHENIUM_MICROCHIP.bits,
-    },
-    Item { bits: 0 },
-];
+pub static INPUT: &str = "
+The first floor contains a thulium generator, a thulium-compatible microchip, a plutonium generator, and a strontium generator.
+The second floor contains a plutonium-compatible microchip and a strontium-compatible microchip.
+The third floor contains a promethium generator, a promethium-compatible microchip, a ruthenium generator, and a ruthenium-compatible microchip.
+The fourth floor contains nothing relevant.
+";
@@ -83,14 +83,4 @@ pub fn part2(s: &str) -> usize {
     compute(s, true)
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 35186);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 22429);
-}
-
 pub const INPUT: &str = "jlmsuwbz";
@@ -1,4 +1,7 @@
+use std::str::FromStr;
+
 use advent_of_code::modulo_solver::{modulo, Congruence};
+use advent_of_code::{Parser, ParserError};
 
 fn solve(discs: &[Disc]) -> usize {
     eprintln!("{:?}", discs);
@@ -17,32 +20,59 @@ fn solve(discs: &[Disc]) -> usize {
 }
 
 pub fn part1(discs: &[Disc]) -> usize {
-    solve(&discs[..discs.len() - 1])
+    solve(discs)
 }
 
 pub fn part2(discs: &[Disc]) -> usize {
+    let mut discs = discs.to_vec();
+    discs.push(Disc {
+        positions: 11,
+        position: 0,
+    });
     solve(&discs)
 }
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Disc {
     positions: usize,
     position: usize,
 }
 
-#[test]
-fn part1_1() {
-    assert_eq!(solve(EXAMPLE), 5);
+// Disc #1 has 5 positions; at time=0, it is at position 4.
+impl FromStr for Disc {
+    type Err = ParserError;
+    fn from_str(s: &str) -> Result<Disc, ParserError> {
+        let mut p = Parser::new(s.trim().as_bytes());
+        p.literal("Disc #")?;
+        p.unsigned::<usize>()?;
+        p.literal(" has ")?;
+        let positions = p.unsigned()?;
+        p.literal(" positions; at time=0, it is at position ")?;
+        let position = p.unsigned()?;
+        p.optional(|p| p.literal("."))?;
+        p.end()?;
+        Ok(Disc {
+            positions,
+            position,
+        })
+    }
 }
 
 #[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 121834);
+fn part1_1() {
+    assert_eq!(solve(EXAMPLE), 5);
 }
 
 #[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 3208099);
+fn parse_disc() {
+    let disc: Disc = "Disc #2 has 13 positions; at time=0, it is at position 11."
+        .parse()
+        .unwrap();
+    assert_eq!((disc.positions, disc.position), (13, 11));
+    let error = "Disc #2 has 13 position; at time=0, it is at position 11."
+        .parse::<Disc>()
+        .unwrap_err();
+    assert_eq!(error.span.start, 14);
 }
 
 #[cfg(test)]
@@ -84,9 +114,4 @@ pub static INPUT: &[Disc] = &[
         positions: 19,
         position: 7,
     },
-    // only for part 2:
-    Disc {
-        positions: 11,
-        position: 0,
-    },
 ];
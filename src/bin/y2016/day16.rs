@@ -1,5 +1,5 @@
-fn compute(input: &[u8], disk_size: usize) -> String {
-    let mut data = Vec::from(input);
+fn compute(input: &str, disk_size: usize) -> String {
+    let mut data = input.trim().bytes().map(|b| b - b'0').collect::<Vec<u8>>();
     while data.len() < disk_size {
         let a = data;
         let mut b = a.clone();
@@ -31,22 +31,12 @@ fn compute(input: &[u8], disk_size: usize) -> String {
         .collect::<String>()
 }
 
-pub fn part1(input: &[u8]) -> String {
+pub fn part1(input: &str) -> String {
     compute(input, 272)
 }
 
-pub fn part2(input: &[u8]) -> String {
+pub fn part2(input: &str) -> String {
     compute(input, 35651584)
 }
 
-#[test]
-fn part1_1() {
-    assert_eq!(part1(INPUT), "10101001010100001");
-}
-
-#[test]
-fn part2_1() {
-    assert_eq!(part2(INPUT), "10100001110101001");
-}
-
-pub const INPUT: &[u8] = &[1, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1];
+pub const INPUT: &str = "10001001100000001";
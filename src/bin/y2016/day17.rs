@@ -1,30 +1,21 @@
 use md5;
 use smallvec::SmallVec;
-use std::cmp::{max, min, Ord, Ordering, PartialOrd, Reverse};
-use std::collections::BinaryHeap;
 
+use advent_of_code::search;
 use advent_of_code::{hi_nib, lo_nib};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct Position(u8, u8, String);
 
-impl PartialOrd for Position {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.2.len().partial_cmp(&other.2.len())
-    }
-}
-
-impl Ord for Position {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 fn is_open(nib: u8) -> bool {
     nib == 0xf || nib == 0xb || nib == 0xc || nib == 0xd || nib == 0xe
 }
 
 impl Position {
+    fn in_vault(&self) -> bool {
+        self.0 == 3 && self.1 == 3
+    }
+
     fn move_down(&self) -> Option<Position> {
         if self.1 == 3 {
             return None;
@@ -85,56 +76,30 @@ impl Position {
 
 pub fn part1(s: &str) -> String {
     let pos = Position(0, 0, s.to_string());
-
-    let mut queue = BinaryHeap::new();
-    let mut min_dist = usize::max_value();
-    queue.push(Reverse(pos));
-    let mut min_path = None::<String>;
-    while let Some(Reverse(nxt)) = queue.pop() {
-        if nxt.2.len() > min_dist {
-            continue;
-        }
-        if nxt.0 == 3 && nxt.1 == 3 {
-            min_dist = min(nxt.2.len(), min_dist);
-            if min_path.is_none() || nxt.2.len() < min_path.as_ref().unwrap().len() {
-                min_path = Some(nxt.2);
-            }
-            continue;
-        }
-        for neighbor in nxt.neighbors() {
-            queue.push(Reverse(neighbor));
-        }
-    }
-    min_path.unwrap()[s.len()..].to_string()
+    let search = search::bfs(pos, Position::neighbors, Position::in_vault);
+    search.goal.expect("no path to the vault").2[s.len()..].to_string()
 }
 
 pub fn part2(s: &str) -> usize {
     let pos = Position(0, 0, s.to_string());
-
-    let mut queue = BinaryHeap::new();
-    let mut max_dist = 0;
-    queue.push(pos);
-    while let Some(nxt) = queue.pop() {
-        if nxt.0 == 3 && nxt.1 == 3 {
-            max_dist = max(nxt.2.len(), max_dist);
-            continue;
-        }
-        for neighbor in nxt.neighbors() {
-            queue.push(neighbor);
-        }
-    }
-
-    max_dist - s.len()
-}
-
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), "RLRDRDUDDR");
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 420);
+    // Every path is a different state, and the paths stop at the vault.
+    let search = search::bfs(
+        pos,
+        |p| {
+            if p.in_vault() {
+                SmallVec::new()
+            } else {
+                p.neighbors()
+            }
+        },
+        |_| false,
+    );
+    let longest = search
+        .reached()
+        .filter(|(p, _)| p.in_vault())
+        .map(|(_, d)| d)
+        .max();
+    longest.expect("no path to the vault")
 }
 
 pub static INPUT: &str = "rrrbmfta";
@@ -54,14 +54,4 @@ pub fn part2(s: &str) -> usize {
     count_safe(s, 400_000)
 }
 
-#[test]
-fn part1_1() {
-    assert_eq!(part1(INPUT), 2013);
-}
-
-#[test]
-fn part2_1() {
-    assert_eq!(part2(INPUT), 20006289);
-}
-
 pub static INPUT: &str = ".^^^.^.^^^.^.......^^.^^^^.^^^^..^^^^^.^.^^^..^^.^.^^..^.^..^^...^.^^.^^^...^^.^.^^^..^^^^.....^....";
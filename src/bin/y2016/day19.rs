@@ -31,19 +31,9 @@ fn part1_1() {
     assert_eq!(part1(5), 3);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 1834471);
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(part2(5), 2);
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 1420064);
-}
-
 pub static INPUT: usize = 3014387;
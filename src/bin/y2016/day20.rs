@@ -51,14 +51,4 @@ pub fn part2(s: &str) -> usize {
     allowed
 }
 
-#[test]
-fn part1_1() {
-    assert_eq!(part1(INPUT), 17348574);
-}
-
-#[test]
-fn part2_1() {
-    assert_eq!(part2(INPUT), 104);
-}
-
 pub static INPUT: &str = include_str!("day20.input");
@@ -60,11 +60,9 @@ fn rotate_based(input: &mut [u8], l: u8) {
     }
 }
 
-pub fn part1(s: &str) -> String {
-    let mut input = [b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h'];
-    let instructions = parse(s);
-    for instruction in instructions {
-        match instruction {
+impl Instruction {
+    fn apply(self, input: &mut [u8]) {
+        match self {
             Instruction::SwapPositions(a, b) => {
                 input.swap(a, b);
             }
@@ -74,13 +72,13 @@ pub fn part1(s: &str) -> String {
                 input.swap(a_idx, b_idx);
             }
             Instruction::RotateLeft(a) => {
-                input.rotate_left(a);
+                input.rotate_left(a % input.len());
             }
             Instruction::RotateRight(a) => {
-                input.rotate_right(a);
+                input.rotate_right(a % input.len());
             }
             Instruction::RotateLetter(l) => {
-                rotate_based(&mut input, l);
+                rotate_based(input, l);
             }
             Instruction::Reverse(a, b) => {
                 input[a..=b].reverse();
@@ -94,54 +92,131 @@ pub fn part1(s: &str) -> String {
             }
         }
     }
-    str::from_utf8(&input[..]).unwrap().to_string()
+
+    /// The instruction undoing this one, if it is a bijection.
+    ///
+    /// Rotating based on the position of a letter is not one for every
+    /// length: it can send two inputs to the same output, and miss others.
+    fn inverse(self) -> Option<Instruction> {
+        Some(match self {
+            Instruction::SwapPositions(..)
+            | Instruction::SwapLetters(..)
+            | Instruction::Reverse(..) => self,
+            Instruction::RotateLeft(a) => Instruction::RotateRight(a),
+            Instruction::RotateRight(a) => Instruction::RotateLeft(a),
+            Instruction::Move(from, to) => Instruction::Move(to, from),
+            Instruction::RotateLetter(_) => return None,
+        })
+    }
+
+    /// All the inputs this instruction scrambles into `output`.
+    fn preimages(self, output: &[u8]) -> Vec<Vec<u8>> {
+        if let Some(inverse) = self.inverse() {
+            let mut input = output.to_vec();
+            inverse.apply(&mut input);
+            return vec![input];
+        }
+        // Every input is a rotation of the output.
+        (0..output.len())
+            .map(|k| {
+                let mut input = output.to_vec();
+                input.rotate_left(k);
+                input
+            })
+            .filter(|input| {
+                let mut scrambled = input.clone();
+                self.apply(&mut scrambled);
+                scrambled == output
+            })
+            .collect()
+    }
+}
+
+fn scramble(password: &[u8], instructions: &[Instruction]) -> Vec<u8> {
+    let mut input = password.to_vec();
+    for instruction in instructions {
+        instruction.apply(&mut input);
+    }
+    input
+}
+
+/// Every password that `instructions` scramble into `scrambled`.
+fn unscramble(scrambled: &[u8], instructions: &[Instruction]) -> Vec<Vec<u8>> {
+    let mut candidates = vec![scrambled.to_vec()];
+    for instruction in instructions.iter().rev() {
+        candidates = candidates
+            .iter()
+            .flat_map(|output| instruction.preimages(output))
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+    }
+    candidates
+}
+
+pub fn part1(s: &str) -> String {
+    let instructions = parse(s);
+    String::from_utf8(scramble(b"abcdefgh", &instructions)).unwrap()
 }
 
 pub fn part2(s: &str) -> String {
-    let mut input = [b'f', b'b', b'g', b'd', b'c', b'e', b'a', b'h'];
     let instructions = parse(s);
-    for instruction in instructions.into_iter().rev() {
-        match instruction {
-            Instruction::SwapPositions(a, b) => {
-                input.swap(a, b);
-            }
-            Instruction::SwapLetters(a, b) => {
-                let a_idx = input.iter().position(|c| *c == a).unwrap();
-                let b_idx = input.iter().position(|c| *c == b).unwrap();
-                input.swap(a_idx, b_idx);
-            }
-            Instruction::RotateLeft(a) => {
-                input.rotate_right(a);
-            }
-            Instruction::RotateRight(a) => {
-                input.rotate_left(a);
-            }
-            Instruction::RotateLetter(l) => {
-                let mut copy = input;
-                loop {
-                    let mut copy2 = copy;
-                    rotate_based(&mut copy2, l);
-                    // that is, rotating this copy produced our original input
-                    if copy2 == input {
-                        input = copy;
-                        break;
-                    }
-                    copy.rotate_left(1);
-                }
-            }
-            Instruction::Reverse(a, b) => {
-                input[a..=b].reverse();
-            }
-            Instruction::Move(from, to) => {
-                if from < to {
-                    input[from..=to].rotate_right(1);
-                } else {
-                    input[to..=from].rotate_left(1);
-                }
-            }
-        }
+    let mut passwords = unscramble(b"fbgdceah", &instructions);
+    assert_eq!(passwords.len(), 1, "ambiguous password");
+    String::from_utf8(passwords.remove(0)).unwrap()
+}
+
+#[test]
+fn part1_1() {
+    let instructions = parse(
+        "swap position 4 with position 0
+swap letter d with letter b
+reverse positions 0 through 4
+rotate left 1 step
+move position 1 to position 4
+move position 3 to position 0
+rotate based on position of letter b
+rotate based on position of letter d",
+    );
+    assert_eq!(scramble(b"abcde", &instructions), b"decab");
+    // With five letters, rotating based on a letter is not a bijection.
+    assert_eq!(
+        unscramble(b"decab", &instructions),
+        vec![b"abcde".to_vec(), b"deabc".to_vec()]
+    );
+}
+
+/// An instruction for a password of `len` letters from three arbitrary bytes.
+#[cfg(test)]
+fn arbitrary_instruction(len: usize, kind: u8, a: u8, b: u8) -> Instruction {
+    let (a, b) = (a as usize % len, b as usize % len);
+    let letter = |i: usize| b'a' + i as u8;
+    match kind % 7 {
+        0 => Instruction::SwapPositions(a, b),
+        1 => Instruction::SwapLetters(letter(a), letter(b)),
+        2 => Instruction::RotateLeft(a),
+        3 => Instruction::RotateRight(a),
+        4 => Instruction::RotateLetter(letter(a)),
+        5 => Instruction::Reverse(a.min(b), a.max(b)),
+        _ => Instruction::Move(a, b),
+    }
+}
+
+quickcheck! {
+    fn unscramble_inverts_scramble(len: u8, ops: Vec<(u8, u8, u8)>) -> bool {
+        let len = 1 + len as usize % 8;
+        let instructions = ops
+            .into_iter()
+            .map(|(kind, a, b)| arbitrary_instruction(len, kind, a, b))
+            .collect::<Vec<_>>();
+        let password = (b'a'..b'a' + len as u8).collect::<Vec<_>>();
+        let scrambled = scramble(&password, &instructions);
+        let passwords = unscramble(&scrambled, &instructions);
+        passwords.contains(&password)
+            && passwords
+                .iter()
+                .all(|p| scramble(p, &instructions) == scrambled)
     }
-    str::from_utf8(&input[..]).unwrap().to_string()
 }
 
 pub static INPUT: &str = include_str!("day21.input");
@@ -1,8 +1,8 @@
-use std::collections::BTreeMap;
-use std::collections::HashMap;
 use std::fmt;
 
-use petgraph::graph::Graph;
+use advent_of_code::geometry::{Direction, Point};
+use advent_of_code::search;
+use advent_of_code::{parse_lines, Matrix, Pattern};
 
 pub fn part1(s: &str) -> usize {
     let nodes = parse(s);
@@ -26,53 +26,130 @@ pub fn part1(s: &str) -> usize {
 }
 
 pub fn part2(s: &str) -> usize {
-    let mut nodes = BTreeMap::new();
-    let mut graph_nodes = HashMap::new();
-    let mut graph = Graph::new_undirected();
-    for node in parse(s) {
-        nodes.entry(node.y).or_insert_with(Vec::new).push(node);
-        graph_nodes.insert((node.x, node.y), graph.add_node(node));
-    }
-    for x in 0usize..=37 {
-        for y in 0usize..=25 {
-            if let Some(x1) = x.checked_sub(1) {
-                if !(x == 1 && y == 13) {
-                    graph.add_edge(graph_nodes[&(x, y)], graph_nodes[&(x1, y)], ());
-                }
+    let cluster = Cluster::new(&parse(s));
+    cluster
+        .shortcut()
+        .or_else(|| cluster.search())
+        .expect("the goal data cannot reach the origin")
+}
+
+/// The nodes as the puzzle intends them to be seen: a single empty node
+/// that data moves into a step at a time, nodes that hold too much to ever
+/// fit into it, and the rest, each of whose data fits into any of the others.
+struct Cluster {
+    walls: Matrix<bool, Vec<bool>>,
+    empty: Point,
+    goal: Point,
+}
+
+impl Cluster {
+    fn new(nodes: &[Node]) -> Cluster {
+        let empty = match nodes.iter().filter(|n| n.used == 0).collect::<Vec<_>>()[..] {
+            [empty] => *empty,
+            ref empty => panic!("expected one empty node, found {}", empty.len()),
+        };
+        let width = nodes.iter().map(|n| n.x + 1).max().unwrap_or(0);
+        let height = nodes.iter().map(|n| n.y + 1).max().unwrap_or(0);
+        let mut walls = Matrix::new(height, width);
+        for y in 0..height {
+            for x in 0..width {
+                walls.set(y, x, true);
             }
-            if let Some(y1) = y.checked_sub(1) {
-                if x == 0 || y != 14 {
-                    graph.add_edge(graph_nodes[&(x, y)], graph_nodes[&(x, y1)], ());
-                }
+        }
+        let movable = nodes.iter().filter(|n| n.used <= empty.size);
+        let smallest = movable.clone().map(|n| n.size).min().unwrap_or(0);
+        for node in movable {
+            assert!(
+                node.used <= smallest,
+                "{:?} does not fit into every other node",
+                node
+            );
+            walls.set(node.y, node.x, false);
+        }
+        Cluster {
+            walls,
+            empty: Point::from_index(empty.y, empty.x),
+            goal: Point::from_index(0, width - 1),
+        }
+    }
+
+    fn is_open(&self, p: Point) -> bool {
+        p.index_in(&self.walls)
+            .map_or(false, |(row, col)| !self.walls.get(row, col).unwrap())
+    }
+
+    /// The steps to move the empty node next to the goal data and then
+    /// shuffle that to the origin along the top row, five steps a node: it
+    /// takes the empty node four steps to get around in front of the data
+    /// again through the second row, and one to swap with it. This is only
+    /// the fewest steps if neither row has walls.
+    fn shortcut(&self) -> Option<usize> {
+        let columns = self.walls.columns() as isize;
+        if columns < 2
+            || (0..columns)
+                .any(|x| !self.is_open(Point::new(x, 0)) || !self.is_open(Point::new(x, 1)))
+        {
+            return None;
+        }
+        let next_to_goal = self.goal + Direction::Left;
+        let to_goal = search::bfs(
+            self.empty,
+            |&p| {
+                p.neighbours()
+                    .iter()
+                    .cloned()
+                    .filter(|&n| n != self.goal && self.is_open(n))
+                    .collect::<Vec<_>>()
+            },
+            |&p| p == next_to_goal,
+        );
+        Some(to_goal.goal_cost()? + 1 + 5 * (columns as usize - 2))
+    }
+
+    /// Searches over every position of both the empty node and the goal
+    /// data.
+    fn search(&self) -> Option<usize> {
+        let search = search::bfs(
+            (self.empty, self.goal),
+            |&(empty, goal)| {
+                empty
+                    .neighbours()
+                    .iter()
+                    .cloned()
+                    .filter(|&n| self.is_open(n))
+                    .map(|n| (n, if n == goal { empty } else { goal }))
+                    .collect::<Vec<_>>()
+            },
+            |&(_, goal)| goal == Point::ORIGIN,
+        );
+        search.goal_cost()
+    }
+}
+
+impl fmt::Debug for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.walls.rows() {
+            for col in 0..self.walls.columns() {
+                let p = Point::from_index(row, col);
+                let c = if p == self.empty {
+                    '_'
+                } else if p == self.goal {
+                    'G'
+                } else if !self.is_open(p) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
             }
+            writeln!(f)?;
         }
+        Ok(())
     }
-    let (distance_to_data, _) = ::petgraph::algo::astar(
-        &graph,
-        graph_nodes[&(17, 22)],
-        |n| n == graph_nodes[&(37, 0)],
-        |_| 1,
-        |_| 0,
-    ).unwrap();
-    let (distance_to_goal, _) = ::petgraph::algo::astar(
-        &graph,
-        graph_nodes[&(37, 0)],
-        |n| n == graph_nodes[&(0, 0)],
-        |_| 1,
-        |_| 0,
-    ).unwrap();
-    // goal * 5 because we have to dance around it in 5-step increments along the straight path
-    // goal - 1 because we don't need to actually hit it; we're going to stop a bit before it - from
-    // there we can shift once to get into the position (something accounted by distance_to_data,
-    // which is one away from the actual distance).
-    //
-    // Realistically, though, the "better" way to do this is to do so by hand. The puzzle we're
-    // solving is fairly simple, and can be done trivially by hand, avoiding the graph manipulations
-    // and heuristic based approaches we apply here.
-    distance_to_data + ((distance_to_goal - 1) * 5)
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Pattern)]
+#[pattern = "/dev/grid/node-x{x}-y{y} {size}T {used}T {available}T {_}%"]
 struct Node {
     x: usize,
     y: usize,
@@ -91,59 +168,38 @@ impl fmt::Debug for Node {
     }
 }
 
-impl fmt::Display for Node {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.used == 0 {
-            return write!(f, "_");
-        }
-
-        if self.used > 100 {
-            return write!(f, "#");
-        }
-
-        if self.x == 0 && self.y == 0 {
-            return write!(f, "T");
-        }
-
-        if self.x == 37 && self.y == 0 {
-            return write!(f, "G");
-        }
+/// Reads the `df` listing, skipping the command and the header.
+fn parse(s: &str) -> Vec<Node> {
+    let start = s.find("/dev/grid/node-").unwrap_or(s.len());
+    parse_lines(&s[start..]).unwrap_or_else(|e| panic!("{}", e.shifted(start).render(s)))
+}
 
-        write!(f, ".")
-    }
+#[test]
+fn example() {
+    let nodes = parse(
+        "root@ebhq-gridcenter# df -h
+Filesystem            Size  Used  Avail  Use%
+/dev/grid/node-x0-y0   10T    8T     2T   80%
+/dev/grid/node-x0-y1   11T    6T     5T   54%
+/dev/grid/node-x0-y2   32T   28T     4T   87%
+/dev/grid/node-x1-y0    9T    7T     2T   77%
+/dev/grid/node-x1-y1    8T    0T     8T    0%
+/dev/grid/node-x1-y2   11T    7T     4T   63%
+/dev/grid/node-x2-y0   10T    6T     4T   60%
+/dev/grid/node-x2-y1    9T    8T     1T   88%
+/dev/grid/node-x2-y2    9T    6T     3T   66%",
+    );
+    let cluster = Cluster::new(&nodes);
+    assert_eq!(format!("{:?}", cluster), "..G\n._.\n#..\n");
+    assert_eq!(cluster.shortcut(), Some(7));
+    assert_eq!(cluster.search(), Some(7));
 }
 
-fn parse(s: &str) -> Vec<Node> {
-    s.trim()
-        .lines()
-        .map(|line| {
-            let dash1 = "/dev/grid/node-".len();
-            let dash1 = dash1 + 1 + line[dash1 + 1..].find('-').unwrap();
-            let x = line["/dev/grid/node-x".len()..dash1].parse().unwrap();
-            let space1 = line.find(' ').unwrap();
-            let y = line[line.find('y').unwrap() + 1..space1].parse().unwrap();
-            let without_prefix = &line["/dev/grid/node-x##-y##".len()..];
-            let first_t = without_prefix.find('T').unwrap();
-            let second_t = 1 + first_t + without_prefix[first_t + 1..].find('T').unwrap();
-            let third_t = 1 + second_t + without_prefix[second_t + 1..].find('T').unwrap();
-            let size = without_prefix[..first_t].trim_left().parse().unwrap();
-            let used = without_prefix[first_t + 1..second_t]
-                .trim()
-                .parse()
-                .unwrap();
-            let available = without_prefix[second_t + 1..third_t]
-                .trim()
-                .parse()
-                .unwrap();
-            Node {
-                x,
-                y,
-                size,
-                used,
-                available,
-            }
-        })
-        .collect()
+#[test]
+fn shortcut_matches_search() {
+    let cluster = Cluster::new(&parse(INPUT));
+    assert!(cluster.shortcut().is_some());
+    assert_eq!(cluster.shortcut(), cluster.search());
 }
 
 pub static INPUT: &str = include_str!("day22.input");
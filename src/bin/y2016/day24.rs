@@ -1,108 +1,66 @@
-use std::cmp;
-use std::collections::HashMap;
+use advent_of_code::maze::{Distances, Maze};
 
-use permutohedron::Heap;
-use petgraph::{algo, graph::NodeIndex, Graph, Undirected};
-
-type Maze = Graph<(usize, usize), (), Undirected>;
-
-fn parse(
-    s: &str,
-) -> (
-    Graph<(usize, usize), (), Undirected>,
-    HashMap<(usize, usize), NodeIndex>,
-    HashMap<usize, NodeIndex>,
-) {
-    let mut map = HashMap::new();
-    let mut landmarks = HashMap::new();
-    let mut graph = Graph::new_undirected();
-    for (y, line) in s.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if ch == '.' || ch.is_digit(10) {
-                let idx = graph.add_node((x, y));
-                map.insert((x, y), idx);
-
-                if let Some(digit) = ch.to_digit(10) {
-                    landmarks.insert(digit as usize, idx);
-                }
-
-                if let Some(x1) = x.checked_sub(1) {
-                    if let Some(before) = map.get(&(x1, y)) {
-                        graph.add_edge(idx, *before, ());
-                    }
-                }
+fn distances(s: &str) -> Distances<u32> {
+    Maze::parse(s, |c| c.to_digit(10)).distances()
+}
 
-                if let Some(y1) = y.checked_sub(1) {
-                    if let Some(before) = map.get(&(x, y1)) {
-                        graph.add_edge(idx, *before, ());
-                    }
+/// The fewest steps that visit every numbered point, starting at 0 and, if
+/// `back`, returning there.
+///
+/// `best[visited][at]` is the shortest walk from 0 through the points in
+/// `visited` that ends at `at`; every walk only gets longer as points are
+/// added, so the sets are filled in increasing order.
+fn shortest_route(distances: &Distances<u32>, back: bool) -> usize {
+    let n = distances.len();
+    let start = distances.index(0).expect("no point 0");
+    let distance = |a, b| distances.get(a, b).expect("unreachable point");
+    let mut best = vec![vec![None; n]; 1 << n];
+    best[1 << start][start] = Some(0);
+    for visited in 1..1usize << n {
+        for at in 0..n {
+            let length = match best[visited][at] {
+                Some(length) => length,
+                None => continue,
+            };
+            for next in (0..n).filter(|&next| visited & 1 << next == 0) {
+                let length = length + distance(at, next);
+                let entry = &mut best[visited | 1 << next][next];
+                if entry.map_or(true, |best| length < best) {
+                    *entry = Some(length);
                 }
             }
         }
     }
-    (graph, map, landmarks)
-}
-
-fn shortest_distance<F: FnMut(NodeIndex) -> bool>(
-    graph: &Maze,
-    start: NodeIndex,
-    goal: F,
-) -> (usize, NodeIndex) {
-    let (distance, path) = algo::astar(graph, start, goal, |_| 1, |_| 0).unwrap();
-    (distance, *path.last().unwrap())
+    best[(1 << n) - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(at, length)| {
+            let back = if back { distance(at, start) } else { 0 };
+            Some(length.as_ref()? + back)
+        })
+        .min()
+        .unwrap()
 }
 
 pub fn part1(s: &str) -> usize {
-    let (graph, _map, landmarks) = parse(s);
-    let mut v = landmarks
-        .values()
-        .cloned()
-        .filter(|v| *v != landmarks[&0])
-        .collect::<Vec<_>>();
-
-    let heap = Heap::new(&mut v);
-    let mut minimum = usize::max_value();
-    'p: for mut order in heap {
-        let mut total_distance = 0;
-        let mut previous = landmarks[&0];
-        while let Some(next) = order.pop() {
-            let (dist, to_node) = shortest_distance(&graph, previous, |n| n == next);
-            total_distance += dist;
-            if total_distance >= minimum {
-                continue 'p;
-            }
-            previous = to_node;
-        }
-        minimum = cmp::min(minimum, total_distance);
-    }
-    minimum
+    shortest_route(&distances(s), false)
 }
 
 pub fn part2(s: &str) -> usize {
-    let (graph, _map, landmarks) = parse(s);
-    let mut v = landmarks
-        .values()
-        .cloned()
-        .filter(|v| *v != landmarks[&0])
-        .collect::<Vec<_>>();
+    shortest_route(&distances(s), true)
+}
 
-    let heap = Heap::new(&mut v);
-    let mut minimum = usize::max_value();
-    'p: for mut order in heap {
-        let mut total_distance = 0;
-        let mut previous = landmarks[&0];
-        while let Some(next) = order.pop() {
-            let (dist, to_node) = shortest_distance(&graph, previous, |n| n == next);
-            total_distance += dist;
-            if total_distance >= minimum {
-                continue 'p;
-            }
-            previous = to_node;
-        }
-        total_distance += shortest_distance(&graph, previous, |n| n == landmarks[&0]).0;
-        minimum = cmp::min(minimum, total_distance);
-    }
-    minimum
+#[test]
+fn example() {
+    let example = "
+###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+###########
+";
+    assert_eq!(part1(example), 14);
+    assert_eq!(part2(example), 20);
 }
 
 pub static INPUT: &str = include_str!("day24.input");
@@ -52,11 +52,6 @@ fn part1_1() {
     );
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 45158);
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(
@@ -70,11 +65,6 @@ fn part2_1() {
     );
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 294);
-}
-
 pub static INPUT: &str = "
 86 440 233 83 393 420 228 491 159 13 110 135 97 238 92 396
 3646 3952 3430 145 1574 2722 3565 125 3303 843 152 1095 3805 134 3873 3024
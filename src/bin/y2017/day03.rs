@@ -128,11 +128,6 @@ fn part1_4() {
     assert_eq!(part1(1024), 31);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 552);
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(part2(1), 2);
@@ -158,9 +153,4 @@ fn part2_5() {
     assert_eq!(part2(23), 25);
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 330785);
-}
-
 pub const INPUT: usize = 325489;
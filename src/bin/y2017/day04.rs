@@ -54,11 +54,6 @@ fn part1_3() {
     assert!(!is_valid_part1("aa bb cc dd aa"));
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 455);
-}
-
 #[test]
 fn part2_1() {
     assert!(is_valid_part2("abcde fghij"));
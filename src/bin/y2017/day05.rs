@@ -23,16 +23,6 @@ pub fn part2(i: &[isize]) -> usize {
     eval(i, |v| if v >= 3 { -1 } else { 1 })
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 315613);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 22570529);
-}
-
 pub static INPUT: &[isize] = &[
     1, 1, 1, 1, 0, 0, 0, -4, -1, 0, -3, -4, 0, -9, -3, 2, -14, 0, -17, -12, -15, -7, 0, -7, -12,
     -3, -17, -11, -24, -10, -16, -15, -28, -13, -28, -15, -28, -29, -20, 0, -10, -30, -13, -24,
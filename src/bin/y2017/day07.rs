@@ -131,11 +131,6 @@ a (00) -> b, e
     );
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), "dgoocsw");
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(
@@ -159,11 +154,6 @@ cntj (57)"
     );
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 1275);
-}
-
 pub static INPUT: &str = "
 uglvj (99) -> ymfjt, gkpgf
 vvwrg (51)
@@ -1,47 +1,78 @@
-use advent_of_code::Parser;
-
-pub fn eval(s: &str) -> (usize, usize) {
-    let mut total = 0;
-    let mut garbage = 0;
-    let mut in_groups = 0;
-    let mut in_garbage = false;
-    let mut p = Parser::new(s.as_bytes());
-    while let Some(ch) = p.read() {
-        if in_garbage {
-            match ch {
-                b'!' => {
-                    p.advance();
-                }
-                b'>' => {
-                    in_garbage = false;
-                }
-                _ => garbage += 1,
-            }
-        } else {
-            match ch {
-                b'{' => {
-                    in_groups += 1;
-                }
-                b'}' => {
-                    total += in_groups;
-                    in_groups -= 1;
+use advent_of_code::{Parser, ParserError};
+
+#[derive(Default)]
+struct Stream {
+    score: usize,
+    garbage: usize,
+}
+
+impl Stream {
+    fn thing(&mut self, p: &mut Parser, depth: usize) -> Result<(), ParserError> {
+        match p.cur() {
+            Some(b'{') => self.group(p, depth + 1),
+            Some(b'<') => self.garbage(p),
+            _ => Err(p.unexpected("'{' or '<'")),
+        }
+    }
+
+    fn group(&mut self, p: &mut Parser, depth: usize) -> Result<(), ParserError> {
+        p.consume(b'{')?;
+        p.sep_by(",", |p| self.thing(p, depth))?;
+        p.consume(b'}')?;
+        self.score += depth;
+        Ok(())
+    }
+
+    fn garbage(&mut self, p: &mut Parser) -> Result<(), ParserError> {
+        p.consume(b'<')?;
+        loop {
+            match p.read() {
+                Some(b'!') => {
+                    p.read()
+                        .ok_or_else(|| p.unexpected("cancelled character"))?;
                 }
-                b'<' => in_garbage = true,
-                _ => {}
+                Some(b'>') => return Ok(()),
+                Some(_) => self.garbage += 1,
+                None => return Err(p.unexpected("'>'")),
             }
         }
     }
-    (total, garbage)
+}
+
+pub fn eval(s: &str) -> Result<(usize, usize), ParserError> {
+    let mut stream = Stream::default();
+    let mut p = Parser::new(s.trim().as_bytes());
+    stream.thing(&mut p, 0)?;
+    p.end()?;
+    Ok((stream.score, stream.garbage))
+}
+
+fn eval_or_panic(s: &str) -> (usize, usize) {
+    eval(s).unwrap_or_else(|e| panic!("{}", e.render(s.trim())))
 }
 
 pub fn part1(s: &str) -> usize {
-    eval(s).0
+    eval_or_panic(s).0
 }
 
 pub fn part2(s: &str) -> usize {
-    eval(s).1
+    eval_or_panic(s).1
 }
 
+#[test]
+fn malformed() {
+    let error = eval("{{<a>},{<b>}}}").unwrap_err();
+    assert_eq!(error.span.start, 13);
+    let error = eval("{{<a>}{<b>}}").unwrap_err();
+    assert_eq!(
+        error.render("{{<a>}{<b>}}"),
+        "line 1, column 7: expected '}', found '{'\n{{<a>}{<b>}}\n      ^"
+    );
+    assert_eq!(
+        eval("{<a!>}").unwrap_err().to_string(),
+        "expected '>', found end of input at byte 6"
+    );
+}
 #[test]
 fn part1_1() {
     assert_eq!(part1("<>"), 0);
@@ -117,11 +117,6 @@ fn part1_1() {
     assert_eq!(c[0] * c[1], 12);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 7888);
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(part2(""), "a2582a3a0e66e6e86e3812dcb672a272");
@@ -142,9 +137,4 @@ fn part2_4() {
     assert_eq!(part2("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), "decdf7d377879877173b7f2fb131cf1b");
-}
-
 pub static INPUT: &str = "70,66,255,2,48,0,54,48,80,141,244,254,160,108,1,41";
@@ -146,16 +146,6 @@ fn part2_1() {
     assert_eq!(part2(EXAMPLE), 2);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 239);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 215);
-}
-
 #[cfg(test)]
 static EXAMPLE: &str = "
 0 <-> 2
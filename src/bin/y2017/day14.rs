@@ -1,4 +1,4 @@
-use day10::knot_hash;
+use super::day10::knot_hash;
 use std::u128;
 
 pub fn part1(s: &str) -> u32 {
@@ -116,21 +116,11 @@ fn part1_1() {
     assert_eq!(part1(EXAMPLE), 8108);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 8214);
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(part2(EXAMPLE), 1242);
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 1093);
-}
-
 #[cfg(test)]
 pub static EXAMPLE: &str = "flqrgnkx";
 pub static INPUT: &str = "hxtvlmkl";
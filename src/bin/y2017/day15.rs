@@ -129,16 +129,6 @@ fn part2_1() {
     assert_eq!(part2((65, 8921)), 309);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 569);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 298);
-}
-
-gen_single!();
+gen_single!(2017, 15);
 
 pub static INPUT: (u64, u64) = (116, 299);
@@ -1,24 +1,35 @@
+use advent_of_code::permutation::{Permutation, Shuffle};
 use std::mem;
-use advent_of_code::swap;
 
-fn eval(dancers: &mut [u8], instructions: &[Instruction]) {
+/// The whole dance as one shuffle of the dancers' positions and names.
+fn dance(dancers: usize, instructions: &[Instruction]) -> Shuffle {
+    let mut positions = (0..dancers).collect::<Vec<_>>();
+    let mut names = positions.clone();
     for &instr in instructions {
         match instr {
-            Instruction::Rotate(k) => dancers.rotate_left(k),
-            Instruction::SwapPositions(a, b) => unsafe { swap(dancers, a, b) },
+            Instruction::Rotate(k) => positions.rotate_left(k),
+            Instruction::SwapPositions(a, b) => positions.swap(a, b),
             Instruction::SwapDancers(a, b) => {
-                let pos0 = dancers.iter().position(|&v| v == a || v == b).unwrap();
-                let pos1 = pos0 + 1
-                    + dancers[pos0 + 1..]
-                        .iter()
-                        .position(|&v| v == a || v == b)
-                        .unwrap();
-                unsafe {
-                    swap(dancers, pos0, pos1);
-                }
+                let (a, b) = ((a - b'a') as usize, (b - b'a') as usize);
+                let pos0 = names.iter().position(|&v| v == a).unwrap();
+                let pos1 = names.iter().position(|&v| v == b).unwrap();
+                names.swap(pos0, pos1);
             }
         }
     }
+    Shuffle {
+        positions: Permutation::arrangement(&positions),
+        labels: Permutation::new(names),
+    }
+}
+
+fn perform(shuffle: &Shuffle) -> String {
+    let dancers = (0..shuffle.positions.len()).collect::<Vec<_>>();
+    shuffle
+        .apply(&dancers)
+        .into_iter()
+        .map(|d| (b'a' + d as u8) as char)
+        .collect()
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Hash, PartialOrd, Ord, Eq)]
@@ -64,43 +75,19 @@ fn parse(dancers: usize, instructions: &str) -> Vec<Instruction> {
 }
 
 pub fn part1(s: &str) -> String {
-    let mut dancers: [u8; 16] = *b"abcdefghijklmnop";
-    let instructions = parse(dancers.len(), s);
-    eval(&mut dancers, &instructions);
-    String::from_utf8(dancers.to_vec()).unwrap()
+    let instructions = parse(16, s);
+    perform(&dance(16, &instructions))
 }
 
 pub fn part2(s: &str) -> String {
-    let mut dancers: [u8; 16] = *b"abcdefghijklmnop";
-    let instructions = parse(dancers.len(), s);
-    let mut seen = Vec::new();
-    let iterations = 1_000_000_000;
-    for i in 0..iterations {
-        if seen.contains(&dancers) {
-            return String::from_utf8(seen[iterations % i].to_vec()).unwrap();
-        }
-        seen.push(dancers.clone());
-        eval(&mut dancers, &instructions);
-    }
-    unreachable!()
+    let instructions = parse(16, s);
+    perform(&dance(16, &instructions).pow(1_000_000_000))
 }
 
 #[test]
 fn part1_1() {
-    let mut dancers = *b"abcde";
-    let instructions = parse(dancers.len(), "s1,x3/4,pe/b");
-    eval(&mut dancers, &instructions);
-    assert_eq!(::std::str::from_utf8(&dancers).unwrap(), "baedc");
-}
-
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), "doeaimlbnpjchfkg");
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), "agndefjhibklmocp");
+    let instructions = parse(5, "s1,x3/4,pe/b");
+    assert_eq!(perform(&dance(5, &instructions)), "baedc");
 }
 
 pub static INPUT: &str = "s2,x15/10,s4,x13/0,pe/j,x11/15,pa/g,x13/2,s10,x12/11,pj/h,x15/8,s9,x11/14,s5,x6/2,s9,x15/12,s11,pm/a,s8,x2/8,pf/e,x12/6,pk/j,x1/10,s7,x3/7,s11,x12/0,ph/p,x1/3,s5,pn/m,x11/4,s1,x15/0,s6,x2/12,s4,pi/d,x14/4,s2,x8/9,pm/p,x0/5,po/d,s13,x2/9,pl/b,x7/15,pj/f,x12/3,pi/c,x11/4,s4,x12/9,s4,x11/6,pa/j,x14/10,pi/m,x8/15,s12,x5/10,s11,x1/4,s4,x8/13,pk/h,x14/4,pf/l,x8/10,pj/o,x15/2,s15,x12/0,s10,x11/5,pb/g,x7/3,s4,x13/9,s6,pd/j,x15/7,s11,x14/13,s11,x5/15,pn/k,x9/12,pe/c,x13/2,pd/o,x9/8,pn/m,x2/12,pi/g,x4/5,s7,x8/13,pb/h,s5,x11/12,s1,x13/2,pi/e,x12/8,s15,x5/6,s9,pf/k,x1/8,pd/h,x4/9,pp/j,x12/6,s8,pg/h,x11/15,s13,x8/1,pf/c,s10,pi/e,x0/15,s13,x6/9,s4,x12/14,s3,x4/2,s1,x1/9,s5,x0/6,s2,x2/7,s14,x13/14,pf/c,s6,x15/11,s6,x3/14,s13,pj/o,x5/8,s7,x12/10,pa/h,x8/2,s6,x10/13,pd/k,x12/8,s5,x0/5,s9,x13/12,pl/j,s9,x6/7,s4,x1/3,s7,x6/2,pp/m,x1/4,pg/e,x2/10,pb/p,x3/5,pk/f,x4/8,pc/e,x13/12,pl/p,x1/11,pb/c,x5/6,ph/e,x14/12,pc/l,x2/9,s7,x4/12,pn/j,x15/1,s11,x2/9,pc/g,x6/14,pa/d,x10/8,ph/o,x9/6,s4,pc/n,x0/4,s12,x3/2,pp/a,x12/10,s9,x0/2,pd/f,x14/10,s8,x3/9,s8,x0/6,pa/b,x14/15,pc/j,x9/4,s5,x11/6,pd/a,x9/2,s12,x12/3,s2,x13/14,pc/e,x3/15,s7,x5/14,pb/m,x9/0,s15,x6/11,s7,x8/7,s4,x4/5,s13,x0/6,s13,pe/o,x2/10,s10,x8/6,pf/d,x15/3,pm/o,x2/8,s12,x7/3,s9,x14/5,s2,x13/8,pa/c,x6/4,pp/g,s1,x8/7,s14,x15/1,s9,x0/3,s15,pc/d,x13/11,s1,x15/6,pe/g,x2/4,s8,x13/10,pb/k,x12/4,s15,x2/5,s4,x7/1,pa/f,x14/5,ph/n,s6,po/b,x9/3,s14,pp/n,s15,x1/8,pj/f,x14/0,s5,x9/10,pp/l,x11/7,s7,x0/5,s1,x13/12,s11,x2/15,s8,x12/10,s9,x11/5,pi/j,x9/1,s6,x12/15,pg/f,x7/13,pk/e,x11/14,s8,x4/3,s8,x10/11,pc/f,x15/5,pn/b,x10/4,pd/h,x1/3,pm/i,x9/2,s3,x1/12,pd/k,x7/15,s12,x12/8,s15,x9/4,pl/m,x15/14,s9,x2/11,s1,x14/0,pk/f,s8,x9/4,s3,x5/6,s14,x12/11,po/c,x1/0,s15,x10/4,s9,x1/14,s11,x5/6,s13,x1/10,s2,x2/4,s14,x0/14,pe/b,x7/8,s14,pl/d,x12/1,s2,pa/e,x2/9,pf/g,x7/1,po/m,x9/2,pg/j,x8/14,s13,x2/9,s9,x15/13,s13,x10/5,ph/d,x15/0,s5,x14/2,s7,x8/3,s11,x15/2,s12,x14/5,po/e,x6/7,s8,x10/13,s13,x12/5,s6,x4/15,pa/i,x14/0,s14,x8/15,s7,x5/6,pn/m,x1/10,s6,x14/8,ph/e,x0/9,s12,x4/2,s10,x10/6,s4,x11/8,pk/b,x5/6,pf/d,x7/8,pc/j,x11/1,s12,x10/7,s14,x6/3,s2,x1/15,s6,x7/0,s4,x6/12,s3,x1/11,pb/n,x5/14,s4,x9/6,s12,x2/12,pl/c,x15/11,pe/g,x8/7,pd/j,x11/6,s11,x15/0,pk/g,x4/5,pc/b,x15/1,s14,x6/0,ph/l,x10/2,s3,x5/12,pf/a,x6/4,s5,x11/3,s10,x2/12,s9,x13/11,s4,x3/10,pb/e,x9/12,s11,x2/8,s6,x14/6,s5,x5/4,s4,x7/0,pm/d,x5/14,pb/p,x13/0,s4,x15/11,pg/o,x13/6,s15,x2/4,s4,x9/14,s7,x5/10,s1,pb/m,s2,x12/14,s5,x4/7,s11,x3/6,s12,x4/1,s10,x15/0,s5,x7/10,pn/c,x5/1,ph/p,x8/10,pj/i,x0/9,s2,x1/5,s11,x10/2,s7,x8/11,pc/d,x14/15,pf/o,x6/12,s3,x15/2,s15,x7/0,s4,x2/11,s4,x8/14,pn/k,x11/7,s13,x0/14,pa/i,x1/8,pn/b,x5/10,pe/a,x14/2,pi/j,x6/4,pa/e,x15/10,s7,x13/14,pm/d,s11,x15/9,s8,x14/2,pj/g,x13/11,s11,pb/l,x15/14,pg/m,x8/4,s11,x6/11,s1,x12/14,pe/h,x6/1,s3,x9/7,s14,x10/13,po/n,s7,x4/15,s2,x8/1,s14,x14/5,s11,x13/11,pc/g,x8/2,pp/e,x14/0,s7,po/h,x11/6,pm/k,s3,x4/12,pa/d,x1/9,pf/j,x5/6,pp/b,x11/12,s6,x9/4,s4,x11/14,pk/m,x1/13,s10,x9/4,s5,x14/1,s14,x12/9,pb/p,x8/4,s6,x11/14,pe/n,x15/4,s11,x12/1,s12,x8/9,pk/l,s7,x0/2,po/e,x15/10,pj/g,s9,x9/0,pk/b,x12/4,s4,x13/0,pj/c,x12/1,pf/k,x2/14,s7,x8/4,s13,x1/10,pj/n,x4/7,pg/d,s15,x14/9,s6,x4/8,pb/j,x7/5,pk/g,x4/11,pe/n,x6/9,pj/f,x3/13,pn/a,x10/1,s11,x13/6,s3,x3/7,s4,x10/6,pb/e,x13/2,s5,x5/8,pk/o,x1/0,ph/e,x10/2,s8,x6/15,pa/p,x4/9,pb/o,x2/14,s15,x12/15,s10,x7/8,s9,x13/5,pl/g,s1,x10/14,pn/d,x1/15,s11,x6/8,s4,x0/9,s2,x11/13,pf/e,s12,x7/0,s7,x13/10,s15,x8/4,s14,x11/2,s5,x1/12,s7,x14/2,s4,x5/4,pm/k,s14,x6/3,po/j,x12/10,s6,x7/11,s6,x1/8,pc/e,x11/0,pi/a,x10/7,s13,pc/m,x11/3,pf/b,x10/6,s14,x2/8,pn/i,x0/6,s3,x7/13,s5,x15/5,s7,x13/7,s11,pm/j,x14/11,s6,x15/1,s2,x4/9,pe/i,x15/7,pd/l,x14/6,s6,x5/3,pn/g,x6/12,pd/b,s8,x5/9,pg/e,x15/1,s15,x3/9,ph/j,x14/1,pk/f,x11/9,pm/a,x0/4,s11,x11/13,s14,x2/10,po/n,x7/0,pd/h,x14/4,pn/e,x8/12,s8,x10/2,pc/h,x3/13,po/l,x6/9,pp/c,x2/0,pl/m,x11/7,s15,x13/8,s2,x10/6,s10,x7/11,pk/n,x9/13,s3,x12/3,s4,x9/15,s4,x3/0,s1,x12/6,s12,x0/15,s5,x11/13,pc/g,x8/10,pd/b,x9/1,pf/j,x2/0,s2,x10/14,pd/g,x5/2,s8,x15/13,s9,pm/j,x1/11,s13,x4/2,s14,x7/9,s12,x1/5,s5,x2/15,s7,x0/1,s2,x6/2,pk/b,x12/4,pn/m,s6,x1/2,pa/h,x4/6,pf/j,x13/7,pb/i,s8,x3/4,pp/d,x15/10,ph/f,s1,x13/0,po/i,s12,x3/2,s1,x8/15,s8,x13/7,s2,x9/14,pm/h,x8/11,pk/b,x12/6,s9,x0/1,s13,x15/3,pp/g,x8/10,s13,x12/11,s5,x10/7,s10,x13/11,pa/j,s6,pi/k,x15/3,s6,x8/11,pd/h,x6/5,s1,pe/i,s2,x1/11,ph/f,x13/0,s10,pb/l,s5,x6/10,s10,x13/11,s12,x10/2,pa/g,s6,x3/14,pl/c,x6/9,s10,x0/11,pe/i,s14,x4/14,pb/k,x7/12,pn/e,s5,x0/5,pi/k,x14/7,s15,x2/4,s5,x1/8,s15,x7/0,s4,x3/4,pm/d,x11/6,ph/j,x4/1,pk/e,x13/2,s5,x12/5,s13,pi/d,s11,pp/h,s6,x2/14,pb/n,x9/12,po/e,x15/11,s1,x10/12,s6,x13/7,pa/j,x9/14,s6,x15/7,s3,pc/h,x8/4,s6,x3/2,s5,x7/11,s15,x4/13,pm/p,x10/1,pl/k,x13/6,s6,x1/3,pn/o,x8/15,s7,x9/3,pg/k,x8/14,s13,x0/7,s8,x13/11,pe/i,x1/5,pc/g,s5,pe/n,x12/3,s10,x2/7,s9,x9/15,pp/j,x8/12,s15,x10/11,s7,x4/9,s14,pl/a,s6,x7/13,s14,x4/8,s14,x0/13,pi/e,x1/2,pa/d,x5/8,s13,x14/1,s7,x6/4,s6,x5/2,pb/i,x14/9,s10,x10/15,pd/e,x9/7,s3,x10/1,s3,pi/m,x15/12,s3,x6/8,pf/b,x11/5,s2,x8/3,s11,x12/10,pp/l,x6/15,s11,x9/11,s4,x14/7,s2,x4/8,s4,x7/11,s7,x3/5,pg/j,s4,x0/14,po/d,x12/3,s5,x5/6,s5,x15/7,pe/l,x2/3,pk/m,x8/11,s11,x14/3,s12,x9/2,pi/o,x3/6,pb/l,x1/13,s14,ph/f,x14/12,pg/n,x11/3,s4,x7/6,ph/j,x14/15,s2,x5/4,pg/k,x2/11,pf/o,x3/4,pn/g,x9/14,pd/l,x4/13,s6,x7/0,s4,x13/8,pi/f,x9/5,po/b,x12/8,s14,x6/0,s6,x15/7,s5,x14/8,s1,x6/15,s3,x1/12,s2,x9/11,pl/n,x5/1,pd/e,x11/9,pg/c,x8/12,pm/d,x10/1,s14,x4/14,s10,x3/13,pj/i,x9/11,pn/m,x6/7,s12,x12/1,s11,x13/4,s7,x8/6,ph/j,x4/2,s1,x0/13,s2,x12/4,pn/f,x15/9,pj/d,x10/14,s15,x8/3,pp/b,x6/14,pl/j,x3/15,pm/b,x10/13,pf/h,x1/6,s7,x14/13,pl/o,x9/1,s13,x7/14,s10,x8/15,s4,x1/13,pg/p,x15/14,s2,x0/13,s7,x7/8,s6,x13/2,s2,x0/11,s3,x12/10,s14,x2/15,s13,x4/14,pk/f,s7,x11/1,pd/g,x13/14,pn/j,x1/15,pb/i,x4/3,s5,x10/1,s15,pm/c,s9,x9/8,pg/f,x4/10,s6,x5/1,pk/d,x6/3,pf/e,x2/11,s12,x13/1,po/h,x3/5,pk/j,s5,x6/9,pc/e,s3,x4/2,s9,x12/1,pn/l,x5/6,pk/h,x2/7,pp/l,s6,pb/m,x3/14,pl/i,x8/13,s2,x12/4,s9,x1/10,s2,x15/3,pk/f,x1/4,po/c,x3/6,s2,pn/g,x15/0,ph/o,x4/14,s3,x15/1,pj/f,x10/14,s4,x0/3,pp/m,x6/5,s9,x11/15,s5,x4/10,s11,x13/9,s15,x5/12,s4,x8/10,s14,pl/b,x5/4,s2,x0/12,pn/e,x11/1,s2,po/g,x13/15,pi/b,s3,x4/0,s9,x15/14,pa/k,x13/5,pl/m,x1/12,pb/k,x4/3,s3,x15/12,pj/i,x0/7,s1,x13/14,s6,x4/2,s4,x14/11,s3,x12/3,pc/l,x8/4,s15,x1/13,pn/o,x0/10,pi/b,x13/2,s2,pp/f,x15/8,s7,pb/m,x5/10,s8,x7/3,s15,x13/1,pe/j,s7,x8/14,pk/n,x5/6,s11,x10/11,pm/d,x4/12,s13,x5/14,pf/n,x13/3,pl/g,x2/8,pa/f,x4/13,pn/k,s13,x8/9,pf/c,x2/6,s12,x11/3,s2,x2/15,s10,x1/6,s7,pm/i,x14/12,s13,x4/6,pa/n,x11/0,pl/e,x4/15,s2,x13/14,s2,x10/7,s6,x15/0,s1,x5/7,s7,pm/g,x6/3,s9,x13/14,s11,x0/6,s15,pf/b,x1/5,s5,x2/11,s3,x14/4,pi/c,x9/0,s13,x4/10,pj/g,s7,x2/0,ph/i,x7/5,s7,x11/13,pl/k,x15/8,s3,x13/10,s11,x7/0,s13,pp/h,x8/13,s13,x5/4,s2,x6/10,s14,x2/14,s13,x12/10,s8,x5/6,s4,x7/1,s6,x14/2,s10,x7/13,s7,x0/10,pk/g,x1/2,pp/c,x15/5,pd/i,x11/1,s11,pl/o,x7/2,s8,x5/8,s4,x9/11,pp/a,x3/7,s5,x4/8,s11,x9/7,s4,x5/4,s11,x3/14,pn/h,x15/6,pf/e,x14/10,pm/o,x7/5,s8,x0/13,s5,pc/g,x5/2,s7,x12/0,s11,x15/10,s10,x12/14,s14,x11/3,ph/d,x5/7,s7,x3/0,s8,x6/4,s14,x10/12,s4,x8/0,s10,x12/13,s4,x10/0,pi/a,x8/7,s7,x9/10,s9,x13/8,s1,x9/2,pf/d,x12/4,pb/l,x13/8,s8,pn/g,x2/3,pb/o,x11/10,pa/k,x15/3,pm/b,x1/10,s4,x15/0,s7,x2/4,s5,x3/8,s5,pl/c,x0/6,s1,x15/4,s8,x10/0,s3,x7/14,s12,x5/0,pj/f,x1/13,pp/h,x6/11,s7,x12/2,s11,x7/8,s5,x15/13,pf/c,x1/3,s13,ph/p,x2/0,s1,x15/11,s14,x6/1,s14,pf/d,x4/7,s9,x5/1,s12,x3/14,pn/k,s3,x7/8,s15,x3/13,pp/f,x15/12,pa/m,x3/7,s14,x1/5,s13,x15/11,po/d,s14,x9/14,s1,x1/10,s12,x8/3,pa/h,x7/13,s1,pj/o,x1/12,s1,x5/11,s14,x2/14,pd/i,x5/7,ph/p,x6/8,s6,x9/10,pm/i,s7,x12/6,s10,x13/5,s13,x7/4,s9,x14/2,s3,x10/3,s15,x13/7,s11,po/d,x2/1,s13,x4/5,pk/m,x10/14,s3,x11/8,s11,x12/15,s9,x11/8,s2,x2/7,pe/c,x6/3,s8,x2/13,s15,x3/11,s13,x8/6,s12,x4/7,s7,x5/6,pi/b,x0/11,s12,x12/2,pn/k,x10/5,s2,x6/7,s1,x1/3,s2,x8/4,pe/o,s1,x7/11,s3,ph/f,s3,x6/3,pm/g,x15/4,s8,x7/1,s15,x3/2,s3,x14/8,pb/a,x9/5,s7,pl/f,x12/4,pc/k,x0/3,pm/g,x15/4,pi/c,s3,x2/14,pk/b,s10,x5/8,pn/g,s15,x2/1,pa/h,x10/13,s8,pi/c,x14/8,s3,x15/13,pj/o,x0/8,s13,x13/1,s4,x8/12,s11,x7/14,pc/m,s3,x12/0,s13,x15/5,pa/f,x13/9,s8,x4/2,pe/k,x6/15,s15,x14/5,pl/a,x12/7,s7,pd/g,x0/3,s3,x9/15,pe/f,x0/11,pp/c,x15/14,pm/g,x4/12,pn/k,x11/14,pm/g,x9/10,po/k,x13/2,s10,x5/8,s4,x6/14,pc/b,x1/7,pm/e,x14/5,s10,x3/13,s12,x11/15,s11,x7/12,pf/c,x9/13,pk/i,x8/12,s3,x9/0,pj/p,s8,x5/8,s15,x13/12,s6,x3/8,s14,x11/12,s15,x0/6,s5,x7/5,s1,x10/2,s12,pa/i,x9/8,s8,x12/15,s15,x0/6,s1,x10/5,s15,x15/14,s14,x8/4,s15,x3/12,pl/b,x14/10,s12,x2/9,pc/j,x15/4,pm/h,x12/1,s7,x4/15,pa/c,x12/11,s12,x14/3,pg/j,x6/7,s8,x3/14,s1,x13/15,pk/f,x10/1,pm/e,s2,x5/6,pd/b,x7/9,s3,x15/0,s3,x11/9,pf/m,x2/13,s12,x5/8,pk/a,x4/9,pd/f,x0/5,pj/h,x1/12,s9,x4/11,s14,x9/1,pc/i,x6/10,s2,x4/3,pb/f,x10/5,po/h,s3,x11/2,pc/f,x8/5,s11,x10/4,s14,x3/11,s8,x4/9,s9,x14/11,s4,x9/15,pe/m,x8/12,pc/g,s9,x1/14,pn/h,s14,pi/f,x10/8,s12,x9/12,pk/b,x2/8,s8,x13/7,pn/i,x2/14,s2,x3/11,s7,x13/14,pe/h,x7/2,pf/m,x15/12,s6,x1/7,s5,x14/8,s3,pl/j,s5,x1/3,s14,pe/b,x9/15,s4,pk/n,s14,x5/11,s2,x6/13,s12,x15/10,pe/m,x14/8,pc/g,x5/3,s2,x4/2,s3,x15/5,s4,x4/10,pe/a,x3/13,s8,x8/10,pj/l,x14/15,s7,x2/7,s9,x13/10,s6,x4/8,pg/e,x15/14,s15,x7/6,s6,x2/15,pk/m,x4/3,s14,x15/2,ph/c,x7/11,s6,pg/m,s3,x12/2,pf/p,x15/10,s5,pd/h,s1,x9/3,pe/p,x0/13,pf/g,x1/10,s14,pj/m,x9/0,pe/p,x10/15,pf/b,x1/13,s14,x5/12,pn/k,x2/7,s13,x13/14,s12,x2/12,pm/i,x13/6,pl/d,x10/5,s15,x3/6,s2,x13/7,s7,x15/6,po/i,x8/1,s14,x2/15,s1,x4/5,pd/e,s11,x12/7,po/c,x13/14,s4,x8/6,pd/a,x12/7,s10,x8/14,s4,x15/13,s15,x2/4,s11,x11/5,s5,x2/0,pn/p,s9,x13/9,pj/b,s3,x15/1,s1,x2/11,s15,x10/9,s1,x14/8,pp/k,x15/3,s3,x5/12,pb/m,x3/14,pe/o,s15,x12/13,pn/m,s7,x0/10,pj/f,x14/1,s9,x9/7,pi/m,x10/13,pl/f,s8,x8/2,s5,x14/1,s1,x2/3,s12,x15/1,s5,x6/0,s13,x3/2,pd/j,x13/7,s7,pg/f,x2/1,s2,x3/4,pb/d,x10/5,s5,x8/13,s13,x5/11,s1,x15/10,pi/c,x3/1,s5,pp/h,x0/9,s13,x2/6,s5,x0/8,s15,x9/7,pl/b,x11/12,pn/k,x1/8,pl/d,x11/14,s5,x5/10,pp/f,x15/4,s11,x6/9,s2,x12/8,pi/h,x15/2,pm/n,x9/5,pf/b,x8/4,s12,x12/14,s3,x3/11,s15,x8/6,pk/l,x1/4,s10,x8/0,s13,x7/1,s14,x15/8,s13,x9/13,s10,x4/11,pd/g,x14/5,s8,x7/15,s3,x2/11,s9,x9/1,s12,x0/4,s8,x3/8,s4,x11/14,s1,x6/4,s2,x3/12,pl/a,x0/13,pc/k,x2/9,s5,x3/1,pg/l,s14,x5/9,pf/c,s6,x0/3,pb/i,x13/14,s1,x15/0,pp/k,s1,x14/12,pf/g,x6/11,pn/h,s2,x2/8,pm/g,x7/5,pe/a,x15/11,s3,x5/12,ph/o,x4/2,s11,x8/0,s5,x4/12,s3,x7/13,s5,x12/15,s11,x2/3,s7,pa/n,x11/7,pl/d,x14/8,s10,x0/2,pj/o,x4/11,s6,x5/12,s15,x6/13,s5,pl/d,x7/3,s3,x15/8,pg/n,x5/11,s11,x12/9,ph/o,x15/10,pe/f,x4/14,s11,pk/n,x15/1,pb/f,x13/10,s4,pc/a,s2,x1/14,s1,x15/13,s8,x1/6,s15,x5/15,pe/h,x12/1,pi/g,x15/4,s9,x12/1,s2,x8/5,s9,x2/0,pb/h,x10/7,po/c,x13/12,s5,x1/15,pg/e,x2/0,ph/n,x8/14,pb/i,x11/12,s9,x6/5,s8,x4/13,s14,x15/10,s15,x12/7,s1,x15/1,s6,x11/12,s10,x3/8,s3,pn/j,x2/12,ph/i,x3/14,s12,x0/7,pb/l,s15,x6/2,s2,x1/7,pp/e,x3/11,pd/a,x5/7,s4,x6/14,s9,x11/2,s10,x1/0,s15,x2/9,s5,x13/14,pi/f,x12/11,pb/h,x1/5,s5,x10/2,s13,x15/0,s7,x2/12,s8,x8/0,pn/c,x10/12,s4,x2/1,pk/m,x14/7,s1,x0/15,ph/j,x13/14,s8,x11/7,pf/b,x12/6,s13,x0/3,s11,x6/15,pm/p,x4/9,pn/c,s7,pb/g,x7/5,s5,pc/h,x15/13,pa/f,x3/11,ph/n,x12/14,pk/d,x0/3,pe/j,x6/1,pd/b,s10,pc/m,x4/9,pa/j,x2/10,s8,x5/13,pd/k,x12/14,pa/h,x13/3,s10,x11/0,s8,x13/8,s1,x5/6,pp/d,x0/1,s9,x3/4,pf/a,s14,x5/15,pn/e,x10/0,s2,x1/12,pp/o,x7/9,s10,x10/14,pl/m,x3/0,pb/j,x12/7,s13,x0/3,pd/l,x9/7,s10,x13/2,s4,x10/4,s6,x6/5,s9,x0/14,s2,x3/9,s7,x11/15,s5,x6/8,ph/a,s1,x1/14,s15,x9/10,pb/k,s4,x15/8,pe/a,x10/2,pg/f,x1/5,s8,x10/3,pk/a,x4/8,s14,pj/m,x13/6,s2,x5/7,s8,x3/10,ph/n,x5/6,s6,x8/1,s3,x13/2,s12,x7/8,pp/m,x4/0,pi/n,x8/10,s15,x15/13,s13,x1/6,pf/p,x15/11,ph/a,x1/14,pn/b,x5/11,s1,x3/4,s10,x11/9,s9,x8/13,s12,x7/14,s5,pc/k,x12/6,pl/n,x3/10,pf/e,x0/12,ph/m,x1/6,pa/d,x7/3,pg/k,x8/9,pl/h,x4/5,pn/b,x10/14,pm/p,x3/9,s3,x6/5,s11,x12/13,pe/l,x9/8,pg/c,x7/10,pf/p,x6/1,s13,x2/4,s8,x14/0,pg/k,s1,x5/10,s6,pn/p,x12/1,pe/m,x13/6,s15,x0/3,s13,x7/1,s4,pk/p,x15/14,s9,x1/11,pi/e,x14/7,s11,x11/6,s10,x2/5,ph/j,x1/9,pi/g,x4/8,s11,x13/2,s3,x12/11,pb/h,x4/2,pm/k,s3,x14/1,pg/l,x4/7,s7,x5/1,pe/k,x9/0,s8,pd/p,x2/5,pc/m,x0/13,s2,x3/2,s7,x4/11,ph/f,x8/14,po/n,x10/4,pd/j,x11/13,pl/g,x4/0,s15,pb/j,x3/12,s9,x5/14,s12,x10/15,s9,x14/8,ph/d,x3/15,s8,pb/c,x14/1,s8,x0/12,s13,x7/13,pl/a,x3/0,s15,x9/5,s15,x13/2,s15,x15/7,po/f,x8/14,pd/b,x9/0,s2,x8/10,s11,x15/14,s11,x8/6,s1,x9/13,pf/k,x12/4,s14,x3/8,s12,x15/1,pc/e,x5/14,pj/f,x1/9,s12,x2/14,pg/a,x6/13,s4,x5/14,s9,x12/10,pj/h,x2/9,s7,x11/14,s6,x5/1,s2,x11/0,pn/o,x4/15,pi/c,x8/5,pk/n,x13/3,s11,x1/14,s10,pb/o,x2/4,s11,x10/12,pj/a,x11/8,s7,pf/l,x1/3,s13,x2/10,s12,x13/9,pj/a,x2/7,s7,x5/1,s7,x0/12,po/n,x1/3,s2,x5/9,s12,x12/3,s13,x9/15,s1,pl/e,x13/7,pm/p,x6/10,pl/f,x11/15,pc/o,x2/13,s3,x10/6,s14,x9/11,pa/n,x4/12,pi/p,s8,pk/m,x0/7,s10,x3/15,s9,x6/14,s10,x8/4,pa/i,x12/0,pf/d,x13/5,pl/i,x8/11,pn/f,x9/2,s12,x7/1,s9,x11/9,ph/b,x12/5,pf/n,x3/14,s10,x1/10,pa/o,x7/3,ph/n,x13/10,pc/j,x6/3,s7,x5/7,s9,x2/4,pm/b,s2,pc/n,x0/8,ph/k,x5/4,po/c,x6/9,pk/d,x5/2,s10,pl/b,x14/9,pc/k,s12,x0/4,pn/g,x7/12,pi/d,x8/15,s13,x12/14,s11,x2/5,pj/g,s7,x8/14,s9,x1/9,s8,x5/13,s13,x2/14,pd/f,x12/7,ph/g,x3/10,pc/i,x4/15,pl/a,s11,x11/3,s12,x9/10,pb/h,x7/14,s5,x1/9,s5,x11/6,pa/n,x14/15,s9,x12/6,pe/k,x8/5,s8,pd/p,x4/2,pb/i,s4,x9/0,s7,x11/15,s13,x2/9,s13,x6/8,s5,x4/7,pl/n,x3/10,s7,x14/5,pc/i,x3/2,s9,x9/13,s8,x7/4,s15,x10/5,s3,x12/8,s4,x6/10,s1,x15/3,s2,x5/10,s7,x1/3,s13,x14/10,s6,pj/a,x4/5,ph/g,x7/15,s14,x2/0,s13,x10/4,po/f,x0/1,s13,x4/8,pn/a,x6/2,s15,pp/d,s5,x1/8,pm/b,x9/7,pf/p,x2/10,pi/b,x11/13,s7,x12/5,s7,x7/2,pd/j,x1/15,s13,x6/14,po/m,x8/3,s1,x4/13,s9,x6/11,s4,pb/h,x9/5,po/f,s3,x4/15,pg/l,x0/12,s12,x5/10,s7,x8/7,s7,x11/10,pp/e,x12/4,s1,x10/5,pj/f,x11/14,pl/p,x12/1,po/h,x14/10,pb/k,x9/0,pe/f,x15/6,s15,x9/14,pg/k,x11/7,s3,x12/5,s15,x4/13,s7,x2/8,pa/f,x15/14,s14,x8/11,s6,x1/10,s12,x13/11,pd/p,s2,x5/1,s2,pb/h,x2/8,pc/j,x11/6,s7,x3/9,pm/n,x0/10,s4,pj/b,x9/1,pm/a,x11/5,pk/h,x12/13,s9,x11/8,pl/n,x5/9,pp/c,x15/14,pj/i,x4/3,pn/k,x9/2,pp/o,s2,x3/10,pl/c,x13/11,s12,x12/3,pf/k,x0/10,s9,pl/a,x8/7,ph/j,x2/4,s2,x14/1,s6,x2/5,s11,x11/0,s13,x12/6,pe/b,x10/5,s1,x1/11,s4,x2/0,pc/l,x4/1,s1,x9/3,pe/h,x11/0,s1,x3/1,pi/o,x10/0,s13,x3/13,pg/m,s5,x4/9,s1,x10/15,s9,x7/8,s8,x1/6,s2,x7/9,ph/n,s5,x15/13,s6,x10/5,s7,x6/7,pl/a,x10/4,po/g,x8/14,s5,x9/1,s13,pd/k,x15/3,pe/j,x14/6,s9,x5/2,pc/d,x6/0,ph/p,x14/12,s7,x15/9,s15,x1/3,pk/l,x0/5,pe/o,s11,x14/8,pj/f,x10/15,s3,x4/8,pp/m,x10/13,s2,x7/1,pi/e,x3/15,s7,x13/11,pj/a,x9/14,s11,x0/5,s4,x13/8,s5,x1/7,s12,x0/8,s1,x2/5,s13,x10/8,s6,pi/l,x9/11,s10,x3/13,pe/o,x1/6,pp/k,x3/10,pg/o,x8/13,s6,x6/12,s14,x9/2,pj/l,x13/1,pk/m,x4/5,pp/f,x12/14,pl/d,x2/9,pa/i,x14/6,s12,x9/13,pd/g,x1/2,pl/k,x4/13,s12,x0/15,s8,x1/13,s12,x4/7,pj/p,x10/2,s6,x14/5,pg/d,x8/3,s4,x11/7,s13,pm/h,x9/14,s4,x2/13,s13,x8/7,s4,x0/3,po/p,x11/9,s13,x12/1,pa/j,s9,x15/5,s2,pd/l,s13,x6/11,s3,x2/10,po/h,x14/5,pc/b,x6/0,pg/a,s11,x14/11,pf/l,x9/8,s9,x4/3,s13,pg/e,x0/6,s13,x3/2,s4,x1/4,s4,x3/7,s8,x2/13,s15,x5/11,pi/m,x0/7,pd/a,x11/3,s12,x4/6,s11,x8/12,s1,x5/14,s14,x4/11,pl/c,s4,ph/k,x5/6,pj/f,x1/4,s8,x12/11,pk/m,x9/13,po/e,x15/2,s8,x3/4,s2,x0/7,s14,x4/13,s15,x1/9,pm/a,x15/10,s8,x2/7,s10,x0/14,pd/g,x12/3,s7,x14/11,s4,x8/2,s12,x10/1,pb/e,x12/13,s12,x0/5,pk/d,x13/9,s12,x8/5,s9,x3/12,ph/b,s15,x1/13,pi/a,x2/0,pe/f,x4/9,s15,x12/3,pd/o,x9/14,s5,x2/0,pe/b,x11/6,s14,x3/12,s10,pn/k,x1/4,s5,x15/3,pd/p,s14,x11/8,s13,x7/5,s9,x15/4,s13,pl/h,x8/7,s5,x12/4,pk/a,x2/13,s12,x15/10,s8,x8/14,pb/e,x3/5,pf/c,x13/7,pi/p,x1/10,s6,x8/9,pm/e,s6,x14/6,pf/h,x5/3,pb/k,x9/10,s5,x7/1,pi/o,x10/2,pb/h,x3/14,s15,x12/0,s1,x4/3,s4,x7/0,s7,x4/14,s6,x13/0,s10,x14/8,s11,x0/9,s5,x15/6,s9,x11/10,s8,x1/4,s1,x6/0,pk/i,x5/2,s4,x11/8,s2,pj/f,x1/10,pa/l,s1,x11/3,s7,x5/9,s8,x7/13,s12,x11/3,pg/n,x14/2,s10,x12/0,s14,x15/5,pf/c,x0/10,s13,x9/5,s7,x0/1,pb/j,s11,x4/8,ph/e,x6/1,s15,x7/15,s10,x0/1,s15,x6/8,s9,x9/12,s7,x1/0,pd/a,x9/13,pj/i,x10/6,pf/n,s14,x0/15,pd/o,x12/9,s4,pp/g,x7/14,pk/a,x10/3,ph/j,s6,x11/8,s15,x4/9,s13,x6/13,s12,x14/5,pc/a,s12,x13/1,po/n,s1,x6/7,s1,x3/14,pa/g,x11/5,pj/k,x12/7,s12,x4/5,pn/o,x2/10,s12,x1/8,s14,x4/5,s15,x9/11,s4,x8/0,s8,x1/11,pe/a,x6/10,pb/c,x13/3,s12,x11/1,pi/p,s13,x15/9,ph/f,x3/5,s4,pp/d,x8/7,pl/b,x0/13,s1,x1/9,pd/f,x4/2,s6,x10/1,s1,x5/3,s1,x10/9,s9,x0/5,pm/e,x1/13,pl/p,x14/10,po/g,x1/15,s5,x8/10,pk/a,s5,x4/13,s15,x14/3,pi/f,s3,x0/9,pm/c,s5,x12/13,pa/k,x8/10,pd/n,x13/7,pp/j,x4/12,po/n,x9/0,ph/b,x6/1,pg/n,x10/13,s3,x6/8,pf/b,s15,x14/12,pi/e,x0/7,s10,x3/10,pj/c,x4/6,s13,x7/2,s11,pf/a,x8/4,ph/n,x7/9,s4,x2/3,s3,x15/11,po/b,s2,x0/12,s15,x11/14,pd/a,x10/5,s4,x12/9,s2,x10/8,pc/l,x11/5,s2,x9/15,pd/n,x12/10,s9,x15/9,s2,x8/5,pp/i,x7/11,s2,x12/2,s4,x9/14,pn/a,x6/4,pb/l,x2/1,s2,x3/0,pg/o,x12/15,pi/l,s15,x13/0,pm/k,x3/9,s11,x6/8,s1,x3/15,s1,x5/12,s4,x2/3,pi/l,x14/7,ph/o,x11/9,s2,x5/10,s15,x4/1,pa/k,x6/3,s3,x4/1,s4,pg/h,x14/7,s4,x12/2,s10,x15/10,s4,x8/2,s5,x5/14,pb/l,x6/10,s14,x0/11,pp/c,x14/10,pb/f,s3,x7/4,s10,pj/n,s3,x1/3,pa/h,x9/0,s15,x15/5,s10,x3/1,s13,x10/0,pl/n,s15,x4/11,s7,x9/12,s2,x11/7,s14,x15/5,pp/e,x9/3,s12,x11/13,pf/j,x3/14,s8,x15/12,s6,pn/b,x3/8,pa/i,x14/15,pg/k,x11/8,pi/l,x12/14,s13,x11/5,pa/g,x15/3,s10,x10/4,s6,x12/8,s2,x4/3,s1,x1/5,s8,pc/l,x8/11,pm/o,s10,x9/6,s11,x2/4,s4,x1/14,pf/d,s6,x13/8,s13,x14/1,pg/a,x2/11,po/b,x13/0,s12,x3/11,s12,x2/0,s4,x4/6,s7,x12/0,pp/g,x6/2,s11,x13/7,s7,x9/8,s6,x14/0,s9,x5/10,pa/f,x0/2,s7,x12/14,ph/l,x11/7,pb/f,x0/6,s1,x3/11,s7,x8/1,s2,x11/5,s1,x2/9,ph/e,x12/0,s13,x5/9,s2,x11/1,s8,x9/2,s1,x7/5,pl/c,x0/15,s14,x12/9,pi/n,x14/2,s15,x3/5,pb/a,x4/11,s3,x8/10,pd/f,x5/9,s3,x7/3,s15,x12/10,s8,x5/3,s6,x2/9,s15,pe/c,x5/10,pg/j,x8/7,pp/o,x9/11,s7,x7/14,pi/l,x4/11,s4,x12/9,ph/p,x4/14,s13,x11/13,s10,pl/m,x14/8,s2,x5/3,pc/a,x11/14,s9,pg/e,x0/9,pj/l,x11/14,s3,x4/6,s10,x8/14,s10,pk/d,x0/13,pe/a,x14/5,s3,x6/1,s7,x4/11,s7,x6/14,pc/o,x7/11,pp/e,x6/13,s9,x8/15,pn/h,x6/3,s10,x14/11,s9,x13/3,s15,x0/8,pf/b,x10/6,s7,x9/2,pi/p,x3/10,s13,x8/12,pe/b,x7/10,s4,x9/15,pf/h,x4/13,pk/e,x11/1,s10,x9/14,pd/f,x1/3,s2,x7/13,s15,x2/10,ph/i,x6/0,s9,x15/2,pl/o,x7/1,s10,x14/4,s9,x1/2,s15,x7/14,pn/k,x6/3,s12,x4/7,s2,x13/9,pp/l,x15/0,pa/i,x14/6,s14,pm/d,x3/7,s12,pi/k,x4/13,ph/b,x3/10,pj/g,x9/13,s8,x11/3,pa/c,x10/1,s1,x14/8,pm/f,x13/12,pc/o,x10/3,s11,x11/8,s13,x1/9,pf/e,x2/6,pl/m,s11,x3/1,s15,x11/15,s11,x13/6,s4,po/e,x9/3,pm/k,x1/10,s6,x11/5,pn/l,x1/15,pm/o,x14/9,s12,x10/13,s7,x14/3,pe/d,x10/1,s9,pm/f,x3/12,s2,x11/10,s6,x12/8,s15,x10/6,s11,x0/7,s4,x11/5,pl/n,x7/9,pi/k,x5/1,pf/o,x4/8,pg/e,s10,x1/14,s12,x0/15,pj/l,x6/2,pm/c,x12/1,pn/g,x9/5,s1,x2/0,s10,x9/4,s12,x7/15,s10,x12/0,s8,x8/4,s2,x11/15,s15,x7/5,ph/i,x6/8,pb/d,x7/13,s3,x5/10,s13,x2/15,s13,x4/3,ph/f,x5/15,s12,x12/9,s5,x7/13,s10,x11/14,s14,x4/12,s11,x7/2,s1,pa/k,x14/4,pl/p,x7/12,s10,x8/3,s11,x9/2,s14,x5/14,s12,x7/3,pj/d,x10/14,s7,x0/3,s6,x1/13,pf/g,x7/0,pi/k,s11,x12/6,pa/c,x4/5,s6,x12/8,s2,x15/10,s9,x13/8,pj/h,x0/6,pd/a,x10/5,s10,x1/12,s4,x14/7,s14,x3/11,s3,x2/7,pe/m,x4/8,pb/j,x2/7,s12,x0/4,pp/m,x6/9,pn/k,x0/13,s2,x10/8,s2,x2/7,pd/f,x15/11,s5,x5/0,s6,x15/6,pn/e,s13,pk/b,x2/3,pd/a,x14/10,pi/o,x4/1,s3,x10/14,s12,x8/13,s1,x1/12,s14,x6/8,pb/l,x7/3,s1,x9/1,s11,x2/10,pg/f,x0/4,s14,x8/15,s7,x14/12,pc/j,x4/9,s8,x13/15,s6,x11/9,s1,x5/2,pf/o,x13/12,pb/e,x15/14,s5,x2/13,s4,x15/10,pa/g,x3/4,s5,x8/10,s3,x13/4,pn/f,x1/2,s12,x12/15,pi/d,x11/8,s2,x12/15,s2,x3/9,pl/h,x10/8,s4,x14/0,pj/d,x5/15,s10,x13/7,s3,x9/11,s7,x12/15,po/p,x9/13,pl/i,x10/8,s7,pn/h,x12/5,pp/o,x2/3,s4,x7/6,s12,x11/0,s11,ph/b,x2/6,pd/a,x4/0,s9,x11/6,pm/c,x13/4,s13,x7/6,s13,x3/14,pk/i,x8/10,pf/m,x12/1,s3,pj/c,x9/11,pk/i,x10/15,s10,x0/11,s4,x6/3,s6,x13/14,pj/g,x10/12,s11,x2/6,s15,pe/a,x0/12,s5,x4/9,ph/g,s8,x11/13,pd/p,s7,x7/15,s8,x10/8,s14,x4/6,s9,x0/7,s14,x1/2,pb/h,x8/6,s4,x10/3,po/c,x5/4,s8,x6/2,pb/f,x14/15,pc/g,x4/12,s8,x1/15,s2,x11/2,s5,x10/1,s11,x13/4,s7,x15/0,s10,x2/6,s7,x12/9,s7,x3/13,pk/j,x15/5,pc/l,x0/7,s3,x12/8,s11,x2/7,pi/n,x15/10,pe/f,x11/1,s7,x5/15,s10,x11/9,pc/m,x8/10,pn/p,x3/4,s9,x15/2,s9,x12/11,s6,x10/6,pb/g,x15/0,pp/a,x9/5,s3,x12/0,pf/m,x9/2,s4,x14/4,s3,x5/8,s1,x3/12,s4,x2/10,pn/d,x8/4,s10,pk/b,x2/12,s5,pa/n,s10,x5/6,s8,pf/o,s7,x0/14,pa/j,x3/2,s12,x14/8,s1,pc/p,x3/7,s8,x11/15,pk/e,s12,x5/13,s4,x1/11,pn/m,x15/13,s5,x1/9,s12,x11/4,s8,x12/13,pd/h,x14/10,s5,x12/2,s12,x7/0,s5,x14/12,pn/g,x0/4,s3,x12/15,pf/e,x4/11,s15,x13/6,pm/o,x10/5,s3,x13/9,pf/g,x7/4,s8,x1/9,pk/b,x14/0,pj/e,x7/5,s4,x0/4,pi/p,x2/13,s3,x6/7,s15,x1/11,pb/e,s3,x9/6,s14,x3/12,s1,x13/2,pj/c,x6/9,s13,x3/11,pd/p,x8/9,s15,x2/0,s10,x5/6,pe/o,x2/7,s3,x14/15,pn/f,x12/7,s6,x3/10,s15,x9/13,s11,x7/5,s7,x8/14,s7,pj/m,x9/3,pn/i,x12/0,ph/k,x11/3,s7,x13/14,s6,x2/12,s10,x1/13,s2,x5/10,s4,x13/0,s7,x5/11,s4,x0/9,s14,x4/10,s11,x2/1,s8,x15/7,pg/f,x4/12,s10,x5/11,s7,x13/1,s6,x8/7,pj/l,x5/6,po/k,x1/15,pb/i,s3,x4/8,pk/e,x7/9,pf/m,x1/3,pk/h,s1,x8/10,pc/j,x5/2,pl/f,x15/4,s12,x3/0,s11,x6/1,s3,x10/4,pc/h,x15/5,s6,x0/13,s11,x6/11,pk/d,x4/0,s4,x6/5,s6,x12/3,s14,x6/15,pj/a,s5,x9/3,s7,x0/11,po/b,x12/2,pd/l,x4/13,s11,x11/10,s4,x5/15,pi/a,x13/1,ph/o,x4/10,s4,x0/13,pi/b,x8/2,pg/h,s2,pd/c,x0/9,s2,pj/p,x7/5,s12,x15/4,s6,x5/2,s14,pm/k,x15/11,pb/j,x12/13,s6,x1/7,s13,pg/d,x5/2,s6,x14/4,pa/k,x11/15,s8,x6/3,ph/p,x8/7,pa/c,x10/4,s8,x13/15,s9,x5/3,pe/d,x2/8,s8,x14/7,pc/k,x5/13,pj/e,x7/0,s6,x14/9,s4,x13/2,s14,pl/b,x7/12,s10,x5/14,pf/o,x4/10,pg/c,x6/14,s6,x11/15,s15,x5/7,s10,x2/9,s9,x4/6,s11,x10/3,s5,x6/5,s14,x7/2,s4,x0/9,pd/m,x4/7,s14,x12/3,s4,x10/11,pi/e,x0/4,pl/m,x13/8,s9,x5/4,pg/e,s15,po/f,x1/12,ph/c,x15/14,s9,x1/6,pm/g,x0/3,pf/c,x13/6,s15,x4/5,pd/b,x2/1,pf/a,x0/12,s9,x9/7,s1,x1/12,pp/c,x2/14,pf/n,x4/12,s5,x7/15,pd/a,x1/3,pc/n,x5/10,pj/e,x0/13,s2,pf/i,x9/3,s8,x15/12,pc/k,x2/1,s6,x3/14,s12,pa/h,x12/5,s3,x3/7,s12,x11/8,pb/d,x13/1,s13,x14/9,s12,pi/h,x7/3,s14,x10/13,pd/p,x8/2,s15,x14/9,s5,x4/12,pj/h,x14/15,s3,x10/11,s8,x15/1,s14,x13/5,s1,x8/3,s4,x15/9,s15,x6/0,pn/o,x9/11,s15,x15/13,s9,x12/5,pi/f,x3/13,s10,x11/10,s7,x7/5,s2,x0/11,s11,x5/15,s6,x10/1,s13,x6/9,pb/d,x15/1,s5,x13/5,pc/e,s2,x3/12,pp/a,x6/1,pg/l,x0/10,s11,x12/15,ph/p,s9,x5/10,s3,x9/12,pe/d,x3/4,s14,x1/12,s10,x9/4,s12,x7/2,pk/o,x1/15,s3,pj/e,x8/2,s14,x9/3,pd/i,x6/10,pa/b,s14,x8/1,pl/g,x2/4,s14,x8/11,s13,x14/3,s13,x9/7,pe/d,x14/15,pc/o,x13/8,s13,x14/10,s12,x1/5,s4,x11/6,s9,x10/5,pl/a,x1/14,s11,x13/15,pn/f,x5/11,s4,x2/4,s10,x15/8,ph/j,x14/0,s14,x6/7,pp/k,x1/14,pb/d,x7/3,s10,x15/8,s10,x14/4,s15,x12/9,s2,x11/10,ph/p,x12/8,s13,po/j,x0/2,pd/c,x3/8,s6,x12/1,pl/g,s2,x8/14,pj/i,x5/4,pg/a,x9/1,pj/d,x8/4,s8,x2/0,pp/f,x7/9,s9,x8/13,pl/i,x11/15,s14,x8/10,s13,x12/9,s6,x2/6,pd/p,x8/12,s8,x14/13,s11,x6/2,ph/i,s2,x1/5,s6,x9/2,s8,x10/8,s6,pf/e,x15/14,ph/b,x9/3,s15,x1/14,s13,x2/12,s11,x8/10,pf/a,x11/15,s12,x13/6,ph/l,x12/10,pg/a,x6/14,pd/e,x7/5,ph/g,x4/11,s14,x0/3,pi/j,x13/2,pc/d,x7/10,s4,x9/1,pi/e,s8,x7/4,pc/p,x5/6,s9,pm/e,x14/4,pb/g,x0/2,pf/m,x8/13,s5,x2/14,s11,x8/0,s13,x4/13,pj/i,x8/3,s4,x5/4,s8,x1/3,pb/p,x10/12,pj/n,x6/15,pp/m,x1/10,pl/c,x8/15,s5,pi/e,x6/7,pl/c,x14/10,s4,pk/p,x2/13,pb/g,x12/4,s1,x0/5,s13,x10/9,s3,x8/2,s9,x12/9,s3,x14/13,pn/d,x15/9,pa/c,x14/2,pp/o,x1/6,s2,x10/13,pn/h,x2/4,pa/g,x7/0,s10,pe/p,x4/11,po/d,x10/0,pg/c,x6/14,po/k,x8/3,pg/e,x11/6,pk/j,x13/4,s3,x9/3,pc/f,s11,x15/6,pg/h,x12/5,s14,x2/8,s13,x14/7,pa/m,s3,x13/15,s10,x11/6,s14,x13/12,pn/e,x4/5,pg/l,x13/1,s3,x8/12,pe/i,x15/9,pk/o,x10/12,s7,x6/4,s7,x0/9,ph/g,x7/13,s7,pd/i,x5/15,s9,x4/10,ph/a,x1/2,pf/m,x12/9,pi/e,x5/14,ph/b,x7/3,s9,x2/13,s9,pn/m,x11/15,pe/k,x6/2,s13,x7/8,pn/m,x5/11,pi/l,x1/12,po/e,x9/8,pg/j,x14/2,s10,x9/6,pm/a,x7/2,s14,x15/11,s11,x2/1,s1,x10/4,pn/p,x8/3,s10,x12/9,s15,x15/4,s7,x2/0,s2,x4/15,s4,x10/11,s11,x4/7,s9,x8/10,s3,pf/j,x4/9,s14,x8/3,pa/e,x7/0,ph/n,x2/8,pi/g,x1/4,pb/p,x8/12,s10,x1/3,s7,pk/l,x6/10,pp/n,x15/9,pj/h,x0/8,s3,pf/d,x14/9,s2,x1/8,s12,x5/12,pg/m,x15/11,pk/j,x0/6,s8,x10/1,s1,x7/11,s11,x1/10,po/i,s7,x6/3,s4,x11/9,s14,x3/8,s11,x7/11,s15,pj/k,x6/8,s6,x10/3,s2,x9/1,pn/o,x7/5,s10,pf/d,s1,x8/6,s14,pa/c,x9/7,s9,x0/12,s11,x5/3,s7,x7/4,s8,x11/15,pi/f,x2/8,pb/h,x4/9,s1,x0/12,s10,x10/11,pg/i,s9,x13/2,pc/d,x15/11,s10,x1/3,s10,x10/12,s4,pf/k,s9,x4/8,s13,x11/13,pp/h,s7,x15/12,s4,x2/9,pf/a,x1/4,s6,x12/9,pn/d,x0/15,pp/o,x9/2,s3,x15/13,s8,x11/5,s9,x4/13,s9,x3/5,s12,pj/d,s12,x6/12,s14,x1/7,s2,pp/l,x14/12,s14,x0/9,pb/g,x15/4,s12,x10/9,pl/i,x14/0,s1,x15/10,s9,x14/0,pb/k,x15/9,s15,x13/5,pc/j,x7/12,pm/i,x4/15,pj/h,x0/9,s2,pb/o,x4/1,pn/c,x7/11,pa/k,x13/12,s3,pp/e,s13,x0/4,s10,x6/7,pj/o,x1/14,s11,x5/4,pi/m,x0/2,s7,x9/13,pb/l,x10/15,pk/n,x9/13,s1,x2/15,s7,x4/6,pm/o,x13/12,s12,x0/6,s6,x3/4,s15,x12/9,s14,x4/5,s13,x15/12,pc/a,x11/8,s10,x10/0,s10,x15/8,s9,x5/13,s1,x11/12,s11,x13/2,s8,x4/0,pn/i,s7,x1/2,s15,x9/0,s9,x12/4,s2,x3/7,s12,x0/6,s2,x4/11,s1,x8/1,s13,x13/5,pk/h,x7/4,pe/d,x0/9,s5,x8/3,s7,x9/7,pp/a,x8/4,s7,x3/13,pf/l,x8/9,s14,x12/15,pm/h,x2/8,pe/d,x11/7,s7,x10/15,s8,x6/3,po/h,x11/1,pc/d,x2/3,s11,x15/7,s1,x14/3,s11,x15/11,s10,x1/14,s5,x3/4,s3,x8/0,s13,pf/p,x3/6,pa/l,x11/10,pm/p,x2/14,pj/b,x0/9,s7,x8/10,s6,x13/6,pf/h,x7/2,pk/c,x11/5,s8,x10/14,s3,x5/1,s3,x0/7,s2,x8/1,pa/j,x0/6,s8,x7/10,s9,x14/0,s9,pc/l,x15/3,s2,x12/8,pe/d,x15/10,pm/j,s9,x4/7,s9,x1/10,s7,x15/4,s5,x5/2,ph/n,s13,x14/9,s4,x11/1,s12,x7/5,s11,x11/8,s8,pk/i,x3/9,pn/b,x2/1,s11,x3/4,s7,x9/8,s7,x11/5,pe/k,x14/10,pf/d,x6/3,s10,x0/5,pe/a,s11,x6/1,s12,x13/9,s8,x0/2,s5,x15/6,pp/i,x0/8,s13,x13/1,s6,x4/6,s1,x8/14,pk/o,s15,x15/12,pg/d,s3,pp/m,x3/7,pi/b,x13/1,pl/a,x2/15,pf/h,x11/4,s4,x6/9,pl/b,x12/4,s9,x11/5,pf/p,x4/8,pi/n,x9/0,s15,x11/3,s12,x4/7,s4,x0/8,s8,x10/13,pc/f,x9/1,pn/g,x2/11,s3,x13/12,s5,x5/4,pf/a,x3/9,pe/l,x13/2,s7,x8/4,pp/o,x0/3,s11,x7/8,pg/j,x13/4,s11,x7/6,s3,x3/14,s13,x4/8,s11,x13/9,pn/i,x4/11,pj/f,x9/7,pk/n,x12/0,pb/o,x1/8,pc/j,x5/14,pk/m,x13/15,s2,x6/2,s7,x13/5,s2,x8/3,s14,x10/6,s2,x7/2,s9,x5/14,pp/i,s2,x2/9,s3,x4/3,s11,x1/12,pc/d,x9/14,s14,x5/15,pj/f,x14/10,s11,x8/3,s10,x12/7,pi/n,x5/6,pl/d,x9/13,pg/o,x2/7,s4,x8/11,s11,x6/1,s1,x13/11,s11,x8/2,s6,x5/10,ph/i,x0/8,pn/c,x11/4,pl/p,x5/15,po/k,x1/9,s6,x3/14,s8,x6/12,s15,x4/13,pg/a,s11,x8/7,s14,x1/11,s3,x6/7,pl/o,x0/11,s7,x10/7,s6,x0/3,s9,x12/8,s12,x13/7,s1,x3/8,s10,pi/p,x5/7,po/k,s12,x3/10,s1,x1/2,s3,x8/7,ph/m,x15/2,pg/n,x14/4,s15,x1/10,pb/h,x0/6,pd/g,x15/4,s4,x12/8,s10,x9/1,s11,x12/11,s5,pp/l,x14/8,pm/n,x1/7,s9,x2/11,s1,x15/6,pk/d,x10/13,pp/j,x4/14,s2,x3/1,s3,pb/g,x7/9,s1,x3/13,pf/m,x10/2,pi/n,x9/15,s3,x3/6,s13,x4/1,s3,x3/8,pa/m,x11/9,s5,x15/4,s9,x7/2,pp/e,x11/5,pc/o,x7/0,s4,x1/9,s13,x15/2,s15,x3/13,pn/g,x4/0,s1,x11/9,po/b,x13/5,pa/e,x8/0,s1,x7/9,pb/m,x3/14,s2,x6/10,s11,x13/1,s6,pk/d,x4/12,s8,x14/11,s6,x15/0,ph/i,x14/3,s10,x1/2,pn/f,x5/8,s7,x0/4,pb/o,x15/8,s14,x1/0,pg/h,x14/11,s14,x0/3,pm/p,x6/10,s7,x2/0,pe/k,x11/15,pa/c,s15,x2/1,pi/m,s10,x4/7,s4,x10/5,s6,x0/7,s10,x3/5,s15,x4/10,pb/p,x0/11,s9,x14/12,s5,x8/11,po/h,s10,x10/9,pd/p,x7/11,pa/o,x1/6,s8,x13/15,pj/n,x8/0,s1,x6/12,s14,x15/14,po/p,s15,x4/0,s4,x6/2,pi/b,x14/1,ph/l,x11/8,pc/m,x15/9,s8,x3/10,pe/k,x14/5,pn/l,x12/1,po/c,x7/14,s2,x6/4,pa/g,x0/11,s6,x5/8,s13,x3/13,pl/o,x2/15,pc/h,x14/0,pp/o,s1,pg/h,s1,x8/13,s11,x6/10,s13,x12/14,pm/n,x9/15,pj/b,x10/1,s11,x3/5,s9,x0/13,s12,x8/10,pi/o,x11/12,pb/k,x5/1,pn/p,x3/8,s6,x4/14,pi/k,x15/7,pn/c,s7,x5/13,s3,x1/9,pl/g,x7/4,pe/d,x2/0,s11,x3/11,pa/b,x2/8,s14,x15/11,s1,x5/14,s14,x1/12,s4,pc/d,x13/10,pk/o,s7,x1/5,s4,x12/0,s11,x7/15,s13,x9/0,s3,x11/6,s3,x3/2,s8,x15/14,s9,x7/4,pp/n,x12/10,s12,x3/11,pm/e,x6/10,s5,po/l,x3/5,s8,x9/13,s3,x0/2,pj/c,x4/12,s5,x14/15,s15,x1/2,pl/k,x12/15,pn/h,x5/1,s14,x10/4,s15,pc/l,x3/11,s9,x2/7,po/e,x10/6,s13,x12/11,s1,x4/8,s4,x15/11,s9,x10/8,pp/m,x4/14,pc/n,x8/6,s1,x2/12,pp/i,x15/5,s4,x11/12,s13,x6/0,pe/o,x9/3,s11,x1/4,s7,x6/13,pl/i,s11,x12/7,s5,x0/8,s2,x13/11,pk/e,x6/3,s6,x5/2,s9,x3/12,s3,x0/6,s8,x9/1,s6,x14/2,ph/o,x12/8,s1,x1/10,s1,x2/14,pf/m,x12/8,s12,x6/1,s1,x4/2,s15,pd/j,x15/3,pe/g,x4/2,s13,x3/0,s7,x6/12,s6,x4/7,s1,x15/2,s8,x7/9,pk/i,x1/12,s6,x4/10,s10,x13/9,s8,x12/2,pm/e,x15/14,s5,x13/0,pa/j,x3/4,pn/c,x14/10,s7,x12/0,s6,pl/g,x15/1,pd/n,x3/7,s11,pa/f,x12/4,s7,x3/10,s3,x2/14,pl/c,x4/13,pe/a,x15/1,s2,x13/6,pp/n,x7/10,s15,x11/6,pe/c,s6,x7/15,pk/p,x5/6,s1,x2/7,s13,x12/10,pm/f,s1,x3/13,pb/n,x2/14,s3,x9/6,s6,x13/1,s13,x10/2,pi/k,x6/5,s1,x13/15,s5,x12/6,s8,pn/m,x0/10,s9,x5/15,s8,x0/6,s8,x14/4,s8,x8/12,s2,x3/7,pj/h,x8/4,pn/l,s10,po/d,x6/2,pf/n,x12/9,s3,x6/8,pd/j,x0/10,s13,x5/1,pg/l,x2/11,s8,x0/4,pc/h,x5/7,s14,x14/9,pb/e,x3/10,pg/o,x7/11,pj/m,x14/8,s3,x6/2,s12,x11/4,pp/h,x12/9,s5,x11/1,pa/f,x2/3,s8,x7/5,s11,x15/2,s6,x7/3,s2,ph/b,x0/4,pm/i,x10/5,s8,x3/0,s3,x4/14,s12,x2/5,s11,x4/6,s2,x5/13,s14,x3/9,pb/j,x2/1,s7,x10/9,pn/k,x1/11,s2,x5/10,pg/o,x11/14,pc/n,x6/3,ph/b,s12,x13/11,s15,x14/15,pk/c,x3/11,pm/j,x2/15,s7,x11/5,ph/b,s10,x2/3,pp/k,x6/9,pm/n,s9,x10/5,s9,x9/1,s9,ph/j,x6/12,pc/g,x1/5,pi/e,x14/15,pf/c,x2/5,s12,x1/10,s4,pd/p,x3/14,s10,pg/l,x5/7,s14,ph/e,s1,x8/4,s8,x7/3,s11,x14/13,s12,x1/11,s1,x3/15,s5,x5/4,s15,x2/15,s5,x3/1,pp/i,x9/11,s12,x13/5,s3,x9/8,pj/b,x3/12,pc/o,x6/0,s6,x3/14,s14,x13/7,s4,x0/8,s13,x15/3,s6,x11/0,pd/n,x14/10,ph/i,x1/13,pe/d,x8/4,s8,x0/2,s2,x5/9,s6,x0/12,s6,x1/2,s5,x5/13,pl/n,x6/11,s11,x13/5,s12,x2/12,s9,pp/e,x9/11,pb/j,x3/1,s8,x6/11,pi/c,x2/13,s4,x3/15,s2,pa/p,x10/8,s11,x13/5,s8,x10/0,pk/l,x7/13,s6,x4/0,ph/e,x9/5,pc/a,x15/13,s5,x0/1,s6,x10/15,s6,pb/d,x9/11,s11,x7/14,s5,x15/0,s13,x6/7,s3,x11/12,s11,pn/i,x7/8,s13,x15/1,pm/o,x13/9,ph/k,s6,x5/6,s1,x12/14,pl/c,x0/8,s3,x12/14,s11,x6/2,s12,x4/10,pa/n,x6/7,pe/f,x9/2,po/c,x15/0,s8,x14/6,s14,x12/15,pi/k,x5/6,s7,x4/15,pl/m,x8/5,pe/g,x11/4,s8,x9/7,pj/n,s6,x13/1,s3,x11/14,ph/b,s3,x15/6,pj/n,x8/14,ph/f,x4/2,s9,x13/0,s2,x11/6,s7,x0/3,pb/c,x14/8,s13,x1/6,pk/o,x15/9,s8,pd/j,x7/1,s4,x10/2,pc/a,s1,x8/3,pn/l,x12/10,s1,x13/1,s11,x3/2,s14,x9/13,ph/k,x5/1,pp/c,s15,po/h,x2/8,s14,x0/9,s9,x4/2,s12,x11/6,s8,x7/13,s10,x3/6,s3,x8/0,s13,pb/d,x7/3,s5,x1/9,s4,x15/11,s3,x6/13,pm/l,x3/10,pj/f,x12/2,s10,x0/6,s9,x4/1,pa/g,s3,x10/5,s6,x11/9,pc/p,x2/6,s11,x15/11,pg/b,x14/1,pk/l,x13/4,s4,x10/0,pm/p,x7/6,s14,pl/e,s6,x2/14,pf/g,x3/8,s12,x2/4,s13,pp/b,x12/15,pj/d,x0/6,s2,x9/13,s11,x3/11,s8,x10/9,s7,x7/3,s1,x1/6,s1,x15/11,s10,x9/4,pc/f,s11,x15/1,s11,x8/12,pp/a,x1/4,s10,x10/0,s14,x12/1,s13,x2/7,pb/j,x14/12,pa/c,x0/3,s15,pg/f,s6,x5/7,s1,x12/3,pc/a,s3,pe/b,s7,x11/0,pa/p,x12/10,s5,x5/9,ph/k,s9,x1/13,pi/a,x2/10,s6,x3/15,s10,x4/8,s6,pj/h,x6/14,s6,x12/15,pa/d,x1/9,pf/j,x5/2,s2,x8/13,s8,x1/12,s3,x2/5,s1,x13/1,pk/c,x10/2,s12,x14/7,s2,x9/4,s11,x10/11,s14,x0/4,pj/h,x14/8,s6,x4/6,pd/o,s8,pe/a,s7,x10/12,s15,x5/2,pm/n,x12/7,s15,x2/15,pg/e,s11,x4/7,pf/o,s6,x0/11,s1,x3/5,pa/h,x4/14,pl/e,x13/12,s1,x4/2,pc/h,x5/9,pb/d,s15,x10/8,po/a,x0/1,pp/g,x12/4,pc/h,x15/7,pb/j,x14/3,s5,x0/8,pe/h,x7/3,s13,x0/4,pc/o,x8/7,pp/e,s11,x12/0,s15,x15/4,s3,x5/10,s9,x1/8,s10,x9/3,s3,x6/11,s10,x5/15,s3,x11/6,ph/a,s15,x8/15,s2,x5/3,s15,x1/13,s15,x9/2,pl/j,x3/4,po/g,s9,x5/10,pb/j,x7/6,s6,x12/5,s12,po/k,x7/4,s12,pg/c,x11/14,pn/o,x12/1,s3,x7/13,s3,x5/10,pk/h,x6/7,s9,x13/0,pd/j,s14,x10/6,s14,x3/11,pe/m,x10/5,pp/o,x12/14,pn/b,x10/15,pa/i,x3/9,pl/b,x4/8,s8,x11/6,s15,x7/10,pf/h,x2/3,s14,x5/11,s5,x4/1,s8,x3/9,s12,x8/4,po/i,x15/12,s4,x13/10,s9,x5/8,s13,x13/11,s6,x12/4,pg/c,x14/0,s1,pn/e,x8/11,s4,x0/6,pl/p,x8/13,pa/o,x15/7,pl/f,x14/6,s10,x11/12,s4,x15/7,s9,x4/5,s3,x2/12,pp/g,x1/4,pn/i,x10/5,ph/c,x4/12,pd/m,x10/3,s14,x14/2,s15,x12/8,pl/g,x5/13,s11,x7/12,ph/d,s15,x14/13,pe/f,x3/7,po/d,x0/1,pf/m,s6,x2/4,pj/o,x9/12,pi/n,x15/2,s3,x10/8,pb/o,x7/14,s5,pl/e,x9/6,pb/g,x1/13,pc/k,x8/5,pb/o,x12/2,s13,x4/1,pk/a,x9/8,s15,x2/13,ph/n,s1,x15/14,pj/a,x13/5,s4,x6/2,pg/h,x4/8,s13,x11/6,s7,x0/3,s13,x4/9,s2,x15/1,s2,x8/6,pk/f,x10/14,pp/b,x1/8,s4,x5/15,s3,x1/6,pk/a,x3/10,s8,x13/15,s14,x3/8,s6,x0/15,s15,x5/3,s2,x14/9,pp/o,x13/2,s4,pg/j,x15/3,s3,x4/12,pd/h,s2,x5/13,pg/a,s5,x4/10,s2,x9/13,s14,x2/14,pk/e,s14,x10/3,s13,x7/2,pp/o,x10/12,s6,x11/5,s14,x12/4,s2,x6/8,s11,x3/15,s10,pm/e,x2/8,pp/l,x12/1,pn/j,x7/15,pl/f,x8/14,pi/h,x3/10,s5,x15/0,s15,x4/12,pn/j,x8/1,pi/o,x4/14,s14,x15/8,s2,x11/3,s2,x5/14,s11,pm/f,x8/4,po/c,x1/9,s13,x7/2,pb/e,x5/3,pd/m,x1/11,pg/j,s14,x3/9,pk/p,x11/0,pa/d,s11,x8/7,s15,x13/10,s7,x0/8,s5,x15/2,s8,x0/4,ph/f,x2/6,s1,x14/7,s6,x10/5,s4,x3/1,s3,x5/0,pl/b,s13,x1/7,pf/k,x0/6,pl/o,x13/12,s1,x11/9,s14,x5/14,s2,x8/6,pp/i,x7/12,ph/c,x10/15,s1,x14/2,s15,x1/3,s1,x10/4,s6,x12/2,pd/n,x10/9,s6,x13/2,s10,x0/5,pi/m,x12/11,s12,x10/5,s3,x12/4,pn/d,s10,x9/13,pg/m,s13,x2/5,s4,x8/13,po/p,s1,pn/m,x15/6,s5,x14/0,ph/l,x1/10,pk/i,x2/0,po/g,x10/1,s4,x15/14,s2,x8/5,pl/b,x15/14,pf/e,x1/4,pi/g,x6/7,s7,x8/9,pk/o,x7/15,s10,x3/4,pn/e,x11/5,s7,pj/c,s6,x1/14,pp/k,x10/4,pn/c,x8/12,s3,x9/7,pi/b,x3/11,pk/h,s14,x9/8,pj/l,x3/4,s13,x6/7,s1,pb/e,x9/15,pm/p,s10,x13/1,pj/k,s3,x2/3,s6,x9/8,po/a,s1,x13/2,s7,pp/e,x3/8,s3,x7/6,s14,x15/10,pj/h,x7/11,s4,x9/8,s14,x10/4,pn/k,x13/9,po/h,s1,x2/8,s15,x11/4,s6,x10/2,s12,x6/0,s14,x13/4,s11,x8/0,pg/c,x5/12,pd/l,x15/1,s9,x9/8,s7,x15/14,s10,x13/5,s15,x0/10,s4,pp/m,x14/9,ph/f,x2/10,s6,pd/m,s12,pf/h,x7/11,pe/c,x13/10,s6,x5/2,s5,x9/10,s7,x3/14,s12,x9/2,ph/p,x0/3,s15,x9/6,s9,x2/10,s13,x3/7,pm/a,x15/5,pf/h,x6/2,s7,pp/i,x14/0,pc/m,x10/5,pn/d,x15/12,pi/j,x4/3,po/e,x15/9,pk/g,x4/3,s12,pl/i,s6,x5/14,s1,x1/12,s8,x13/11,po/g,x8/0,pf/b,x14/9,pg/k,x0/8,pd/i,s13,x12/14,po/g,x9/7,pp/k,x2/1,pc/b,x4/14,ph/l,x13/0,pk/b,x11/7,s10,x6/2,s13,x7/10,pd/n,x8/4,s10,x6/15,s13,x12/1,s6,pm/i,x10/3,s15,x1/0,pb/d,x5/14,s9,pm/h,s2,x4/10,s12,x9/0,s11,pk/j,x15/14,pd/g,x2/8,s10,x14/11,s4,x2/12,s13,x4/0,s5,x7/10,pf/e,x15/3,s10,x6/2,pk/h,x0/12,pj/c,x1/6,s10,x8/14,s9,x13/5,s11,x1/6,pd/e,x5/0,pa/j,x15/12,ph/c,x3/13,s15,x6/10,s13,x5/7,s2,pi/o,x3/10,s10,x1/4,s10,x9/8,s12,x3/5,s9,x4/15,s3,x1/10,s5,x7/14,s10,x1/2,s7,x11/14,ph/c,x12/1,pn/m,s7,x9/13,pj/g,x10/2,pd/b,x9/14,pf/c,x2/15,s4,x5/13,s14,x11/2,pn/i,s4,x7/13,s10,ph/g,x14/10,s11,x11/12,pe/b,x10/5,pf/o,x2/15,s11,x13/4,pk/c,x6/7,s14,x4/0,ph/a,x8/2,s10,x13/11,s10,x15/9,s8,x10/13,s13,x0/3,s1,x8/15,s8,x0/9,pg/p,x4/10,pm/b,x0/15,s9,x2/5,s13,x9/14,po/j,x6/2,s5,x3/7,pf/a,s3,pp/n,x15/0,s11,x14/9,s3,x1/4,pl/b,x5/9,s5,x14/2,pa/j,x11/0,pg/p,s10,x6/10,pb/e,s9,x3/5,pm/j,x11/1,s11,pp/o,x14/2,s9,x5/0,s14,x2/3,pe/n,x13/10,s5,x12/2,pi/l,x10/5,s4,x0/9,s13,x7/2,s3,x6/4,pa/k,x14/1,s7,x0/2,s14,x12/3,pp/i,x7/15,s11,x0/2,s11,x4/10,s8,x0/12,s11,x3/14,pk/o,x2/6,s5,x13/0,pj/i,x10/4,s15,x0/1,s4,x10/8,s5,x4/5,s12,x13/14,pf/g,x1/11,pa/b,x12/6,po/m,x1/13,s2,x9/12,s8,ph/i,x5/15,s13,x8/14,s1,x0/12,pl/a,x13/2,s1,x5/8,s10,x0/9,pe/g,x15/13,s2,x7/8,s10,pc/m,x2/10,s15,x12/8,pl/a,x0/9,pj/c,x6/14,s3,x4/0,s5,x15/7,pa/b,x1/10,ph/p,x6/15,pa/i,x12/0,pe/l,x9/5,ph/c,s7,po/a,x14/11,s9,x5/4,s15,x8/1,s7,x11/14,s4,x7/5,s6,x8/1,pg/k,x7/3,s7,x5/14,s9,x15/4,s13,x1/12,s3,x3/13,pe/c,x7/2,s7,x12/0,s7,x2/4,s15,x13/15,s1,x12/10,s14,x14/0,pm/h,x1/4,s4,x8/11,pe/k,x9/5,pg/j,x13/8,pf/b,x6/2,s8,x12/1,s2,x15/0,s10,x12/5,s13,x10/4,s2,x15/13,s3,x12/5,pj/e,x3/7,pb/l,s4,x13/14,s8,x15/10,pk/e,x1/2,s12,pb/l,x3/7,pa/k,x14/6,pc/p,x2/1,s4,x0/10,s1,x12/1,s4,x2/6,pe/d,s12,x15/3,po/k,x10/11,s13,pn/b,x1/5,pc/l,x8/14,s7,x0/3,s8,x1/5,s6,x3/14,pf/g,s9,x6/8,s1,x5/9,s8,x3/4,pm/o,x7/9,s15,x13/11,pi/a,x5/2,s4,x3/7,s13,x5/14,pp/f,x0/7,s4,pb/d,x10/15,s8,x5/14,s10,x3/13,ph/n,x11/6,pp/b,s3,x5/2,s15,x12/13,s2,x3/0,pj/e,x14/15,pd/i,x0/9,ph/a,x15/5,s4,x10/12,s5,x0/7,pf/c,s7,x9/4,pb/e,x11/3,s13,x5/4,s3,x7/14,s8,x8/5,s9,x13/12,s6,x11/1,s2,x12/2,s11,x6/0,s5,x11/2,s8,x1/7,pp/i,x0/13,s6,x12/8,s6,x4/3,pe/f,s12,x9/10,pn/b,x15/2,po/p,x13/1,s9,x12/8,s2,x14/6,pe/m,x8/9,s13,x11/10,pd/g,x7/8,pm/n,x2/14,s12,x12/5,po/i,x14/11,s5,x2/6,s8,x5/3,s5,x9/4,pa/j,x8/10,s13,x13/3,pp/d,x11/5,s5,x1/15,s1,x3/10,pg/i,x0/12,s12,x9/8,s6,x0/3,po/l,x12/6,pi/a,s11,x14/1,pd/c,x15/8,s10,x9/1,s5,x10/7,s2,x0/14,s6,x11/5,pg/e,x9/0,s3,x10/6,s15,x1/14,s12,x15/7,s4,x2/9,s3,x11/15,pi/l,x9/12,s9,x5/0,s6,x11/2,po/h,x0/5,pc/j,x15/14,s3,x6/9,s2,x11/7,pe/b,x13/4,pm/l,x6/3,pg/k,x2/0,s7,x3/1,s10,x6/15,pb/h,x9/7,s3,x2/15,pn/m,x1/14,pf/i,x13/10,s14,x1/7,pn/k,x6/13,s11,x5/7,s15,x10/2,pa/m,x4/14,ph/i,x11/15,pa/o,x9/13,s10,pg/j,x5/4,pf/p,x6/14,pj/h,x11/15,s7,x12/1,pc/b,x11/8,pd/f,x4/6,s12,x13/2,s10,x10/7,s15,x13/5,s9,x8/3,s5,pb/l,x7/13,ph/f,x2/4,s1,pc/j,s1,ph/n,x1/13,s13,x9/14,pa/o,x6/7,pg/p,x13/1,s15,x11/6,s6,x3/15,s2,x0/9,s15,pn/c,x7/2,pg/a,x15/13,s9,x7/0,pj/o,x8/4,pf/d,x15/2,pg/h,s6,x12/10,pn/k,x7/9,pb/j,x12/11,ph/i,x8/2,s13,x1/7,s12,x3/11,s4,x1/5,s12,x0/10,s2,x2/1,pe/g,x12/11,pk/p,x2/1,s10,x8/4,s12,x14/3,pg/i,s4,x1/7,s2,x8/9,s10,x14/10,s14,x3/2,pb/e,s1,x6/12,s6,pa/l,x7/8,s2,x0/6,s13,x4/12,pb/f,s2,x2/10,s11,x6/3,s4,x1/12,pa/p,x10/5,s3,x6/9,s4,x8/5,pd/f,x9/4,s5,x3/6,s14,x0/10,ph/l,x6/12,pe/j,x7/1,pa/c,x12/6,s5,x9/3,s9,x2/5,pk/d,x7/9,pl/b,s9,x3/4,s4,x14/13,s10,x2/4,s15,x1/5,pn/m,x7/6,pp/d,s6,x13/10,pc/j,x12/1,pl/n,x2/6,s14,x3/12,po/g,s11,x9/14,s15,x0/11,ph/d,x1/9,s13,pf/a,x5/0,s2,x3/4,s7,x1/0,s7,x2/10,s10,x14/4,s3,x3/10,pc/b,x14/15,pf/i,x6/12,pk/c,s5,x10/14,s2,x0/13,pj/h,s8,x6/11,pb/n,x2/13,ph/m,s10,x14/7,s11,x0/10,pg/b,x2/3,s9,ph/c,x14/9,s15,x8/10,po/d,x4/3,s11,x14/7,s1,x1/2,s12,pm/i,x7/11,pg/d,x1/8,s10,x4/9,pa/b,x6/3,s3,x5/12,pe/n,x2/8,s2,x6/13,s6,x15/10,s4,pb/p,x7/1,s12,x15/11,s2,x7/2,pk/n,s6,x10/0,pi/c,x3/1,s8,x8/10,s10,x3/6,po/k,s2,x8/5,s15,pb/j,s11,x7/4,s14,x14/13,pn/i,x3/1,s6,x11/4,pf/j,x7/15,s6,x10/3,s12,x0/5,s3,x9/15,pl/n,x10/2,pf/e,s14,x6/11,pg/a,x10/14,s12,x4/2,s8,x6/13,s8,x7/12,pf/e,x9/8,s6,pi/d,x0/12,s6,x3/15,pb/j,x4/14,s10,x15/7,s3,x8/3,s2,po/d,x15/13,s5,pl/b,x11/14,s1,x2/13,pg/c,s7,x1/6,s8,x2/5,pp/n,x7/10,pj/m,x5/4,pn/o,x3/9,pf/k,x12/2,s14,x10/13,s5,x5/12,pb/d,x10/3,s5,x6/4,s14,x0/12,s10,x8/9,s4,x6/11,s4,pg/i,x15/8,s15,x11/13,pc/n,x14/3,s7,x5/2,s8,x10/6,s1,x13/4,s12,x5/11,pa/d,x4/3,s10,pn/c,x12/14,s5,x0/15,s8,x9/2,s5,x15/5,pe/j,s2,x0/6,s12,x4/10,s13,pm/k,x9/15,ph/g,x2/1,pc/l,x5/4,s11,x1/8,s13,x6/11,pm/k,s15,pc/g,x0/14,po/h,x13/9,pl/a,x4/6,pc/n,x3/11,pa/e,x4/9,s10,x10/7,pb/p,x13/1,pk/e,x2/0,pf/b,x6/4,s7,x3/5,s5,pg/h,x2/6,s10,x10/1,pj/e,x2/13,pa/c,x11/12,pe/b,s1,x10/2,s8,x9/14,pk/c,x12/3,pb/l,x1/11,s8,x9/0,s3,x13/1,pj/m,x15/6,s11,x13/14,pa/b,x3/9,pf/e,x12/1,s4,x3/7,s2,x12/9,pl/j,x7/0,pn/e,x8/11,s12,x14/0,s13,x13/6,pd/i,x2/7,pg/h,x3/6,s5,x13/11,pm/k,x1/6,s15,x12/3,s13,x9/13,pj/o,x1/7,pe/a,x2/8,s12,x15/3,ph/m,x7/6,s6,x1/13,pp/d,x4/8,pg/b,s9,x5/0,pe/d,x9/13,po/p,x4/3,s11,x6/9,pc/a,x10/14,s5,x9/8,s6,x14/11,s13,x0/5,pb/m,x3/6,s4,x12/15,s4,pe/o,x7/1,pc/l,x12/6,pe/p,x9/0,pa/l,x5/3,s8,x8/1,s13,x15/13,s2,x14/12,s5,pb/m,x5/15,pf/l,x7/14,pp/h,x2/9,s11,x0/1,pi/n,x2/6,pc/g,x11/13,s7,pm/a,x0/10,s4,x9/3,s15,x1/0,s9,x10/6,s5,x1/4,s8,x14/8,s4,x10/12,s1,x5/15,s11,x3/8,pp/d,x7/10,po/i,x0/14,pe/p,s5,x10/7,pc/o,x5/11,pl/n,s4,x10/3,pm/c,s11,x6/9,pb/h,s2,x0/8,s12,x7/10,s1,x11/8,s14,po/a,x2/12,s12,x9/5,s1,x11/14,pn/l,x10/15,pk/o,x0/9,s11,x1/3,s11,x7/14,s10,x4/12,s13,pi/f,s7,pa/j,x0/8,s1,x11/10,s9,x6/3,pi/d,x14/2,s15,pl/e,x12/5,s13,x4/10,pa/f,x12/15,pc/h,x8/2,s10,x9/15,s14,x1/13,pi/g,s15,x8/5,s1,x9/14,s7,x8/6,pl/e,x10/5,s15,x6/13,s7,x11/10,s10,x5/14,s3,x9/6,s7,x7/0,pc/h,x15/12,pe/l,s15,x8/11,s3,x1/7,pg/i,x12/11,s9,x2/10,s4,pf/n,x5/14,pl/c,x15/6,s4,x7/0,s1,x10/2,s9,x8/0,po/n,x5/11,s11,x6/13,pe/m,x3/0,pa/b,x15/4,s10,x1/6,s10,x8/12,po/g,x15/0,pl/k,x5/9,po/n,x15/7,s5,x4/5,s10,x0/3,s12,x2/1,ph/g,s5,x6/4,s1,pe/m,x2/11,po/l,s2,x13/5,pi/k,x2/9,s1,x3/12,s7,x1/4,s2,x6/14,pb/j,x3/13,pn/c,x12/5,s4,x9/13,pm/o,x10/12,s6,x5/6,s10,x12/11,s2,x3/5,s14,x8/12,pl/i,x6/1,s6,x7/11,pg/a,x14/9,ph/d,x7/2,s8,x9/10,pl/n,x1/8,s6,x15/6,s11,x12/10,s9,pp/a,s6,x11/15,s2,x2/3,ph/f,x12/11,s11,x14/5,s6,x7/11,pm/j,x9/1,s10,x12/2,s13,x4/11,po/b,x1/14,s15,x9/4,pl/n,x8/7,ph/k,x11/14,pb/m,x5/8,s2,x15/7,pl/a,s10,x5/2,s7,x1/11,pe/d,x8/14,pk/n,x3/7,s7,x6/2,s2,x1/4,s2,x5/0,s14,pi/e,x13/10,s12,x1/8,pg/m,x3/10,s5,x0/15,s6,x11/8,s12,x13/0,s6,x15/14,s3,pe/j,s10,x11/0,s14,x14/2,s15,x4/7,s7,x3/9,s12,x6/10,pb/a,x8/13,s5,x7/12,s9,x10/1,s1,x13/11,pf/k,x5/12,ph/j,s15,x13/15,s14,x6/11,pa/e,x3/7,s7,x1/2,pk/b,x13/15,s10,x4/11,s7,x0/3,pl/c,s6,x14/12,s10,x0/9,s2,x6/4,pn/p,x11/15,pd/g,s6,x4/1,pp/c,s9,x5/2,s3,x8/3,pb/d,x9/12,pl/k,x14/6,s9,x15/12,pe/g,x0/2,pl/f,x11/3,s4,x13/6,s10,pj/b,x9/3,s7,x8/10,pp/n,x0/2,s11,x13/12,pi/d,x7/5,s11,x1/15,s3,x10/6,s3,x14/11,s10,x3/15,po/n,x1/13,pp/i,x2/14,po/h,x8/3,s8,x6/12,s12,x4/1,pb/f,x12/0,s1,x3/9,pd/g,x7/10,pa/c,x0/1,pl/m,x14/12,s1,po/a,x1/9,pc/k,x12/2,pg/b,x6/4,s13,x14/15,s6,x11/6,s1,x4/12,s3,pc/d,x1/11,s5,x12/10,pg/m,s6,x1/15,po/e,x11/2,s5,x3/1,s6,x11/8,s1,x12/15,s11,x3/10,s10,x5/4,s1,x7/10,s12,x11/5,pl/c,x7/8,pp/e,x3/9,s14,x12/11,s14,x0/5,s11,pn/m,x2/3,s3,x10/5,s13,x8/13,s10,x3/15,s15,x10/1,pe/i,x5/15,s14,x0/10,s1,x11/12,s13,x7/2,pk/j,s13,x11/4,pc/a,x3/0,pg/k,x4/7,s3,x11/13,pp/n,s2,x1/0,s14,x6/14,pk/i,x2/11,pb/g,x9/0,ph/c,x1/3,s10,x0/13,pg/p,x15/9,s13,x4/11,s1,x0/2,s5,x9/15,pb/i,x4/13,s2,x0/3,s5,x13/9,s10,pa/g,x4/7,pb/o,x0/9,pg/m,x1/14,s12,x2/5,s9,x3/9,pp/h,x1/2,s3,x8/12,s6,x11/3,pf/o,x15/6,s3,x1/9,s13,x14/15,s13,x0/2,s8,x3/8,s4,x2/5,s6,x6/14,pd/m,x4/13,pp/o,x9/15,s4,x10/11,s7,pg/i,x15/14,s5,x12/5,s11,pd/c,x11/9,pp/j,x15/5,pf/g,x13/1,s7,x12/3,s3,x9/5,ph/o,x15/6,pf/d,x7/1,s10,x15/6,s4,x13/10,s9,x0/1,pb/g,x11/5,s14,x13/2,s14,x14/10,pp/k,s12,pl/g,s5,x5/0,s15,x15/7,s2,x2/14,s8,x12/11,pk/c,x2/6,po/f,s7,x15/14,s2,x11/4,s14,x8/9,pk/m,x7/3,s13,x14/6,pd/p,x13/9,pl/k,x14/8,s2,pi/b,x0/6,pe/p,x10/2,s1,pf/a,x3/13,ph/i,x10/12,pl/g,x4/11,pm/f,x8/1,s12,x6/7,pg/h,x11/9,pc/p,x13/3,s6,x4/1,pn/g,x2/11,pi/k,x10/0,s10,x15/11,pd/c,x13/5,s8,x1/14,s9,x3/11,s11,x2/4,s9,x12/6,s3,pp/k,x1/10,s8,x8/6,pj/o,x4/2,s6,x5/10,pn/m,x2/12,s3,pi/k,x11/3,s14,x4/13,po/c,x3/14,pk/e,s1,x7/0,s12,x2/10,s11,x12/13,pn/d,s15,x3/5,s4,x7/2,s13,x15/0,s10,po/p,x5/13,s2,x15/6,s15,pl/d,s15,x2/12,s8,x3/15,pa/e,x0/2,s6,x3/7,pm/p,x15/1,pc/a,x10/13,s4,x8/11,po/e,x1/4,pm/l,x12/9,pj/c,x11/3,pd/f,x7/6,pi/h,x14/2,s3,x3/0,s15,x15/10,pc/g,x8/11,s10,x2/7,po/a,x3/1,s3,x12/11,s1,x6/8,pi/g,x11/7,s3,x13/5,s7,x14/4,pn/j,x2/8,s3,x7/1,pl/a,x4/11,s13,x15/7,s12,x13/3,s5,x11/7,pi/h,x13/9,s11,pc/l,x7/15,po/k,x5/3,s8,x10/2,pg/p,x1/6,pe/o,x5/14,s4,x9/3,s14,x5/4,pl/g,x9/1,pe/i,x7/2,pg/j,x6/11,s6,x13/12,s9,x4/2,s9,x3/11,pp/k,x10/7,s4,x9/2,s6,pe/b,x4/13,pm/a,s15,x8/1,pb/l,x2/5,po/h,x15/1,s9,x5/12,pc/d,x0/9,pg/m,x1/12,pj/o,x0/15,s13,x11/9,pm/n,x4/10,s4,po/i,x3/0,pk/d,x11/15,s7,x0/9,pn/m,x1/5,pi/p,x12/14,s1,x9/3,pa/j,x14/6,s9,x11/10,s12,x8/0,pk/d,x9/3,s10,x0/14,s15,x11/7,pe/i,x0/13,pp/c,x11/2,pb/e,s7,pj/n,x4/3,pf/c,x15/10,s15,x11/7,s8,x12/10,s7,x9/2,s5,x3/1,s9,x4/13,s6,x11/8,s3,x12/3,s8,x10/15,s2,x8/12,pl/b,x10/1,s1,x3/14,pa/k,x11/12,pj/h,x15/9,pd/e,x13/2,pc/o,x4/14,s7,x1/0,s7,x6/3,s10,x9/8,s13,x0/11,s2,x3/7,pd/m,s9,x14/4,s13,x6/12,pp/k,x8/7,pb/f,x10/9,pn/h,s3,x6/1,s12,pg/l,s15,x12/3,pi/p,x7/0,s2,x9/10,s13,x6/5,s15,x11/13,s6,x9/6,s14,x7/4,s13,x13/8,s4,x12/1,pl/k,x13/0,s9,x1/3,pg/d,x6/5,s12,x7/14,s7,x4/3,ph/f,x12/9,pi/c,x15/2,s14,x1/5,pa/g,x6/7,pk/p,x3/4,s14,x9/15,s6,x0/5,s2,x14/11,s6,x0/5,pb/i,x12/1,s9,x0/6,pe/k,x2/1,s8,pc/o,x8/10,s14,x12/7,s9,pi/d,x4/0,pm/p,s9,x15/9,s4,x8/14,pg/k,x7/15,s11,x10/1,pl/a,x12/9,s15,x3/4,s2,x7/0,pb/p,x14/2,pl/a,x11/12,pc/h,s10,pe/f,x8/6,s8,pd/c,x12/1,s10,x14/3,pp/i,x5/2,pk/n,x9/4,s8,x5/7,s10,x11/8,s9,x4/7,s4,x1/2,s12,x4/11,pg/o,x15/5,s4,pn/h,x8/2,s4,x0/13,s13,x9/2,pe/j,x6/4,s7,x7/3,s2,x8/14,pa/i,x4/12,s10,x15/8,s8,x1/4,s11,x10/2,s14,x0/9,s5,pl/j,x10/7,s11,x1/4,pp/b,x9/13,s5,x8/2,s12,x0/3,s12,x14/4,s11,x8/10,s14,x13/1,s4,x14/11,pm/d,x12/8,s12,pj/i,x9/3,s1,x2/13,pl/p,x3/0,s4,x13/5,ph/d,x4/7,pp/m,s4,po/g,x9/6,s6,pp/j,s7,x4/8,pb/f,x1/14,pa/d,x10/0,pg/c,x6/15,s10,x13/10,s15,x11/3,s7,x6/1,pp/l,x14/12,pi/m,s14,x11/7,pe/g,s8,x12/4,pm/i,x6/1,pl/j,x9/12,s13,x11/1,s1,x6/12,s13,x5/1,s2,pb/a,x3/9,s7,x11/4,s13,pg/e,x2/7,s13,x14/13,s3,x11/7,ph/n,x13/10,s4,x2/14,s4,x12/4,pm/b,x1/8,pj/e,x9/3,s9,x4/13,s6,x7/10,s9,x6/14,pd/k,x12/3,s5,x4/6,s6,x14/2,s15,x0/1,s7,pi/c,x15/3,pf/h,x6/4,s15,x1/0,po/n,x12/10,pf/d,x0/7,po/e,s11,x13/8,s13,x5/15,pd/l,x9/2,pk/f,s10,x1/10,pn/c,x12/11,s14,x10/13,pj/h,x12/5,pg/m,s2,pn/l,x11/4,s9,x5/8,s15,x7/12,s13,pa/g,x10/5,s1,ph/m,x11/2,pn/c,s9,x3/14,s13,x10/11,s9,x4/14,s5,x5/11,pb/l,x8/13,s7,pm/d,x6/3,s1,x1/4,pc/l,x14/8,s8,x1/9,s9,x5/4,s4,x3/8,pp/b,x15/11,pm/e,x8/1,pk/l,s10,x9/7,s5,x1/5,s6,x8/4,s12,x15/3,pe/a,x2/8,pn/h,x11/7,s12,x0/5,s3,x10/11,pj/d,x3/7,ph/m,s8,x15/2,s12,x13/5,s3,pn/f,x10/4,s5,x6/9,s14,ph/d,x1/13,s14,x11/15,s15,x14/8,pp/e,x11/3,pa/l,x5/12,s8,x7/3,pf/d,x15/5,ph/g,x10/14,s6,x3/1,po/f,x14/0,pj/g,x6/11,pn/k,s12,x15/5,s10,x0/14,s10,x13/5,s3,x9/1,pl/b,x10/13,s8,x8/15,s2,x2/9,s14,x13/0,pp/o,x9/3,s3,x1/4,pd/g,x11/0,s12,x10/5,s12,x13/2,s6,x14/3,pe/n,x1/9,s8,x11/10,s15,x13/15,s10,x8/0,s15,x5/12,s5,x15/9,s6,x5/11,s10,x10/1,s11,x8/5,s3,x2/4,s9,x8/11,s5,x6/1,pc/d,x13/12,s7,x9/3,s8,x2/11,s2,x4/1,s7,x3/12,pp/h,s6,x13/7,pc/f,x8/15,pa/b,x5/3,s15,x12/0,pg/d,s10,x7/14,pi/c,x12/13,ph/m,x15/14,s14,x11/0,s12,x5/7,pk/g,x11/2,s14,x8/7,pf/h,x4/2,s6,x12/14,s14,pb/c,x4/3,s13,x14/13,pp/m,x3/6,s13,x10/15,s15,x0/11,s10,x12/4,pc/b,x14/2,s8,x5/8,pp/k,s3,x10/15,s2,pe/h,x12/4,s13,x7/10,pf/p,x13/11,s10,pb/l,x9/0,s15,x12/4,s1,x3/5,s8,x12/4,s2,x14/5,s12,x7/3,pk/a,x6/2,po/h,x3/12,pg/l,x10/8,ph/p,x3/2,s11,x7/8,s6,x5/14,s10,x6/13,pj/k,x2/12,s3,pa/e,x10/11,pk/g,x15/7,pa/i,x9/3,s1,x13/14,s3,x9/11,s15,x2/5,s11,x0/4,s10,x13/2,pp/l,x8/9,pd/j,x10/4,s10,pa/o,x1/11,s8,x13/5,s1,pj/i,x0/9,s7,x11/14,s10,x10/0,s7,x3/7,pc/a,x9/5,s7,x8/1,s7,x5/12,pl/o,x11/3,s12,x5/15,pa/d,x13/7,s7,x12/4,po/h,x0/13,s10,pn/p,x15/1,s15,x5/3,ph/j,x8/1,s2,x11/6,pd/g,x15/13,s6,x8/5,ph/b,x4/14,s6,x12/3,pg/a,x13/7,s7,x2/11,po/d,x5/8,s10,x7/12,s2,x15/8,s9,x2/14,pj/i,x10/6,s4,x12/5,s5,x7/0,s13,x1/5,ph/p,x7/15,pa/m,x10/1,s14,x7/12,s7,x14/0,pi/b,x11/7,pa/j,x10/8,ph/o,x4/15,s12,x12/13,s3,x8/14,s14,x7/1,pj/c,x13/12,pe/p,x4/2,pb/c,x7/3,ph/d,x9/8,pk/c,x15/14,po/b,x3/2,pp/i,x13/8,pg/m,x5/11,s12,x8/6,s11,x1/13,pl/a,x7/5,pe/k,x4/8,pl/i,x2/11,s13,x9/1,s10,x4/15,pg/e,x8/12,s11,x14/0,s8,x13/2,s5,x5/14,po/m,x9/13,s7,x6/7,ph/j,x12/2,s13,x7/8,s15,pk/i,x3/1,pp/a,x13/10,s12,x7/2,pf/d,s15,x10/12,ph/i,x1/11,s3,x3/9,s1,x15/11,pp/g,s8,x12/8,pn/i,x4/10,s4,x13/15,s9,x3/4,pa/e,x8/10,s1,x4/11,s7,x0/15,po/n,x4/11,pj/p,x8/14,s9,x12/2,s6,x10/1,pd/b,x8/4,po/a,x11/14,s14,x2/0,s3,x15/12,s10,x5/11,s15,x3/9,s13,x13/12,s10,x15/2,s7,x7/12,pm/f,x5/1,s4,x11/8,ph/p,x0/3,pe/d,x5/4,pp/l,x11/2,pb/k,s7,x14/1,s8,x4/9,s6,x7/14,pj/m,x15/4,pi/l,s14,x3/7,s11,x15/12,pg/e,x2/4,pk/b,x7/11,s11,x4/13,pd/e,x8/10,pb/a,x12/5,s5,x13/2,s15,x7/0,s7,x6/5,po/m,x7/12,ph/k,x14/3,s9,x0/6,s5,pa/p,x13/15,pn/l,x4/3,s3,x6/2,s4,x11/8,s3,x3/6,po/j,x13/10,s15,x3/7,pe/i,x10/1,s6,x13/12,pf/b,x5/4,s4,x15/7,s10,x11/5,s7,x4/12,s1,x15/14,ph/d,x8/9,s2,x4/5,s3,pi/k,x7/11,s11,pc/g,s10,x1/9,s10,x8/10,s7,x0/6,s10,x13/10,s9,x11/14,s12,x6/15,s12,x8/3,s9,x10/1,ph/p,x7/6,s13,x15/12,pe/d,x4/10,s2,x15/12,s11,x13/7,pk/j,x3/9,s4,x7/4,s5,x11/14,s1,x12/1,s11,pn/i,x6/8,s4,x3/14,pc/o,x12/6,s11,x1/3,pj/k,x4/13,s4,x1/0,s2,x7/4,pl/h,x15/11,pb/e,x3/5,pn/g,x0/14,s10,x8/2,s5,x15/12,s6,x0/4,s14,x10/9,s15,x1/14,pe/k,x4/6,pa/g,x5/11,pe/h,x0/14,s1,x1/2,s2,x5/6,s12,x8/2,s9,x6/15,s7,x5/1,s5,x15/12,s11,x3/14,s1,x15/12,s2,x1/7,pn/o,x5/4,s8,x2/15,s15,x6/4,pe/h,x7/10,s12,x1/9,pg/m,x0/14,s2,x4/2,s11,x9/15,s6,x2/1,s10,x13/3,pc/n,x14/1,s14,x7/6,s5,x13/2,pj/g,s5,x4/0,pl/i,x12/14,pc/k,x15/10,s6,x9/2,s3,pd/m,x12/6,pe/g,s5,x9/13,pl/h,x4/11,s12,x15/9,s12,x10/13,s3,pb/e,x0/11,s11,x7/9,pg/j,x6/1,s4,x2/5,s12,x14/4,pk/b,x1/10,s5,x8/12,s12,x11/4,s5,x12/1,pf/d,x4/7,s10,x8/2,s12,x7/4,po/g,x1/5,s11,x6/14,s15,x1/2,s3,x7/12,ph/f,x14/9,pa/b,x0/11,pg/n,x8/1,pf/p,x0/12,pa/n,x1/4,pd/c,x14/12,s12,x1/4,s1,x15/13,pm/a,x9/5,s9,x4/8,s13,x13/11,s6,x15/3,s14,x10/7,s7,x11/0,s7,x8/3,s14,x9/6,s4,x7/8,s2,x10/11,s10,x8/2,s10,x5/3,pf/g,x11/10,pp/k,x9/14,s9,x1/12,s3,x2/9,s1,ph/e,x3/1,pj/o,s13,x2/12,s15,x0/9,s12,x3/13,s2,x9/6,ph/a,s4,x13/8,pf/d,x4/12,s13,x0/1,s4,x15/4,s4,x1/14,s12,x13/4,pc/h,s3,x6/9,pj/d,s10,x11/12,s8,x14/1,s10,x11/5,s1,x2/4,s15,x1/9,pm/k,x4/5,pf/d,s15,x9/13,pj/l,x2/5,po/n,x3/9,s4,x5/4,s14,x15/10,pg/a,x14/3,pp/j,x13/7,pf/k,x10/0,pp/l,x8/6,s14,x0/14,s4,pi/o,x6/7,s12,x1/4,s13,x5/15,pk/c,s15,x10/11,ph/o,x4/9,pb/n,x8/7,pg/j,s11,x9/2,s11,x4/5,s10,x2/9,s4,x8/13,s11,x12/11,s9,pp/o,x7/10,s8,x8/13,s9,x11/3,pm/f,x15/4,s1,x13/11,pn/g,x15/12,s2,x9/8,s4,x13/0,pe/b,x4/6,s5,x12/10,pp/m,x2/13,ph/b,s15,x3/7,pn/p,s10,x12/9,pb/h,x15/2,pm/c,x11/4,s7,x9/6,s2,x11/4,s12,x13/12,s13,x10/9,s3,x2/3,pg/e,x15/10,pi/h,x5/6,s2,x15/2,s3,x12/9,s12,pj/f,x14/11,pi/n,x9/4,s15,x1/10,s10,x3/5,s12,x2/8,s13,x3/10,s2,x2/9,s8,x15/0,pl/c,x3/12,pf/g,x11/0,s4,x10/6,pc/b,x3/9,s15,pl/f,x4/0,pm/g,x3/5,pc/k,x8/7,pp/l,x12/15,pj/b,x3/0,s15,x8/6,pd/l,x11/1,s2,pe/h,x14/7,pm/i,x11/0,pb/d,x10/3,pj/f,x2/1,s14,x6/11,pc/p,s6,pg/d,x5/14,s2,x11/4,s9,x2/6,s7,x12/15,s13,x5/3,pn/m,x7/12,ph/j,x10/1,pd/n,x11/7,s13,x4/8,pg/m,x0/11,pp/n,x4/10,pd/k,x8/7,s2,x9/1,s1,x12/6,s13,pm/n,x11/5,s8,x4/7,s14,x15/0,pe/d,s2,x1/8,pn/o,x6/13,s10,x10/2,pd/g,s3,x12/1,pn/j,x13/7,s10,x1/4,pd/k,x6/2,pn/l,x13/8,s15,x15/4,pd/h,x5/7,pn/l,x11/12,s4,x7/6,s9,x13/2,s3,x3/15,pc/b,x10/14,s4,x13/6,s9,x9/8,pf/n,s11,pe/k,x1/4,s12,x7/12,s8,x10/4,pa/l,s2,x12/1,s5,x10/0,s4,x1/4,pf/o,x13/11,s1,x5/15,pc/m,s13,x11/3,s6,pi/f,x5/6,s6,x3/7,s3,x6/1,s3,x5/4,s1,x8/13,s13,x2/10,s8,x1/3,s1,x5/15,s8,x8/9,s13,x14/2,s5,x7/13,s11,x2/10,pl/e,x11/9,s15,x13/8,s4,x15/5,pm/d,x6/7,pc/j,x14/12,s1,x15/2,pb/e,x12/5,pm/d,x11/14,s13,x13/0,s1,x1/8,s13,x6/9,s8,pg/p,x14/15,s12,x4/1,s9,x6/2,s8,x0/12,pn/m,x10/5,s2,x1/13,s5,x15/8,s7,x14/2,pd/c,s1,x5/1,s12,x10/6,s3,x0/7,pp/g,x4/8,s9,x6/1,s11,x14/12,s4,x15/10,s11,x2/8,pe/a,s3,pl/o,x9/6,pe/h,x14/1,s6,x6/10,s5,x9/0,pc/k,x10/3,s6,x13/2,pl/e,x12/6,s6,x3/4,s1,x9/8,pf/c,x6/15,pe/n,s6,x10/13,s8,x3/2,s11,x13/0,s2,x14/15,s1,x13/5,s4,x3/2,s7,x14/6,s7,x5/1,pm/g,x9/3,s13,x5/8,pj/l,x15/12,s13,x4/1,pd/h,x3/5,s10,x9/10,s12,pl/i,x15/0,s5,pp/b,x10/13,s5,x5/6,s11,x10/12,s13,x13/7,s12,x14/0,po/a,x4/12,pp/c,x1/14,s7,x6/5,po/j,x7/9,s1,x14/12,pe/m,x5/10,pl/c,x3/8,s4,x9/11";
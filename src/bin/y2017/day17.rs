@@ -28,14 +28,4 @@ fn part1_1() {
     assert_eq!(part1(3), 638);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 1173);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 1930815);
-}
-
 pub const INPUT: usize = 304;
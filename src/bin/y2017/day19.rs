@@ -96,16 +96,6 @@ fn part1_1() {
     assert_eq!(part1(EXAMPLE), "ABCDEF");
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), "VTWBPYAQFU");
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 17358);
-}
-
 #[cfg(test)]
 static EXAMPLE: &str = "
      |
@@ -1,9 +1,12 @@
 use std::fmt;
 use std::collections::{BTreeMap, HashSet};
 
+use advent_of_code::{parse_lines, Pattern};
+
 pub fn part1(s: &str) -> usize {
     // Find the particle with the lowest acceleration, velocity, and position.
     parse(s)
+        .into_iter()
         .enumerate()
         .min_by(|&(_, a), &(_, b)| {
             a.a
@@ -109,12 +112,12 @@ impl Solution {
     }
 }
 
-fn parse<'a>(s: &'a str) -> impl Iterator<Item = Particle> + 'a {
-    s.trim().lines().map(|x| Particle::parse(x))
+fn parse(s: &str) -> Vec<Particle> {
+    parse_lines(s).unwrap_or_else(|e| panic!("{}", e.render(s)))
 }
 
 pub fn part2(s: &str) -> usize {
-    let particles = parse(s).collect::<Vec<_>>();
+    let particles = parse(s);
     let mut collisions = BTreeMap::new();
     for (i1, p1) in particles.iter().enumerate() {
         for (i2, p2) in particles.iter().enumerate() {
@@ -157,7 +160,8 @@ pub fn part2(s: &str) -> usize {
     particles.len() - collided.len()
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Ord, Eq)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Ord, Eq, Pattern)]
+#[pattern = "{x},{y},{z}"]
 struct Vector {
     x: i32,
     y: i32,
@@ -171,25 +175,13 @@ impl fmt::Debug for Vector {
 }
 
 impl Vector {
-    fn parse(s: &str) -> Self {
-        let mut n = s.split(',').map(|x| {
-            x.trim().parse::<i32>().unwrap_or_else(|e| {
-                panic!("failed to parse {:?} (part of {:?}) as i32: {:?}", x, s, e);
-            })
-        });
-        Vector {
-            x: n.next().unwrap(),
-            y: n.next().unwrap(),
-            z: n.next().unwrap(),
-        }
-    }
-
     fn mag_sq(self) -> u64 {
         (self.x.abs() as u64).pow(2) + (self.y.abs() as u64).pow(2) + (self.z.abs() as u64).pow(2)
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, Pattern)]
+#[pattern = "p=<{pos}>, v=<{v}>, a=<{a}>"]
 struct Particle {
     pos: Vector,
     v: Vector,
@@ -202,16 +194,6 @@ impl fmt::Debug for Particle {
     }
 }
 
-impl Particle {
-    fn parse(s: &str) -> Particle {
-        Particle {
-            pos: Vector::parse(&s[s.find("p=").unwrap() + 3..s.find(">, v").unwrap()]),
-            v: Vector::parse(&s[s.find("v=").unwrap() + 3..s.find(">, a").unwrap()]),
-            a: Vector::parse(&s[s.find("a=").unwrap() + 3..s.len() - 1]),
-        }
-    }
-}
-
 #[bench]
 fn parse_1(b: &mut ::test::Bencher) {
     b.iter(|| parse(INPUT));
@@ -223,8 +205,11 @@ fn part1_1() {
 }
 
 #[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 457);
+fn parse_particle() {
+    let p = "p=< 3,0,0>, v=<2,0,-1>, a=<-1,0,0>".parse::<Particle>().unwrap();
+    assert_eq!(format!("{:?}", p), "p=<3,0,0>, v=<2,0,-1>, a=<-1,0,0>");
+    let s = "p=<1,2,3>, v=<4,5>, a=<0,0,0>";
+    assert_eq!(parse_lines::<Particle>(s).unwrap_err().span.start, 17);
 }
 
 #[test]
@@ -232,11 +217,6 @@ fn part2_1() {
     assert_eq!(part2(EXAMPLE_1), 1);
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 448);
-}
-
 #[cfg(test)]
 static EXAMPLE: &str = "
 p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>
@@ -114,16 +114,6 @@ pub fn part2(s: &str) -> u32 {
     eval(s, 18)
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 176);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 2368161);
-}
-
 static INITIAL_PATTERN: &str = "
 .#.
 ..#
@@ -1,74 +1,10 @@
-use day18::{to_register, Value};
+use advent_of_code::duet::{self, Program};
 
-#[derive(Copy, Clone, Debug)]
-enum Instruction {
-    Set(usize, Value),
-    Sub(usize, Value),
-    Mul(usize, Value),
-    Jmp(Value, Value),
-}
-
-enum Out {
-    Mul,
-    None,
-    Jmp(isize),
-}
-
-impl Instruction {
-    fn exec(self, registers: &mut [i64; 8]) -> Out {
-        match self {
-            Instruction::Set(reg, val) => registers[reg] = val.resolve(registers),
-            Instruction::Sub(reg, val) => registers[reg] -= val.resolve(registers),
-            Instruction::Mul(reg, val) => {
-                registers[reg] *= val.resolve(registers);
-                return Out::Mul;
-            }
-            Instruction::Jmp(cnd, off) => {
-                if cnd.resolve(registers) != 0 {
-                    return Out::Jmp(off.resolve(registers) as isize);
-                }
-            }
-        }
-        Out::None
-    }
-}
-
-fn parse(s: &str) -> Vec<Instruction> {
-    s.trim()
-        .lines()
-        .map(|line| {
-            let arg1 = Value::parse(&line[4..5]);
-            match &line[0..3] {
-                "jnz" => Instruction::Jmp(arg1, Value::parse(&line[6..])),
-                val => {
-                    let arg1 = to_register(line.as_bytes()[4] as char);
-                    match val {
-                        "set" => Instruction::Set(arg1, Value::parse(&line[6..])),
-                        "sub" => Instruction::Sub(arg1, Value::parse(&line[6..])),
-                        "mul" => Instruction::Mul(arg1, Value::parse(&line[6..])),
-                        _ => unreachable!("unexpected instruction: {}", line),
-                    }
-                }
-            }
-        })
-        .collect::<Vec<_>>()
-}
-
-pub fn part1(s: &str) -> usize {
-    let instructions = parse(s);
-    let mut registers = [0; 8];
-    let mut times = 0;
-    let mut pos = 0;
-    while pos < instructions.len() {
-        let instr = instructions[pos];
-        pos += 1;
-        match instr.exec(&mut registers) {
-            Out::None => {}
-            Out::Mul => times += 1,
-            Out::Jmp(off) => pos = ((pos as isize) + off - 1) as usize,
-        }
-    }
-    times
+pub fn part1(s: &str) -> u64 {
+    let instructions = duet::parse(s).unwrap_or_else(|e| panic!("{}", e.render(s)));
+    let mut program = Program::new(&instructions);
+    program.run();
+    program.executed("mul")
 }
 
 /// This is a manually decompiled version of the below assembly, given that a=1.
@@ -86,16 +22,6 @@ pub fn part2(_: &str) -> i64 {
     h
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 3025);
-}
-
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 915);
-}
-
 pub static INPUT: &str = "
 set b 57
 set c b
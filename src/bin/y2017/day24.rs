@@ -91,21 +91,11 @@ fn part1_1() {
     assert_eq!(part1(EXAMPLE), 31);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 1906);
-}
-
 #[test]
 fn part2_1() {
     assert_eq!(part2(EXAMPLE), 19);
 }
 
-#[test]
-fn part2_actual() {
-    assert_eq!(part2(INPUT), 1824);
-}
-
 #[cfg(test)]
 static EXAMPLE: &str = "
 0/2
@@ -29,11 +29,6 @@ fn part1_1() {
     assert_eq!(part1(EXAMPLE), 3);
 }
 
-#[test]
-fn part1_actual() {
-    assert_eq!(part1(INPUT), 2526);
-}
-
 #[derive(Copy, Clone, PartialEq, Eq)]
 struct State {
     // value, direction, next_state
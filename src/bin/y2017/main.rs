@@ -12,7 +12,7 @@ extern crate quickcheck;
 extern crate smallvec;
 extern crate test;
 
-gen!(
+gen!(2017;
     day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
     day14, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
 );
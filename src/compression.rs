@@ -0,0 +1,430 @@
+//! The experimental compression format of 2016 day 9.
+//!
+//! A marker `(NxM)` repeats the `N` bytes after it `M` times; the rest of
+//! the input is copied as it is. In version one, the repeated bytes are
+//! copied as they are too, while in version two any markers in them are
+//! decompressed in turn, so the output can be far too long to hold in memory.
+
+use std::cmp;
+use std::io::{self, Read, Seek, SeekFrom};
+
+use memchr::memchr;
+#[cfg(test)]
+use {ErrorKind, Span};
+use {Parser, ParserError};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Version {
+    One,
+    Two,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Marker {
+    /// The length of the marker itself.
+    header: usize,
+    len: usize,
+    times: u64,
+}
+
+impl Marker {
+    /// Reads the marker at the start of `data`, and checks that the bytes it
+    /// repeats follow it.
+    fn parse(data: &[u8]) -> Result<Marker, ParserError> {
+        let mut p = Parser::new(data);
+        p.consume(b'(')?;
+        let len = p.consume_number()? as usize;
+        p.consume(b'x')?;
+        let times = p.consume_number()?;
+        p.consume(b')')?;
+        let header = p.position();
+        p.consume_bytes(len)?;
+        Ok(Marker { header, len, times })
+    }
+
+    fn span(self, data: &[u8]) -> &[u8] {
+        &data[self.header..self.header + self.len]
+    }
+}
+
+/// The length of the decompressed `data`.
+pub fn decompressed_len(data: &[u8], version: Version) -> Result<u64, ParserError> {
+    let mut len = 0;
+    let mut i = 0;
+    while i < data.len() {
+        if data[i] == b'(' {
+            let marker = Marker::parse(&data[i..]).map_err(|e| e.shifted(i))?;
+            let span = marker.span(&data[i..]);
+            let once = match version {
+                Version::One => span.len() as u64,
+                Version::Two => {
+                    decompressed_len(span, version).map_err(|e| e.shifted(i + marker.header))?
+                }
+            };
+            len += once * marker.times;
+            i += marker.header + marker.len;
+        } else {
+            let run = memchr(b'(', &data[i..]).unwrap_or(data.len() - i);
+            len += run as u64;
+            i += run;
+        }
+    }
+    Ok(len)
+}
+
+/// Part of the input being decompressed, `passes` more times from `pos`.
+#[derive(Clone, Debug)]
+struct Frame<'a> {
+    data: &'a [u8],
+    pos: usize,
+    passes: u64,
+    /// Whether markers are copied rather than decompressed.
+    literal: bool,
+}
+
+impl<'a> Frame<'a> {
+    /// The bytes up to the next marker.
+    fn run(&self) -> &'a [u8] {
+        let rest = &self.data[self.pos..];
+        if self.literal {
+            rest
+        } else {
+            &rest[..memchr(b'(', rest).unwrap_or(rest.len())]
+        }
+    }
+}
+
+/// Decompresses lazily, as an iterator over the bytes or through `Read`.
+///
+/// Seeking, or skipping with `nth`, steps over whole repetitions at a time
+/// without producing the bytes in between.
+#[derive(Clone, Debug)]
+pub struct Decompressor<'a> {
+    input: &'a [u8],
+    version: Version,
+    stack: Vec<Frame<'a>>,
+    len: u64,
+    remaining: u64,
+}
+
+impl<'a> Decompressor<'a> {
+    pub fn new(input: &'a [u8], version: Version) -> Result<Decompressor<'a>, ParserError> {
+        let len = decompressed_len(input, version)?;
+        let mut decompressor = Decompressor {
+            input,
+            version,
+            stack: Vec::new(),
+            len,
+            remaining: len,
+        };
+        decompressor.rewind();
+        Ok(decompressor)
+    }
+
+    /// The length of the whole output.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// How much of the output has been produced or skipped.
+    pub fn offset(&self) -> u64 {
+        self.len - self.remaining
+    }
+
+    fn rewind(&mut self) {
+        self.stack.clear();
+        self.stack.push(Frame {
+            data: self.input,
+            pos: 0,
+            passes: 1,
+            literal: false,
+        });
+        self.remaining = self.len;
+    }
+
+    /// Drops finished frames and starts the next pass of repeated ones,
+    /// returning the frame the next byte comes from.
+    fn top(&mut self) -> Option<&mut Frame<'a>> {
+        loop {
+            let done = {
+                let frame = self.stack.last_mut()?;
+                if frame.pos < frame.data.len() {
+                    break;
+                }
+                frame.passes -= 1;
+                frame.pos = 0;
+                frame.passes == 0
+            };
+            if done {
+                self.stack.pop();
+            }
+        }
+        self.stack.last_mut()
+    }
+
+    /// If the top frame is at a marker, steps over it and returns it with
+    /// the bytes it repeats.
+    fn marker(&mut self) -> Option<(Marker, &'a [u8])> {
+        let frame = self.top()?;
+        if frame.literal || frame.data[frame.pos] != b'(' {
+            return None;
+        }
+        let at = &frame.data[frame.pos..];
+        let marker = Marker::parse(at).expect("checked by decompressed_len");
+        frame.pos += marker.header + marker.len;
+        Some((marker, marker.span(at)))
+    }
+
+    fn push(&mut self, span: &'a [u8], passes: u64) {
+        if !span.is_empty() && passes > 0 {
+            self.stack.push(Frame {
+                data: span,
+                pos: 0,
+                passes,
+                literal: self.version == Version::One,
+            });
+        }
+    }
+
+    /// The bytes that come next without any markers in between.
+    fn run(&mut self) -> Option<&'a [u8]> {
+        while let Some((marker, span)) = self.marker() {
+            self.push(span, marker.times);
+        }
+        self.top().map(|frame| frame.run())
+    }
+
+    fn consume(&mut self, n: usize) {
+        self.stack.last_mut().unwrap().pos += n;
+        self.remaining -= n as u64;
+    }
+
+    /// Skips over the next `n` bytes of output.
+    pub fn skip_bytes(&mut self, n: u64) {
+        let mut n = cmp::min(n, self.remaining);
+        while n > 0 {
+            if let Some((marker, span)) = self.marker() {
+                let once = match self.version {
+                    Version::One => span.len() as u64,
+                    Version::Two => decompressed_len(span, self.version).unwrap(),
+                };
+                if n >= once * marker.times {
+                    n -= once * marker.times;
+                    self.remaining -= once * marker.times;
+                } else {
+                    self.push(span, marker.times - n / once);
+                    self.remaining -= n / once * once;
+                    n %= once;
+                }
+                continue;
+            }
+            let run = self.top().unwrap().run().len();
+            let run = cmp::min(run as u64, n) as usize;
+            self.consume(run);
+            n -= run as u64;
+        }
+    }
+}
+
+impl<'a> Iterator for Decompressor<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.run()?[0];
+        self.consume(1);
+        Some(byte)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u8> {
+        self.skip_bytes(n as u64);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = cmp::min(self.remaining, usize::max_value() as u64) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> Read for Decompressor<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() {
+            let run = match self.run() {
+                Some(run) => run,
+                None => break,
+            };
+            let n = cmp::min(run.len(), buf.len() - written);
+            buf[written..written + n].copy_from_slice(&run[..n]);
+            self.consume(n);
+            written += n;
+        }
+        Ok(written)
+    }
+}
+
+impl<'a> Seek for Decompressor<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let to = match pos {
+            SeekFrom::Start(to) => Some(to),
+            SeekFrom::Current(by) => add_signed(self.offset(), by),
+            SeekFrom::End(by) => add_signed(self.len, by),
+        };
+        let to =
+            to.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seek before the start"))?;
+        if to < self.offset() {
+            self.rewind();
+        }
+        let position = self.offset();
+        self.skip_bytes(to - position);
+        Ok(to)
+    }
+}
+
+fn add_signed(from: u64, by: i64) -> Option<u64> {
+    if by < 0 {
+        from.checked_sub(by.wrapping_neg() as u64)
+    } else {
+        from.checked_add(by as u64)
+    }
+}
+
+/// Repetitions longer than this aren't looked for.
+const MAX_SPAN: usize = 64;
+
+fn marker_len(len: usize, times: usize) -> usize {
+    len.to_string().len() + times.to_string().len() + 3
+}
+
+/// Replaces runs of repeated bytes in `data` with markers, greedily taking
+/// whichever saves the most at each point. The markers are never nested, so
+/// version one decompresses the output back into `data`, and so does
+/// version two if `data` has no `(`: those are only ever in a marker's span.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let rest = &data[i..];
+        let mut best = None;
+        for len in 1..=cmp::min(MAX_SPAN, rest.len() / 2) {
+            let times = 1 + rest[len..]
+                .chunks(len)
+                .take_while(|&chunk| chunk == &rest[..len])
+                .count();
+            let saved = (len * times) as isize - (len + marker_len(len, times)) as isize;
+            if saved > best.map_or(0, |(_, _, saved)| saved) {
+                best = Some((len, times, saved));
+            }
+        }
+        match best {
+            Some((len, times, _)) => {
+                out.extend(format!("({}x{})", len, times).bytes());
+                out.extend(&rest[..len]);
+                i += len * times;
+            }
+            None if rest[0] == b'(' => {
+                out.extend(b"(1x1)(");
+                i += 1;
+            }
+            None => {
+                out.push(rest[0]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+fn decompress(data: &str, version: Version) -> String {
+    let bytes = Decompressor::new(data.as_bytes(), version)
+        .unwrap()
+        .collect::<Vec<_>>();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn compression_decompress() {
+    assert_eq!(decompress("A(2x2)BCD(2x2)EFG", Version::One), "ABCBCDEFEFG");
+    assert_eq!(
+        decompress("X(8x2)(3x3)ABCY", Version::One),
+        "X(3x3)ABC(3x3)ABCY"
+    );
+    assert_eq!(
+        decompress("X(8x2)(3x3)ABCY", Version::Two),
+        "XABCABCABCABCABCABCY"
+    );
+    assert_eq!(decompress("A(0x5)B(2x0)CD", Version::Two), "AB");
+
+    let nested = b"(27x12)(20x12)(13x14)(7x10)(1x12)A";
+    assert_eq!(decompressed_len(nested, Version::Two), Ok(241920));
+    assert_eq!(decompressed_len(nested, Version::One), Ok(27 * 12));
+    assert_eq!(
+        decompressed_len(b"(3x3)AB", Version::One).map_err(|e| e.kind),
+        Err(ErrorKind::Eof)
+    );
+    let error = Decompressor::new(b"AB(2x2)CD(5x1)(1xA)", Version::Two).unwrap_err();
+    assert_eq!(error.span, Span { start: 17, end: 18 });
+}
+
+#[test]
+fn compression_seek() {
+    let data = b"(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN";
+    let all = Decompressor::new(data, Version::Two)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(all.len(), 445);
+    for (k, &byte) in all.iter().enumerate() {
+        let mut decompressor = Decompressor::new(data, Version::Two).unwrap();
+        assert_eq!(decompressor.nth(k), Some(byte), "{}", k);
+        assert_eq!(decompressor.offset(), k as u64 + 1);
+        assert_eq!(
+            decompressor.len() - decompressor.offset(),
+            decompressor.count() as u64
+        );
+    }
+
+    let mut decompressor = Decompressor::new(data, Version::Two).unwrap();
+    let mut buf = [0; 10];
+    decompressor.seek(SeekFrom::End(-20)).unwrap();
+    decompressor.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, all[425..435]);
+    decompressor.seek(SeekFrom::Current(-30)).unwrap();
+    decompressor.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, all[405..415]);
+    assert!(decompressor.seek(SeekFrom::Current(-500)).is_err());
+
+    // Far past what could be held in memory.
+    let huge = b"(31x999)(23x999)(15x999)(8x999)(1x999)AB";
+    let mut decompressor = Decompressor::new(huge, Version::Two).unwrap();
+    assert_eq!(decompressor.len(), 999u64.pow(5) + 1);
+    assert_eq!(decompressor.nth(999usize.pow(3)), Some(b'A'));
+    decompressor.seek(SeekFrom::End(-1)).unwrap();
+    assert_eq!(decompressor.collect::<Vec<_>>(), b"B");
+}
+
+#[test]
+fn compression_round_trip() {
+    let inputs: &[&[u8]] = &[
+        b"",
+        b"ADVENT",
+        b"XYZXYZXYZXYZXYZ-abababababababab(((((((((())",
+        b"(1x1)(1x1)(1x1)(1x1)",
+    ];
+    for &input in inputs {
+        let compressed = compress(input);
+        let output = Decompressor::new(&compressed, Version::One)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(output, input, "{:?}", String::from_utf8_lossy(&compressed));
+    }
+    assert_eq!(compress(b"ABCABCABCABCABCD"), b"(3x5)ABCD");
+    let round = Decompressor::new(b"(3x5)ABCD", Version::Two)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(compress(&round), b"(3x5)ABCD");
+}
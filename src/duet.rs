@@ -0,0 +1,306 @@
+//! The duet language of 2017 days 18 and 23, and a scheduler running
+//! several programs that send each other values.
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use {parse_lines, ParserError, Pattern};
+
+pub fn to_register(ch: char) -> usize {
+    (ch as u8 - b'a') as usize
+}
+
+/// One of the registers `a` to `z`, by its index.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Register(pub usize);
+
+impl FromStr for Register {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Register, String> {
+        match s.as_bytes() {
+            [c @ b'a'..=b'z'] => Ok(Register(to_register(*c as char))),
+            _ => Err(format!("expected a register, found {:?}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Value {
+    Register(Register),
+    Value(i64),
+}
+
+impl Value {
+    pub fn resolve(self, registers: &[i64]) -> i64 {
+        match self {
+            Value::Register(reg) => registers[reg.0],
+            Value::Value(val) => val,
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Value, String> {
+        match s.parse::<i64>() {
+            Ok(val) => Ok(Value::Value(val)),
+            Err(_) => s.parse().map(Value::Register),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Pattern)]
+pub enum Instruction {
+    #[pattern = "snd {0}"]
+    Snd(Value),
+    #[pattern = "rcv {0}"]
+    Rcv(Register),
+    #[pattern = "set {0} {1}"]
+    Set(Register, Value),
+    #[pattern = "add {0} {1}"]
+    Add(Register, Value),
+    #[pattern = "sub {0} {1}"]
+    Sub(Register, Value),
+    #[pattern = "mul {0} {1}"]
+    Mul(Register, Value),
+    #[pattern = "mod {0} {1}"]
+    Mod(Register, Value),
+    #[pattern = "jgz {0} {1}"]
+    Jgz(Value, Value),
+    #[pattern = "jnz {0} {1}"]
+    Jnz(Value, Value),
+}
+
+impl Instruction {
+    pub fn name(self) -> &'static str {
+        match self {
+            Instruction::Snd(_) => "snd",
+            Instruction::Rcv(_) => "rcv",
+            Instruction::Set(..) => "set",
+            Instruction::Add(..) => "add",
+            Instruction::Sub(..) => "sub",
+            Instruction::Mul(..) => "mul",
+            Instruction::Mod(..) => "mod",
+            Instruction::Jgz(..) => "jgz",
+            Instruction::Jnz(..) => "jnz",
+        }
+    }
+}
+
+/// Parses a program, an instruction per line; `ParserError::render` shows
+/// the line at fault.
+pub fn parse(s: &str) -> Result<Vec<Instruction>, ParserError> {
+    parse_lines(s)
+}
+
+/// What `Program::step` did.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    Ran,
+    Sent(i64),
+    /// `rcv` into the register found nothing to receive; the program stays
+    /// at it.
+    Blocked(usize),
+    Halted,
+}
+
+pub struct Program<'a> {
+    pub registers: [i64; 26],
+    pub position: i64,
+    /// How many values the program has sent.
+    pub sent: usize,
+    instructions: &'a [Instruction],
+    counts: Vec<u64>,
+}
+
+impl<'a> Program<'a> {
+    pub fn new(instructions: &'a [Instruction]) -> Program<'a> {
+        Program {
+            registers: [0; 26],
+            position: 0,
+            sent: 0,
+            instructions,
+            counts: vec![0; instructions.len()],
+        }
+    }
+
+    pub fn current(&self) -> Option<Instruction> {
+        if self.position < 0 {
+            return None;
+        }
+        self.instructions.get(self.position as usize).cloned()
+    }
+
+    /// Runs one instruction, receiving from `inbox`.
+    pub fn step(&mut self, inbox: &mut VecDeque<i64>) -> Step {
+        let instr = match self.current() {
+            Some(instr) => instr,
+            None => return Step::Halted,
+        };
+        let mut step = Step::Ran;
+        let mut offset = 1;
+        let registers = &mut self.registers;
+        match instr {
+            Instruction::Snd(val) => {
+                self.sent += 1;
+                step = Step::Sent(val.resolve(registers));
+            }
+            Instruction::Rcv(Register(reg)) => match inbox.pop_front() {
+                Some(input) => registers[reg] = input,
+                None => return Step::Blocked(reg),
+            },
+            Instruction::Set(Register(reg), val) => registers[reg] = val.resolve(registers),
+            Instruction::Add(Register(reg), val) => registers[reg] += val.resolve(registers),
+            Instruction::Sub(Register(reg), val) => registers[reg] -= val.resolve(registers),
+            Instruction::Mul(Register(reg), val) => registers[reg] *= val.resolve(registers),
+            Instruction::Mod(Register(reg), val) => registers[reg] %= val.resolve(registers),
+            Instruction::Jgz(cond, val) => {
+                if cond.resolve(registers) > 0 {
+                    offset = val.resolve(registers);
+                }
+            }
+            Instruction::Jnz(cond, val) => {
+                if cond.resolve(registers) != 0 {
+                    offset = val.resolve(registers);
+                }
+            }
+        }
+        self.counts[self.position as usize] += 1;
+        self.position += offset;
+        step
+    }
+
+    /// Runs until the program halts, which it must do without sending or
+    /// receiving anything.
+    pub fn run(&mut self) {
+        loop {
+            match self.step(&mut VecDeque::new()) {
+                Step::Ran => {}
+                Step::Halted => return,
+                step => panic!("program did not run on its own: {:?}", step),
+            }
+        }
+    }
+
+    /// How many times the instruction at each position has run.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// How many times instructions called `name` have run.
+    pub fn executed(&self, name: &str) -> u64 {
+        self.instructions
+            .iter()
+            .zip(&self.counts)
+            .filter(|&(instr, _)| instr.name() == name)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+/// How `Scheduler::run` ended.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Finish {
+    Halted,
+    /// Some programs are waiting to receive, and no other program can send.
+    Deadlock,
+}
+
+/// Runs copies of a program in turns, each with its id in register `p`, and
+/// delivers what each sends to the next, in a ring.
+pub struct Scheduler<'a> {
+    pub programs: Vec<Program<'a>>,
+    inboxes: Vec<VecDeque<i64>>,
+}
+
+impl<'a> Scheduler<'a> {
+    /// How many instructions a program runs before the next takes its turn.
+    const SLICE: usize = 1000;
+
+    pub fn new(instructions: &'a [Instruction], count: usize) -> Scheduler<'a> {
+        let programs = (0..count)
+            .map(|id| {
+                let mut program = Program::new(instructions);
+                program.registers[to_register('p')] = id as i64;
+                program
+            })
+            .collect();
+        Scheduler {
+            programs,
+            inboxes: vec![VecDeque::new(); count],
+        }
+    }
+
+    pub fn run(&mut self) -> Finish {
+        let count = self.programs.len();
+        loop {
+            let mut progress = false;
+            for id in 0..count {
+                for _ in 0..Self::SLICE {
+                    match self.programs[id].step(&mut self.inboxes[id]) {
+                        Step::Ran => {}
+                        Step::Sent(value) => self.inboxes[(id + 1) % count].push_back(value),
+                        Step::Blocked(_) | Step::Halted => break,
+                    }
+                    progress = true;
+                }
+            }
+            if !progress {
+                let halted = self.programs.iter().all(|p| p.current().is_none());
+                return if halted {
+                    Finish::Halted
+                } else {
+                    Finish::Deadlock
+                };
+            }
+        }
+    }
+}
+
+#[test]
+fn duet_scheduler() {
+    let instructions = parse(
+        "snd 1
+        snd 2
+        snd p
+        rcv a
+        rcv b
+        rcv c
+        rcv d",
+    )
+    .unwrap();
+    let mut scheduler = Scheduler::new(&instructions, 2);
+    assert_eq!(scheduler.run(), Finish::Deadlock);
+    assert_eq!(scheduler.programs[1].sent, 3);
+    assert_eq!(scheduler.programs[0].registers[to_register('c')], 1);
+    assert_eq!(
+        scheduler.programs[1].current(),
+        Some(Instruction::Rcv(Register(3)))
+    );
+
+    let instructions = parse("snd p\nrcv a\nsnd a\nrcv a").unwrap();
+    let mut scheduler = Scheduler::new(&instructions, 3);
+    assert_eq!(scheduler.run(), Finish::Halted);
+    assert_eq!(scheduler.programs[0].registers[0], 1);
+}
+
+#[test]
+fn duet_counts() {
+    let instructions = parse("set a 3\nmul b 2\nsub a 1\njnz a -2").unwrap();
+    let mut program = Program::new(&instructions);
+    program.run();
+    assert_eq!(program.counts(), &[1, 3, 3, 3]);
+    assert_eq!(program.executed("mul"), 3);
+}
+
+#[test]
+fn duet_parse_errors() {
+    let error = |s: &str| parse(s).unwrap_err().line_column(s);
+    assert_eq!(error("set a 1\nsnd"), (2, 4));
+    assert_eq!(error("set a 1\nset 1 a"), (2, 5));
+    assert_eq!(error("snd a\n\nrcv"), (3, 4));
+    assert_eq!(error("snd a\nnop"), (2, 1));
+    assert_eq!(error("add a 1 2"), (1, 7));
+}
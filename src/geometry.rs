@@ -0,0 +1,384 @@
+//! Points and directions on square and hexagonal grids.
+//!
+//! `y` grows downwards, as it does in the puzzle inputs, so `Up` is towards
+//! smaller `y` and points order in reading order: by row, then by column.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub};
+use {Matrix, VecLike};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    /// The point at a `Matrix` row and column.
+    pub fn from_index(row: usize, col: usize) -> Point {
+        Point::new(col as isize, row as isize)
+    }
+
+    /// The `Matrix` row and column of the point, unless it is left of or
+    /// above the origin.
+    pub fn index(self) -> Option<(usize, usize)> {
+        if self.x < 0 || self.y < 0 {
+            None
+        } else {
+            Some((self.y as usize, self.x as usize))
+        }
+    }
+
+    /// The row and column of the point, if it is within `matrix`.
+    pub fn index_in<T: Copy + Default, C: VecLike<T>>(
+        self,
+        matrix: &Matrix<T, C>,
+    ) -> Option<(usize, usize)> {
+        self.index()
+            .filter(|&(row, col)| row < matrix.rows() && col < matrix.columns())
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    /// The four orthogonally adjacent points, in reading order.
+    pub fn neighbours(self) -> [Point; 4] {
+        [
+            self + Direction::Up,
+            self + Direction::Left,
+            self + Direction::Right,
+            self + Direction::Down,
+        ]
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Debug for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// The four cardinal directions.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `U`, `R`, `D` and `L`, the compass points `N`, `E`, `S` and
+    /// `W`, and the arrows `^`, `>`, `v` and `<`.
+    pub fn from_char(ch: char) -> Option<Direction> {
+        match ch {
+            'U' | 'N' | '^' => Some(Direction::Up),
+            'R' | 'E' | '>' => Some(Direction::Right),
+            'D' | 'S' | 'v' => Some(Direction::Down),
+            'L' | 'W' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    fn from_index(i: usize) -> Direction {
+        Direction::ALL[i % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::from_index(self as usize + 1)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::from_index(self as usize + 3)
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::from_index(self as usize + 2)
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// The cardinal and diagonal directions, turning by an eighth of a circle.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// Clockwise, from `N`.
+    pub const ALL: [Compass; 8] = [
+        Compass::N,
+        Compass::NE,
+        Compass::E,
+        Compass::SE,
+        Compass::S,
+        Compass::SW,
+        Compass::W,
+        Compass::NW,
+    ];
+
+    fn from_index(i: usize) -> Compass {
+        Compass::ALL[i % 8]
+    }
+
+    pub fn turn_right(self) -> Compass {
+        Compass::from_index(self as usize + 1)
+    }
+
+    pub fn turn_left(self) -> Compass {
+        Compass::from_index(self as usize + 7)
+    }
+
+    pub fn reverse(self) -> Compass {
+        Compass::from_index(self as usize + 4)
+    }
+
+    pub fn offset(self) -> Point {
+        let (x, y) = match self {
+            Compass::N => (0, -1),
+            Compass::NE => (1, -1),
+            Compass::E => (1, 0),
+            Compass::SE => (1, 1),
+            Compass::S => (0, 1),
+            Compass::SW => (-1, 1),
+            Compass::W => (-1, 0),
+            Compass::NW => (-1, -1),
+        };
+        Point::new(x, y)
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Compass {
+        Compass::from_index(direction as usize * 2)
+    }
+}
+
+/// A hexagon in a grid of flat-topped hexagons, in axial coordinates: `q`
+/// grows to the south-east and `r` to the south. See
+/// https://www.redblobgames.com/grids/hexagons/#coordinates-axial.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub fn step(self, direction: HexDirection) -> Hex {
+        let (q, r) = match direction {
+            HexDirection::N => (0, -1),
+            HexDirection::NE => (1, -1),
+            HexDirection::SE => (1, 0),
+            HexDirection::S => (0, 1),
+            HexDirection::SW => (-1, 1),
+            HexDirection::NW => (-1, 0),
+        };
+        Hex {
+            q: self.q + q,
+            r: self.r + r,
+        }
+    }
+
+    /// The fewest steps between two hexagons: the Manhattan distance between
+    /// their cube coordinates, `(q, r, -q - r)`, halved. See
+    /// https://www.redblobgames.com/grids/hexagons/#distances.
+    pub fn distance(self, other: Hex) -> usize {
+        let (dq, dr) = (self.q - other.q, self.r - other.r);
+        ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as usize
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HexDirection {
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+impl HexDirection {
+    pub fn parse(s: &str) -> Option<HexDirection> {
+        match s {
+            "n" => Some(HexDirection::N),
+            "ne" => Some(HexDirection::NE),
+            "se" => Some(HexDirection::SE),
+            "s" => Some(HexDirection::S),
+            "sw" => Some(HexDirection::SW),
+            "nw" => Some(HexDirection::NW),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn geometry_points() {
+    let p = Point::new(2, -1);
+    assert_eq!(p.step(Direction::Up), Point::new(2, -2));
+    assert_eq!(p.manhattan(Point::ORIGIN), 3);
+    assert_eq!(p.index(), None);
+    assert_eq!(Point::from_index(3, 4).index(), Some((3, 4)));
+    let matrix = Matrix::<bool, Vec<bool>>::new(2, 3);
+    assert_eq!(Point::new(2, 1).index_in(&matrix), Some((1, 2)));
+    assert_eq!(Point::new(3, 1).index_in(&matrix), None);
+
+    let mut reading = p.neighbours().to_vec();
+    reading.sort();
+    assert_eq!(reading, p.neighbours());
+    assert!(Point::new(5, 0) < Point::new(0, 1));
+
+    let a = Point3::new(1, -2, 3);
+    assert_eq!((a - a).manhattan(Point3::ORIGIN), 0);
+    assert_eq!(a.manhattan(Point3::ORIGIN), 6);
+}
+
+#[test]
+fn geometry_turns() {
+    for &d in &Direction::ALL {
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.turn_right().turn_right(), d.reverse());
+        assert_eq!(d.offset() + d.reverse().offset(), Point::ORIGIN);
+        assert_eq!(Compass::from(d).offset(), d.offset());
+        assert_eq!(
+            Compass::from(d).turn_right().turn_right(),
+            Compass::from(d.turn_right())
+        );
+    }
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Compass::NE.reverse(), Compass::SW);
+    assert_eq!(Compass::N.turn_left(), Compass::NW);
+}
+
+#[test]
+fn geometry_hex() {
+    let walk = |s: &str| {
+        s.split(',')
+            .map(|d| HexDirection::parse(d).unwrap())
+            .fold(Hex::ORIGIN, Hex::step)
+    };
+    assert_eq!(walk("ne,ne,ne").distance(Hex::ORIGIN), 3);
+    assert_eq!(walk("ne,ne,sw,sw"), Hex::ORIGIN);
+    assert_eq!(walk("ne,ne,s,s").distance(Hex::ORIGIN), 2);
+    assert_eq!(walk("se,sw,se,sw,sw").distance(Hex::ORIGIN), 3);
+}
@@ -0,0 +1,752 @@
+//! Hashlife: evolving automata through huge numbers of generations by
+//! remembering the future of every distinct block of cells.
+//!
+//! The universe is a tree, binary for a `Line` and a quadtree for a `Plane`,
+//! whose equal subtrees are stored once. A node `2^k` cells wide determines
+//! its centre half for `2^(k - 2)` generations, divided by the radius of the
+//! rule, and that result is computed once per node and stored. Patterns that
+//! repeat, or move without changing, are then advanced exponentially many
+//! generations at a time, as 2018 day 12's plants are.
+//!
+//! Cells outside the pattern are dead, and must stay dead while all of their
+//! neighbours are.
+
+use fnv::FnvHashMap;
+use std::hash::Hash;
+
+use {BitVec, Matrix};
+
+/// Interned nodes, with their level and the number of live cells in them.
+struct Store<N> {
+    nodes: Vec<N>,
+    levels: Vec<u32>,
+    population: Vec<u64>,
+    ids: FnvHashMap<N, usize>,
+    /// The node each node and `j` advances to in `2^j` generations.
+    results: FnvHashMap<(usize, u32), usize>,
+    /// The empty node of each level.
+    empty: Vec<Option<usize>>,
+}
+
+impl<N: Copy + Eq + Hash> Store<N> {
+    fn new() -> Self {
+        Store {
+            nodes: Vec::new(),
+            levels: Vec::new(),
+            population: Vec::new(),
+            ids: FnvHashMap::default(),
+            results: FnvHashMap::default(),
+            empty: Vec::new(),
+        }
+    }
+
+    fn intern(&mut self, node: N, level: u32, population: u64) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(node);
+        self.levels.push(level);
+        self.population.push(population);
+        self.ids.insert(node, id);
+        id
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Pair {
+    /// The cells of a leaf, the first in the lowest bit.
+    Leaf(u64),
+    Branch(usize, usize),
+}
+
+/// A one-dimensional automaton, whose rule maps each window of `2 * radius +
+/// 1` cells, read as a binary number with the leftmost cell highest, to the
+/// next state of the cell at its centre.
+pub struct Line {
+    store: Store<Pair>,
+    rule: Vec<bool>,
+    radius: usize,
+    /// `2^shift` is the radius rounded up to a power of two.
+    shift: u32,
+    root: usize,
+    /// The position of the first cell of `root`.
+    origin: i64,
+    generation: u64,
+}
+
+impl Line {
+    /// A line whose cells from `origin` on are `cells`.
+    pub fn new(cells: &BitVec, origin: i64, radius: usize, rule: &[bool]) -> Line {
+        assert!((1..=4).contains(&radius), "unsupported radius {}", radius);
+        assert_eq!(rule.len(), 1 << (2 * radius + 1));
+        assert!(!rule[0], "dead cells must stay dead");
+        let mut line = Line {
+            store: Store::new(),
+            rule: rule.to_vec(),
+            radius,
+            shift: (radius as u32).next_power_of_two().trailing_zeros(),
+            root: 0,
+            origin,
+            generation: 0,
+        };
+        let width = line.width();
+        let mut nodes = cells
+            .iter()
+            .collect::<Vec<_>>()
+            .chunks(width)
+            .map(|chunk| {
+                let bits = chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |bits, (i, &c)| bits | (c as u64) << i);
+                line.leaf(bits)
+            })
+            .collect::<Vec<_>>();
+        while nodes.len() < 2 || !nodes.len().is_power_of_two() {
+            let empty = line.empty(line.leaf_level());
+            nodes.push(empty);
+        }
+        while nodes.len() > 1 {
+            nodes = nodes.chunks(2).map(|p| line.join(p[0], p[1])).collect();
+        }
+        line.root = nodes[0];
+        line
+    }
+
+    /// The level of leaves, wide enough that a node of two of them can be
+    /// advanced by a generation.
+    fn leaf_level(&self) -> u32 {
+        2 + self.shift
+    }
+
+    fn width(&self) -> usize {
+        1 << self.leaf_level()
+    }
+
+    fn level(&self, id: usize) -> u32 {
+        self.store.levels[id]
+    }
+
+    fn leaf(&mut self, bits: u64) -> usize {
+        let level = self.leaf_level();
+        self.store
+            .intern(Pair::Leaf(bits), level, u64::from(bits.count_ones()))
+    }
+
+    fn join(&mut self, left: usize, right: usize) -> usize {
+        let level = self.level(left) + 1;
+        let population = self.store.population[left] + self.store.population[right];
+        self.store
+            .intern(Pair::Branch(left, right), level, population)
+    }
+
+    fn children(&self, id: usize) -> (usize, usize) {
+        match self.store.nodes[id] {
+            Pair::Branch(left, right) => (left, right),
+            Pair::Leaf(_) => unreachable!("leaves have no children"),
+        }
+    }
+
+    fn bits(&self, id: usize) -> u64 {
+        match self.store.nodes[id] {
+            Pair::Leaf(bits) => bits,
+            Pair::Branch(..) => unreachable!("not a leaf"),
+        }
+    }
+
+    fn empty(&mut self, level: u32) -> usize {
+        let index = level as usize;
+        if self.store.empty.len() <= index {
+            self.store.empty.resize(index + 1, None);
+        }
+        if let Some(id) = self.store.empty[index] {
+            return id;
+        }
+        let id = if level == self.leaf_level() {
+            self.leaf(0)
+        } else {
+            let child = self.empty(level - 1);
+            self.join(child, child)
+        };
+        self.store.empty[index] = Some(id);
+        id
+    }
+
+    /// The cells of a node of two leaves.
+    fn pair_bits(&self, id: usize) -> u64 {
+        let (left, right) = self.children(id);
+        self.bits(left) | self.bits(right) << self.width()
+    }
+
+    /// The middle half of a node, a level down.
+    fn centre(&mut self, id: usize) -> usize {
+        if self.level(id) == self.leaf_level() + 1 {
+            let width = self.width();
+            let bits = self.pair_bits(id) >> (width / 2) & ((1 << width) - 1);
+            return self.leaf(bits);
+        }
+        let (left, right) = self.children(id);
+        let (_, a) = self.children(left);
+        let (b, _) = self.children(right);
+        self.join(a, b)
+    }
+
+    /// Runs a node of two leaves directly.
+    fn simulate(&mut self, id: usize, generations: u64) -> usize {
+        let width = 2 * self.width() as isize;
+        let radius = self.radius as isize;
+        let mut bits = self.pair_bits(id);
+        for _ in 0..generations {
+            let mut next = 0;
+            for cell in 0..width {
+                let window = (cell - radius..=cell + radius).fold(0, |window, c| {
+                    let alive = c >= 0 && c < width && bits & 1 << c != 0;
+                    window << 1 | alive as usize
+                });
+                if self.rule[window] {
+                    next |= 1 << cell;
+                }
+            }
+            bits = next;
+        }
+        let width = self.width();
+        self.leaf(bits >> (width / 2) & ((1 << width) - 1))
+    }
+
+    /// The centre half of a node, `2^j` generations on; `j` is at most the
+    /// level less two and the shift.
+    fn advance(&mut self, id: usize, j: u32) -> usize {
+        if let Some(&result) = self.store.results.get(&(id, j)) {
+            return result;
+        }
+        let level = self.level(id);
+        let result = if level == self.leaf_level() + 1 {
+            self.simulate(id, 1 << j)
+        } else {
+            // Advance three overlapping halves, then the two overlapping
+            // halves of their results; or take the latter's centres if the
+            // first step was all there was to do.
+            let full = j == level - 2 - self.shift;
+            let step = if full { j - 1 } else { j };
+            let (left, right) = self.children(id);
+            let (_, a) = self.children(left);
+            let (b, _) = self.children(right);
+            let middle = self.join(a, b);
+            let left = self.advance(left, step);
+            let middle = self.advance(middle, step);
+            let right = self.advance(right, step);
+            let left = self.join(left, middle);
+            let right = self.join(middle, right);
+            let (left, right) = if full {
+                (self.advance(left, step), self.advance(right, step))
+            } else {
+                (self.centre(left), self.centre(right))
+            };
+            self.join(left, right)
+        };
+        self.store.results.insert((id, j), result);
+        result
+    }
+
+    /// The first and last live cells under a node whose first cell is at
+    /// `offset`.
+    fn span(&self, id: usize, offset: i64) -> Option<(i64, i64)> {
+        if self.store.population[id] == 0 {
+            return None;
+        }
+        match self.store.nodes[id] {
+            Pair::Leaf(bits) => Some((
+                offset + i64::from(bits.trailing_zeros()),
+                offset + 63 - i64::from(bits.leading_zeros()),
+            )),
+            Pair::Branch(left, right) => {
+                let half = 1 << (self.level(id) - 1);
+                let left = self.span(left, offset);
+                let right = self.span(right, offset + half);
+                match (left, right) {
+                    (Some((first, _)), Some((_, last))) => Some((first, last)),
+                    _ => left.or(right),
+                }
+            }
+        }
+    }
+
+    /// Doubles the universe around the pattern.
+    fn pad(&mut self) {
+        let level = self.level(self.root);
+        let empty = self.empty(level - 1);
+        let (left, right) = self.children(self.root);
+        let left = self.join(empty, left);
+        let right = self.join(right, empty);
+        self.root = self.join(left, right);
+        self.origin -= 1 << (level - 1);
+    }
+
+    pub fn run(&mut self, generations: u64) {
+        for j in (0..64).filter(|j| generations & 1 << j != 0) {
+            // Cells spread at most `radius` cells a generation, and must not
+            // spread out of the centre half that advancing leaves, which
+            // must not be a leaf either.
+            loop {
+                let level = self.level(self.root);
+                let quarter = 1 << (level - 2);
+                let margin = (self.radius as i64) << j;
+                let fits = self.span(self.root, 0).map_or(true, |(first, last)| {
+                    first >= quarter + margin && last < 3 * quarter - margin
+                });
+                if level >= (j + 2 + self.shift).max(self.leaf_level() + 2) && fits {
+                    break;
+                }
+                self.pad();
+            }
+            let level = self.level(self.root);
+            self.root = self.advance(self.root, j);
+            self.origin += 1 << (level - 2);
+            self.generation += 1 << j;
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn population(&self) -> u64 {
+        self.store.population[self.root]
+    }
+
+    /// The positions of the live cells, in order.
+    pub fn live(&self) -> Vec<i64> {
+        let mut live = Vec::new();
+        let mut stack = vec![(self.root, self.origin)];
+        while let Some((id, offset)) = stack.pop() {
+            if self.store.population[id] == 0 {
+                continue;
+            }
+            match self.store.nodes[id] {
+                Pair::Leaf(bits) => {
+                    live.extend((0..64).filter(|i| bits & 1 << i != 0).map(|i| offset + i))
+                }
+                Pair::Branch(left, right) => {
+                    let half = 1 << (self.level(id) - 1);
+                    stack.push((right, offset + half));
+                    stack.push((left, offset));
+                }
+            }
+        }
+        live
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Quad {
+    /// 4 by 4 cells, row by row from the top left in the lowest bit.
+    Leaf(u64),
+    /// The north-west, north-east, south-west and south-east quarters.
+    Branch([usize; 4]),
+}
+
+const QUAD_LEAF: u32 = 2;
+
+/// A two-dimensional automaton of live and dead cells with a rule like
+/// Conway's life: a dead cell with a number of live neighbours in `birth`
+/// comes alive, and a live one with a number in `survive` stays alive.
+pub struct Plane {
+    store: Store<Quad>,
+    birth: u16,
+    survive: u16,
+    root: usize,
+    /// The position of the top left cell of `root`.
+    origin: (i64, i64),
+    generation: u64,
+}
+
+impl Plane {
+    /// A plane whose cells from `origin` on are those of `matrix`.
+    pub fn new(
+        matrix: &Matrix<bool, BitVec>,
+        origin: (i64, i64),
+        birth: &[usize],
+        survive: &[usize],
+    ) -> Plane {
+        assert!(!birth.contains(&0), "dead cells must stay dead");
+        let mask = |counts: &[usize]| counts.iter().fold(0, |mask, &n| mask | 1 << n);
+        let mut plane = Plane {
+            store: Store::new(),
+            birth: mask(birth),
+            survive: mask(survive),
+            root: 0,
+            origin,
+            generation: 0,
+        };
+        let size = matrix.rows().max(matrix.columns()).max(8);
+        let level = size.next_power_of_two().trailing_zeros();
+        plane.root = plane.build(matrix, 0, 0, level);
+        plane
+    }
+
+    fn build(&mut self, matrix: &Matrix<bool, BitVec>, x: usize, y: usize, level: u32) -> usize {
+        if level == QUAD_LEAF {
+            let mut bits = 0;
+            for i in 0..16 {
+                if matrix.get(y + i / 4, x + i % 4).unwrap_or(false) {
+                    bits |= 1 << i;
+                }
+            }
+            return self.leaf(bits);
+        }
+        let half = 1 << (level - 1);
+        let nw = self.build(matrix, x, y, level - 1);
+        let ne = self.build(matrix, x + half, y, level - 1);
+        let sw = self.build(matrix, x, y + half, level - 1);
+        let se = self.build(matrix, x + half, y + half, level - 1);
+        self.join([nw, ne, sw, se])
+    }
+
+    fn level(&self, id: usize) -> u32 {
+        self.store.levels[id]
+    }
+
+    fn leaf(&mut self, bits: u64) -> usize {
+        self.store
+            .intern(Quad::Leaf(bits), QUAD_LEAF, u64::from(bits.count_ones()))
+    }
+
+    fn join(&mut self, quarters: [usize; 4]) -> usize {
+        let level = self.level(quarters[0]) + 1;
+        let population = quarters.iter().map(|&q| self.store.population[q]).sum();
+        self.store.intern(Quad::Branch(quarters), level, population)
+    }
+
+    fn children(&self, id: usize) -> [usize; 4] {
+        match self.store.nodes[id] {
+            Quad::Branch(quarters) => quarters,
+            Quad::Leaf(_) => unreachable!("leaves have no children"),
+        }
+    }
+
+    fn empty(&mut self, level: u32) -> usize {
+        let index = level as usize;
+        if self.store.empty.len() <= index {
+            self.store.empty.resize(index + 1, None);
+        }
+        if let Some(id) = self.store.empty[index] {
+            return id;
+        }
+        let id = if level == QUAD_LEAF {
+            self.leaf(0)
+        } else {
+            let child = self.empty(level - 1);
+            self.join([child; 4])
+        };
+        self.store.empty[index] = Some(id);
+        id
+    }
+
+    /// The 8 by 8 cells of a node of four leaves, row by row.
+    fn quad_bits(&self, id: usize) -> u64 {
+        let mut bits = 0;
+        for (q, &leaf) in self.children(id).iter().enumerate() {
+            let leaf = match self.store.nodes[leaf] {
+                Quad::Leaf(bits) => bits,
+                Quad::Branch(_) => unreachable!("not a leaf"),
+            };
+            let (dx, dy) = (q % 2 * 4, q / 2 * 4);
+            for i in (0..16).filter(|i| leaf & 1 << i != 0) {
+                bits |= 1 << ((dy + i / 4) * 8 + dx + i % 4);
+            }
+        }
+        bits
+    }
+
+    /// The middle 4 by 4 of 8 by 8 cells, as a leaf.
+    fn middle(&mut self, bits: u64) -> usize {
+        let mut leaf = 0;
+        for i in 0..16 {
+            if bits & 1 << ((2 + i / 4) * 8 + 2 + i % 4) != 0 {
+                leaf |= 1 << i;
+            }
+        }
+        self.leaf(leaf)
+    }
+
+    fn centre(&mut self, id: usize) -> usize {
+        if self.level(id) == QUAD_LEAF + 1 {
+            let bits = self.quad_bits(id);
+            return self.middle(bits);
+        }
+        let [nw, ne, sw, se] = self.children(id);
+        self.join([
+            self.children(nw)[3],
+            self.children(ne)[2],
+            self.children(sw)[1],
+            self.children(se)[0],
+        ])
+    }
+
+    fn simulate(&mut self, id: usize, generations: u64) -> usize {
+        let mut bits = self.quad_bits(id);
+        for _ in 0..generations {
+            let mut next = 0;
+            for cell in 0..64 {
+                let (x, y) = (cell % 8, cell / 8);
+                let mut neighbours = 0;
+                for ny in y.max(1) - 1..=(y + 1).min(7) {
+                    for nx in x.max(1) - 1..=(x + 1).min(7) {
+                        if (nx, ny) != (x, y) && bits & 1 << (ny * 8 + nx) != 0 {
+                            neighbours += 1;
+                        }
+                    }
+                }
+                let rule = if bits & 1 << cell != 0 {
+                    self.survive
+                } else {
+                    self.birth
+                };
+                if rule & 1 << neighbours != 0 {
+                    next |= 1 << cell;
+                }
+            }
+            bits = next;
+        }
+        self.middle(bits)
+    }
+
+    /// As `Line::advance`, with nine overlapping quarters.
+    fn advance(&mut self, id: usize, j: u32) -> usize {
+        if let Some(&result) = self.store.results.get(&(id, j)) {
+            return result;
+        }
+        let level = self.level(id);
+        let result = if level == QUAD_LEAF + 1 {
+            self.simulate(id, 1 << j)
+        } else {
+            let full = j == level - 2;
+            let step = if full { j - 1 } else { j };
+            let [nw, ne, sw, se] = self.children(id);
+            let [nw_, ne_, sw_, se_] = [nw, ne, sw, se].map(|q| self.children(q));
+            let quarters = [
+                nw,
+                self.join([nw_[1], ne_[0], nw_[3], ne_[2]]),
+                ne,
+                self.join([nw_[2], nw_[3], sw_[0], sw_[1]]),
+                self.join([nw_[3], ne_[2], sw_[1], se_[0]]),
+                self.join([ne_[2], ne_[3], se_[0], se_[1]]),
+                sw,
+                self.join([sw_[1], se_[0], sw_[3], se_[2]]),
+                se,
+            ];
+            let mut r = [0; 9];
+            for (r, &q) in r.iter_mut().zip(&quarters) {
+                *r = self.advance(q, step);
+            }
+            let mut result = [0; 4];
+            for (i, result) in result.iter_mut().enumerate() {
+                let at = i / 2 * 3 + i % 2;
+                let node = self.join([r[at], r[at + 1], r[at + 3], r[at + 4]]);
+                *result = if full {
+                    self.advance(node, step)
+                } else {
+                    self.centre(node)
+                };
+            }
+            self.join(result)
+        };
+        self.store.results.insert((id, j), result);
+        result
+    }
+
+    /// The live cells under a node whose top left is at `offset`, passed to
+    /// `f`.
+    fn each_live<F: FnMut(i64, i64)>(&self, id: usize, offset: (i64, i64), f: &mut F) {
+        if self.store.population[id] == 0 {
+            return;
+        }
+        match self.store.nodes[id] {
+            Quad::Leaf(bits) => {
+                for i in (0..16).filter(|i| bits & 1 << i != 0) {
+                    f(offset.0 + i % 4, offset.1 + i / 4);
+                }
+            }
+            Quad::Branch(quarters) => {
+                let half = 1 << (self.level(id) - 1);
+                for (q, &quarter) in quarters.iter().enumerate() {
+                    let (dx, dy) = (q as i64 % 2 * half, q as i64 / 2 * half);
+                    self.each_live(quarter, (offset.0 + dx, offset.1 + dy), f);
+                }
+            }
+        }
+    }
+
+    fn pad(&mut self) {
+        let level = self.level(self.root);
+        let e = self.empty(level - 1);
+        let [nw, ne, sw, se] = self.children(self.root);
+        let quarters = [
+            self.join([e, e, e, nw]),
+            self.join([e, e, ne, e]),
+            self.join([e, sw, e, e]),
+            self.join([se, e, e, e]),
+        ];
+        self.root = self.join(quarters);
+        self.origin.0 -= 1 << (level - 1);
+        self.origin.1 -= 1 << (level - 1);
+    }
+
+    pub fn run(&mut self, generations: u64) {
+        for j in (0..64).filter(|j| generations & 1 << j != 0) {
+            loop {
+                let level = self.level(self.root);
+                let quarter = 1 << (level - 2);
+                let (low, high) = (quarter + (1 << j), 3 * quarter - (1 << j));
+                let mut fits = true;
+                self.each_live(self.root, (0, 0), &mut |x, y| {
+                    fits &= x >= low && x < high && y >= low && y < high;
+                });
+                if level >= (j + 2).max(QUAD_LEAF + 2) && fits {
+                    break;
+                }
+                self.pad();
+            }
+            let level = self.level(self.root);
+            self.root = self.advance(self.root, j);
+            self.origin.0 += 1 << (level - 2);
+            self.origin.1 += 1 << (level - 2);
+            self.generation += 1 << j;
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn population(&self) -> u64 {
+        self.store.population[self.root]
+    }
+
+    /// The positions of the live cells.
+    pub fn live(&self) -> Vec<(i64, i64)> {
+        let mut live = Vec::new();
+        self.each_live(self.root, self.origin, &mut |x, y| live.push((x, y)));
+        live.sort_by_key(|&(x, y)| (y, x));
+        live
+    }
+}
+
+#[cfg(test)]
+fn naive_line(cells: &[i64], radius: i64, rule: &[bool], generations: u64) -> Vec<i64> {
+    let mut cells = cells.to_vec();
+    for _ in 0..generations {
+        if cells.is_empty() {
+            break;
+        }
+        let (first, last) = (cells[0] - radius, cells[cells.len() - 1] + radius);
+        cells = (first..=last)
+            .filter(|&c| {
+                let window =
+                    (c - radius..=c + radius).fold(0, |w, n| w << 1 | cells.contains(&n) as usize);
+                rule[window]
+            })
+            .collect();
+    }
+    cells
+}
+
+#[test]
+fn hashlife_line() {
+    // xorshift, to avoid a dependency on rand.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut random = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for &radius in &[1, 2, 3] {
+        for _ in 0..5 {
+            let mut rule = (0..1 << (2 * radius + 1))
+                .map(|_| random() % 3 == 0)
+                .collect::<Vec<_>>();
+            rule[0] = false;
+            let cells = (0..20).map(|_| random() % 2 == 0).collect::<BitVec>();
+            let live = cells
+                .iter()
+                .enumerate()
+                .filter(|&(_, c)| c)
+                .map(|(i, _)| i as i64 - 7)
+                .collect::<Vec<_>>();
+            if live.is_empty() {
+                continue;
+            }
+            let mut line = Line::new(&cells, -7, radius, &rule);
+            let mut total = 0;
+            for &n in &[1, 2, 3, 5, 13] {
+                line.run(n);
+                total += n;
+                let expected = naive_line(&live, radius as i64, &rule, total);
+                assert_eq!(line.live(), expected, "radius {} after {}", radius, total);
+            }
+        }
+    }
+}
+
+#[test]
+fn hashlife_line_moves() {
+    // Every live cell moves one to the right.
+    let rule = (0..32).map(|w| w & 0b01000 != 0).collect::<Vec<_>>();
+    let cells = vec![true, false, true, true]
+        .into_iter()
+        .collect::<BitVec>();
+    let mut line = Line::new(&cells, 0, 2, &rule);
+    line.run(50_000_000_000);
+    let start = 50_000_000_000;
+    assert_eq!(line.live(), vec![start, start + 2, start + 3]);
+    assert_eq!(line.population(), 3);
+}
+
+#[test]
+fn hashlife_plane() {
+    use automaton::{Automaton, Neighbourhood};
+    use Grid;
+
+    let glider = Matrix::interpret_bool(".#./..#/###");
+    let mut plane = Plane::new(&glider, (0, 0), &[3], &[2, 3]);
+    plane.run(4_000_000);
+    let d = 1_000_000;
+    assert_eq!(
+        plane.live(),
+        vec![
+            (d + 1, d),
+            (d + 2, d + 1),
+            (d, d + 2),
+            (d + 1, d + 2),
+            (d + 2, d + 2)
+        ]
+    );
+    assert_eq!(plane.generation(), 4_000_000);
+
+    // The R-pentomino, against the automaton.
+    let pentomino = Matrix::interpret_bool(".##/##./.#.");
+    let mut plane = Plane::new(&pentomino, (0, 0), &[3], &[2, 3]);
+    let grid: Grid<bool, BitVec> = Grid::from_matrix(pentomino, (0, 0));
+    let mut automaton = Automaton::unbounded(grid, Neighbourhood::Moore, |c, n: &[bool]| {
+        let n = n.iter().filter(|&&b| b).count();
+        n == 3 || (c && n == 2)
+    });
+    for &n in &[1, 6, 20, 100] {
+        plane.run(n);
+        for _ in 0..n {
+            automaton.step();
+        }
+        let (left, top, right, bottom) = automaton.grid().bounds();
+        let mut expected = Vec::new();
+        for y in top..bottom {
+            for x in left..right {
+                if automaton.grid().get(x, y) {
+                    expected.push((x as i64, y as i64));
+                }
+            }
+        }
+        assert_eq!(plane.live(), expected);
+    }
+}
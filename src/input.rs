@@ -0,0 +1,180 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use answers;
+
+#[derive(Fail, Debug)]
+pub enum InputError {
+    #[fail(display = "failed to read {}: {}", _0, _1)]
+    Io(String, #[cause] io::Error),
+    #[fail(display = "line {}: {}", _0, _1)]
+    Parse(usize, String),
+    #[fail(display = "INPUT names a single input, but {} days are selected", _0)]
+    SharedInput(usize),
+}
+
+/// Conversion from puzzle text into the type of a day's `INPUT`.
+///
+/// Input loaded at runtime lives for the rest of the process, so
+/// implementations may borrow from it.
+pub trait FromInput: Sized {
+    fn from_input(s: &'static str) -> Result<Self, InputError>;
+}
+
+impl FromInput for &'static str {
+    fn from_input(s: &'static str) -> Result<Self, InputError> {
+        Ok(s)
+    }
+}
+
+macro_rules! from_input_parse {
+    ($($t:ty),+) => {
+        $(
+            impl FromInput for $t {
+                fn from_input(s: &'static str) -> Result<Self, InputError> {
+                    s.trim()
+                        .parse()
+                        .map_err(|e| InputError::Parse(1, format!("{}", e)))
+                }
+            }
+        )+
+    };
+}
+
+from_input_parse!(u32, u64, usize);
+
+/// Multi-line input is parsed one element per line; a single line is split
+/// on whitespace and commas.
+impl<T> FromInput for &'static [T]
+where
+    T: FromStr + 'static,
+    T::Err: ::std::fmt::Display,
+{
+    fn from_input(s: &'static str) -> Result<Self, InputError> {
+        let s = s.trim();
+        let items: Vec<&str> = if s.contains('\n') {
+            s.lines().map(str::trim).collect()
+        } else {
+            s.split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|item| !item.is_empty())
+                .collect()
+        };
+        let mut out = Vec::with_capacity(items.len());
+        for (i, item) in items.into_iter().enumerate() {
+            match item.parse() {
+                Ok(v) => out.push(v),
+                Err(e) => return Err(InputError::Parse(i + 1, format!("{}: {:?}", e, item))),
+            }
+        }
+        Ok(Box::leak(out.into_boxed_slice()))
+    }
+}
+
+/// Runtime overrides for the inputs embedded in the binaries.
+///
+/// `INPUT` names a file (or `-` for stdin) used for the one day that is run;
+/// otherwise `INPUT_DIR/<day>.input` is used if present, and the embedded
+/// input if not.
+pub struct Inputs {
+    path: Option<String>,
+    dir: Option<PathBuf>,
+    stdin: Option<&'static str>,
+    loaded: Option<u64>,
+}
+
+impl Inputs {
+    /// Reads the overrides for a run of `days` days, failing if `INPUT` is
+    /// set and there is more than one.
+    pub fn from_env(days: usize) -> Result<Inputs, InputError> {
+        let path = env::var("INPUT").ok().filter(|p| !p.is_empty());
+        if path.is_some() && days > 1 {
+            return Err(InputError::SharedInput(days));
+        }
+        Ok(Inputs {
+            path,
+            dir: env::var_os("INPUT_DIR").map(PathBuf::from),
+            stdin: None,
+            loaded: None,
+        })
+    }
+
+    pub fn load<T: FromInput>(&mut self, day: &str, embedded: T) -> Result<T, InputError> {
+        match self.text(day)? {
+            Some(text) => {
+                self.loaded = Some(answers::fingerprint(text));
+                T::from_input(text)
+            }
+            None => {
+                self.loaded = None;
+                Ok(embedded)
+            }
+        }
+    }
+
+    /// The fingerprint of the input last loaded from outside the binary, or
+    /// `None` if it was embedded or nothing was loaded since the last call.
+    pub fn take_fingerprint(&mut self) -> Option<u64> {
+        self.loaded.take()
+    }
+
+    fn text(&mut self, day: &str) -> Result<Option<&'static str>, InputError> {
+        if self.path.as_ref().map(|p| p == "-").unwrap_or(false) {
+            if self.stdin.is_none() {
+                let mut buf = String::new();
+                io::stdin()
+                    .read_to_string(&mut buf)
+                    .map_err(|e| InputError::Io(String::from("stdin"), e))?;
+                self.stdin = Some(leak(buf));
+            }
+            return Ok(self.stdin);
+        }
+        let path = match (&self.path, &self.dir) {
+            (Some(path), _) => PathBuf::from(path),
+            (None, Some(dir)) => {
+                let path = dir.join(format!("{}.input", day));
+                if !path.exists() {
+                    return Ok(None);
+                }
+                path
+            }
+            (None, None) => return Ok(None),
+        };
+        match fs::read_to_string(&path) {
+            Ok(s) => Ok(Some(leak(s))),
+            Err(e) => Err(InputError::Io(path.display().to_string(), e)),
+        }
+    }
+}
+
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+#[test]
+fn input_number() {
+    assert_eq!(u32::from_input("1362\n").unwrap(), 1362);
+}
+
+#[test]
+fn input_slice_single_line() {
+    let v: &[u32] = FromInput::from_input("4\t1 15,12\n").unwrap();
+    assert_eq!(v, &[4, 1, 15, 12]);
+}
+
+#[test]
+fn input_slice_lines() {
+    let v: &[isize] = FromInput::from_input("\n0\n3\n-1\n").unwrap();
+    assert_eq!(v, &[0, 3, -1]);
+}
+
+#[test]
+fn input_slice_error_line() {
+    let v: Result<&[isize], _> = FromInput::from_input("0\nx\n");
+    match v {
+        Err(InputError::Parse(2, _)) => {}
+        other => panic!("unexpected: {:?}", other),
+    }
+}
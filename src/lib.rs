@@ -1,13 +1,18 @@
 #![feature(test)]
 
+extern crate aoc_macro;
 #[macro_use]
 extern crate failure;
+extern crate fnv;
 extern crate itoa;
 extern crate md5;
 extern crate memchr;
 extern crate smallvec;
 extern crate test;
 
+// `#[derive(Pattern)]` refers to this crate by name, as it does elsewhere.
+extern crate self as advent_of_code;
+
 use std::ptr;
 
 mod md5iter;
@@ -17,10 +22,25 @@ pub use md5iter::HashIter;
 mod bitvec;
 mod matrix;
 pub mod modulo_solver;
+mod parser;
+pub use aoc_macro::Pattern;
 pub use bitvec::BitVec;
 pub use matrix::{Grid, Matrix};
+pub use parser::{parse_lines, ErrorKind, Parser, ParserError, Span};
 
+pub mod answers;
+pub mod assembunny;
+pub mod automaton;
+pub mod compression;
 pub mod cycle;
+pub mod duet;
+pub mod geometry;
+pub mod hashlife;
+pub mod input;
+pub mod maze;
+pub mod permutation;
+pub mod runner;
+pub mod search;
 
 pub trait VecLike<T: Default + Copy + Clone>: Clone + std::fmt::Debug {
     fn new() -> Self;
@@ -142,173 +162,6 @@ pub unsafe fn swap<T>(slice: &mut [T], a: usize, b: usize) {
     ptr::swap_nonoverlapping(a, b, 1)
 }
 
-use memchr::memchr;
-use memchr::memchr2;
-
-#[derive(Fail, Debug, PartialEq, Eq)]
-pub enum ParserError {
-    #[fail(display = "Unexpected end of input")]
-    Eof,
-    #[fail(display = "Expected char {}, got {:?}", _0, _1)]
-    Unexpected(String, Option<char>),
-}
-
-#[derive(Copy, Clone)]
-pub struct Parser<'a> {
-    idx: usize,
-    input: &'a [u8],
-}
-
-impl<'a> Parser<'a> {
-    pub fn new<'b>(input: &'b [u8]) -> Parser<'b> {
-        Parser {
-            idx: 0,
-            input: input,
-        }
-    }
-
-    #[inline(always)]
-    pub fn at_end(&self) -> bool {
-        self.idx == self.input.len()
-    }
-
-    #[inline(always)]
-    pub fn cur(&self) -> Option<u8> {
-        self.input.get(self.idx).map(|x| *x)
-    }
-
-    #[inline(always)]
-    pub fn read(&mut self) -> Option<u8> {
-        let out = self.cur();
-        if out.is_some() {
-            self.idx += 1;
-        }
-        out
-    }
-
-    #[inline(always)]
-    pub fn advance(&mut self) {
-        if self.at_end() {
-            panic!("advanced past input length");
-        }
-        self.idx += 1;
-    }
-
-    /// If needle isn't present, consumes until EOF.
-    pub fn consume_until(&mut self, c: u8) -> usize {
-        if let Some(idx) = memchr(c, &self.input[self.idx..]) {
-            self.idx += idx;
-            idx
-        } else {
-            let ret = self.input.len() - self.idx;
-            self.idx = self.input.len();
-            ret
-        }
-    }
-
-    pub fn consume_until_or_stop(&mut self, c: u8) -> Option<usize> {
-        if let Some(idx) = memchr(c, &self.input[self.idx..]) {
-            self.idx += idx;
-            Some(idx)
-        } else {
-            None
-        }
-    }
-
-    pub fn consume_until2_or_stop(&mut self, a: u8, b: u8) -> Option<usize> {
-        if let Some(idx) = memchr2(a, b, &self.input[self.idx..]) {
-            self.idx += idx;
-            Some(idx)
-        } else {
-            None
-        }
-    }
-
-    pub fn consume_bytes_until(&mut self, c: u8) -> &'a [u8] {
-        let orig_idx = self.idx;
-        let _ = self.consume_until(c);
-        &self.input[orig_idx..self.idx]
-    }
-
-    pub fn expect(&mut self, needle: &[u8]) -> Result<bool, ParserError> {
-        match self.consume_bytes(needle.len()).map(|b| b == needle) {
-            Ok(eq) => {
-                if !eq {
-                    self.idx -= needle.len();
-                }
-                Ok(eq)
-            }
-            Err(e) => Err(e),
-        }
-    }
-
-    pub fn consume_bytes(&mut self, n: usize) -> Result<&[u8], ParserError> {
-        if self.idx + n > self.input.len() {
-            return Err(ParserError::Eof);
-        }
-
-        let out = &self.input[self.idx..self.idx + n];
-        self.idx += n;
-        Ok(out)
-    }
-
-    pub fn consume(&mut self, x: u8) -> Result<(), ParserError> {
-        let next = self.cur();
-        if next == Some(x) {
-            self.idx += 1;
-            Ok(())
-        } else {
-            return Err(ParserError::Unexpected(
-                format!("char {}", x as char),
-                next.map(|c| c as char),
-            ));
-        }
-    }
-
-    pub fn consume_signed_number(&mut self) -> Result<i64, ParserError> {
-        let sign = if self.cur() == Some(b'-') {
-            self.idx += 1;
-            -1
-        } else {
-            1
-        };
-        Ok(self.consume_number()? as i64 * sign)
-    }
-
-    pub fn consume_number(&mut self) -> Result<u64, ParserError> {
-        let mut i = 0;
-        let mut out = 0;
-        while let Some(next) = self.cur().and_then(|c| (c as char).to_digit(10)) {
-            self.idx += 1;
-            out *= 10;
-            out += next as u64;
-            i += 1;
-        }
-        if i == 0 {
-            return Err(ParserError::Unexpected(
-                format!("number"),
-                self.cur().map(|c| c as char),
-            ));
-        }
-        Ok(out)
-    }
-}
-
-#[test]
-fn parser_number() {
-    assert_eq!(Parser::new(b"100").consume_number(), Ok(100));
-}
-
-#[test]
-fn parser_consume_until() {
-    assert_eq!(Parser::new(b"aaax").consume_until(b'x'), 3);
-}
-
-#[test]
-fn parser_consume_until_no_exist() {
-    assert_eq!(Parser::new(b"aaa").consume_until(b'x'), 3);
-}
-
 #[inline]
 pub fn hi_nib(b: u8) -> u8 {
     (b >> 4) & 0x0f
@@ -321,36 +174,92 @@ pub fn lo_nib(b: u8) -> u8 {
 
 #[macro_export]
 macro_rules! gen_single {
-    () => {
+    ($year:expr, $day:expr) => {
         #[allow(unused)]
         fn main() {
-            eprintln!("part1 = {}", part1(INPUT));
-            eprintln!("part2 = {}", part2(INPUT));
+            let answers = $crate::answers::Answers::embedded();
+            let res = part1(INPUT).to_string();
+            let verdict = answers.check($year, $day, 1, None, &res);
+            eprintln!("part1 = {} [{}]", res, verdict);
+            let res = part2(INPUT).to_string();
+            let verdict = answers.check($year, $day, 2, None, &res);
+            eprintln!("part2 = {} [{}]", res, verdict);
+        }
+
+        #[test]
+        fn answers() {
+            let answers = $crate::answers::Answers::embedded();
+            answers.assert_embedded($year, $day, 1, || part1(INPUT).to_string());
+            answers.assert_embedded($year, $day, 2, || part2(INPUT).to_string());
         }
     };
 }
 
+/// Generates a `main` running each day's `part1` and `part2`.
+///
+/// The input embedded as `$day::INPUT` can be replaced at runtime through the
+/// `INPUT` and `INPUT_DIR` environment variables; see `input::Inputs`. `INPUT`
+/// is refused unless the filter selects a single day. Each answer is checked
+/// against `answers.txt`, and a test per day checks those for the embedded
+/// inputs.
 #[macro_export]
 macro_rules! gen {
-    ($($day:ident),+$(,)*) => {
+    ($year:expr; $($day:ident),+$(,)*) => {
         $(
             mod $day;
         )+
 
+        fn load_input<T: $crate::input::FromInput>(
+            inputs: &mut $crate::input::Inputs,
+            day: &str,
+            embedded: T,
+        ) -> T {
+            match inputs.load(day, embedded) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{}: {}", day, e);
+                    ::std::process::exit(1);
+                }
+            }
+        }
+
         fn micros(v: ::std::time::Duration) -> u64 {
             let nanos = v.as_secs() * 1_000_000_000 + (v.subsec_nanos() as u64);
             nanos / 1_000
         }
 
         fn main() {
+            let answers = $crate::answers::Answers::embedded();
             let time: bool = std::env::var("TIME").ok().map(|t| t == "1").unwrap_or(false);
+            let bench: String = std::env::var("BENCH").ok().unwrap_or_else(|| String::from("0"));
+            let filter: String = std::env::args().nth(1).unwrap_or_default();
+            let iters: usize = std::env::var("BENCH_TIMES").ok()
+                .and_then(|x| x.parse().ok())
+                .unwrap_or(1000);
+            let should_bench = bench != "0";
+            let days = [$(stringify!($day)),+];
+            let selected = if time {
+                days.len()
+            } else if should_bench {
+                1
+            } else {
+                days.iter().filter(|day| day.contains(&filter)).count()
+            };
+            let mut inputs = match $crate::input::Inputs::from_env(selected) {
+                Ok(inputs) => inputs,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    ::std::process::exit(1);
+                }
+            };
 
             if time {
                 let total = ::std::time::Instant::now();
                 $(
+                    let input = load_input(&mut inputs, stringify!($day), $day::INPUT);
                     let start = ::std::time::Instant::now();
-                    test::black_box($day::part1($day::INPUT));
-                    test::black_box($day::part2($day::INPUT));
+                    test::black_box($day::part1(input));
+                    test::black_box($day::part2(input));
                     let elapsed = start.elapsed();
                     println!("{} {:10} μs", stringify!($day), micros(elapsed));
                 )+
@@ -359,50 +268,66 @@ macro_rules! gen {
                 return;
             }
 
-            let bench: String = std::env::var("BENCH").ok().unwrap_or_else(|| String::from("0"));
-            let filter: String = std::env::args().nth(1).unwrap_or_default();
-            let iters: usize = std::env::var("BENCH_TIMES").ok()
-                .and_then(|x| x.parse().ok())
-                .unwrap_or(1000);
-            let should_bench = bench != "0";
             if !should_bench {
             $(
                 if stringify!($day).contains(&filter) {
+                    let input = load_input(&mut inputs, stringify!($day), $day::INPUT);
+                    let fingerprint = inputs.take_fingerprint();
+                    let day = stringify!($day)[3..].parse().unwrap();
                     {
                         let start = ::std::time::Instant::now();
-                        let res = $day::part1($day::INPUT);
+                        let res = $day::part1(input).to_string();
                         let elapsed = start.elapsed();
-                        println!("{}::part1 = {} ({}s {}ns or {}s {}ms)",
-                            stringify!($day), res, elapsed.as_secs(), elapsed.subsec_nanos(),
-                                elapsed.as_secs(), elapsed.subsec_nanos() / 1_000_000);
+                        let verdict = answers.check($year, day, 1, fingerprint, &res);
+                        println!("{}::part1 = {} [{}] ({}s {}ns or {}s {}ms)",
+                            stringify!($day), res, verdict, elapsed.as_secs(),
+                                elapsed.subsec_nanos(), elapsed.as_secs(),
+                                elapsed.subsec_nanos() / 1_000_000);
                     }
                     {
                         let start = ::std::time::Instant::now();
-                        let res = $day::part2($day::INPUT);
+                        let res = $day::part2(input).to_string();
                         let elapsed = start.elapsed();
-                        println!("{}::part2 = {} ({}s {}ns or {}s {}ms)",
-                            stringify!($day), res, elapsed.as_secs(), elapsed.subsec_nanos(),
-                                elapsed.as_secs(), elapsed.subsec_nanos() / 1_000_000);
+                        let verdict = answers.check($year, day, 2, fingerprint, &res);
+                        println!("{}::part2 = {} [{}] ({}s {}ns or {}s {}ms)",
+                            stringify!($day), res, verdict, elapsed.as_secs(),
+                                elapsed.subsec_nanos(), elapsed.as_secs(),
+                                elapsed.subsec_nanos() / 1_000_000);
                     }
                 }
             )+
             } else {
             $(
                 if concat!(stringify!($day), "::part1") == bench {
+                    let input = load_input(&mut inputs, stringify!($day), $day::INPUT);
                     println!("benching {} {} times", bench, iters);
                     for _ in 0..iters {
-                        ::test::black_box($day::part1($day::INPUT));
+                        ::test::black_box($day::part1(input));
                     }
                 } else if concat!(stringify!($day), "::part2") == bench {
+                    let input = load_input(&mut inputs, stringify!($day), $day::INPUT);
                     println!("benching {} {} times", bench, iters);
                     for _ in 0..iters {
-                        ::test::black_box($day::part2($day::INPUT));
+                        ::test::black_box($day::part2(input));
                     }
                 }
             )+
             }
         }
 
+        #[cfg(test)]
+        mod answers {
+            $(
+            #[test]
+            fn $day() {
+                let answers = $crate::answers::Answers::embedded();
+                let day = stringify!($day)[3..].parse().unwrap();
+                answers.assert_embedded($year, day, 1, || ::$day::part1(::$day::INPUT).to_string());
+                answers.assert_embedded($year, day, 2, || ::$day::part2(::$day::INPUT).to_string());
+            }
+            )+
+        }
+
         #[cfg(test)]
         mod part1 {
             use test::Bencher;
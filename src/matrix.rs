@@ -1,4 +1,5 @@
 use smallvec::SmallVec;
+use std::cmp;
 use std::fmt;
 use std::marker::PhantomData;
 use {BitVec, VecLike};
@@ -6,6 +7,8 @@ use {BitVec, VecLike};
 const ON: u8 = b'#';
 const OFF: u8 = b'.';
 
+/// An unbounded grid of cells, stored in a `Matrix` that grows to cover
+/// every cell that is set; all others are the default.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Grid<T, C> {
     left_bound: isize,
@@ -30,38 +33,130 @@ impl<T: Copy + Default, C: VecLike<T>> Grid<T, C> {
         }
     }
 
-    pub fn to_matrix(&mut self, x: isize, y: isize) -> (usize, usize) {
-        while x < self.left_bound {
-            self.matrix.insert_column_left();
-            self.matrix.insert_column_left();
-            self.matrix.insert_column_left();
-            self.matrix.insert_column_left();
-            self.matrix.insert_column_left();
-            self.matrix.insert_column_left();
-            self.left_bound -= 6;
+    /// The row and column of a stored cell.
+    fn index(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (left, top, right, bottom) = self.bounds();
+        if x >= left && x < right && y >= top && y < bottom {
+            Some(((y - top) as usize, (x - left) as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Stores the cells in the given bounds, dropping any outside them.
+    fn resize(&mut self, left: isize, top: isize, right: isize, bottom: isize) {
+        let mut matrix = Matrix::new((bottom - top) as usize, (right - left) as usize);
+        for y in top..bottom {
+            for x in left..right {
+                if let Some((row, col)) = self.index(x, y) {
+                    let v = self.matrix.get(row, col).unwrap_or_default();
+                    matrix.set_direct((y - top) as usize, (x - left) as usize, v);
+                }
+            }
         }
-        while y < self.top_bound {
-            self.matrix.insert_row_top();
-            self.matrix.insert_row_top();
-            self.matrix.insert_row_top();
-            self.matrix.insert_row_top();
-            self.matrix.insert_row_top();
-            self.matrix.insert_row_top();
-            self.top_bound -= 6;
+        *self = Grid::from_matrix(matrix, (left, top));
+    }
+
+    /// Grows the grid to store the cell, by at least half again in each
+    /// direction it grows in.
+    fn reserve(&mut self, x: isize, y: isize) -> (usize, usize) {
+        if let Some(index) = self.index(x, y) {
+            return index;
         }
-        let col = (x - self.left_bound) as usize;
-        let row = (y - self.top_bound) as usize;
-        (row, col)
+        let extend = |low: isize, high: isize, v: isize| {
+            let extra = cmp::max((high - low) / 2, 4);
+            (
+                if v < low { v - extra } else { low },
+                if v >= high { v + 1 + extra } else { high },
+            )
+        };
+        let (left, top, right, bottom) = self.bounds();
+        let (left, right) = extend(left, right, x);
+        let (top, bottom) = extend(top, bottom, y);
+        self.resize(left, top, right, bottom);
+        self.index(x, y).unwrap()
     }
 
-    pub fn get(&mut self, x: isize, y: isize) -> T {
-        let (row, col) = self.to_matrix(x, y);
-        self.matrix.get(row, col).unwrap_or_default()
+    pub fn get(&self, x: isize, y: isize) -> T {
+        match self.index(x, y) {
+            Some((row, col)) => self.matrix.get(row, col).unwrap_or_default(),
+            None => T::default(),
+        }
     }
 
     pub fn set(&mut self, x: isize, y: isize, v: T) {
-        let (row, col) = self.to_matrix(x, y);
-        self.matrix.set(row, col, v);
+        let (row, col) = self.reserve(x, y);
+        self.matrix.set_direct(row, col, v);
+    }
+
+    /// The left, top, right and bottom edges of the stored cells, exclusive
+    /// of the right and bottom; every cell outside is the default.
+    pub fn bounds(&self) -> (isize, isize, isize, isize) {
+        (
+            self.left_bound,
+            self.top_bound,
+            self.left_bound + self.matrix.columns() as isize,
+            self.top_bound + self.matrix.rows() as isize,
+        )
+    }
+
+    /// Keeps only the cells within the given edges, as `bounds` returns them.
+    pub fn crop(&mut self, left: isize, top: isize, right: isize, bottom: isize) {
+        self.resize(left, top, right.max(left), bottom.max(top));
+    }
+
+    /// The stored cells and their positions, row by row.
+    pub fn cells<'a>(&'a self) -> impl Iterator<Item = ((isize, isize), T)> + 'a {
+        let (left, top, right, bottom) = self.bounds();
+        (top..bottom)
+            .flat_map(move |y| (left..right).map(move |x| (x, y)))
+            .map(move |(x, y)| ((x, y), self.get(x, y)))
+    }
+
+    /// The eight cells around a cell, row by row.
+    pub fn neighbours<'a>(
+        &'a self,
+        x: isize,
+        y: isize,
+    ) -> impl Iterator<Item = ((isize, isize), T)> + 'a {
+        (y - 1..=y + 1)
+            .flat_map(move |ny| (x - 1..=x + 1).map(move |nx| (nx, ny)))
+            .filter(move |&p| p != (x, y))
+            .map(move |(x, y)| ((x, y), self.get(x, y)))
+    }
+
+    /// Renders the stored cells, a line for each row.
+    pub fn render<F: FnMut(T) -> char>(&self, mut f: F) -> String {
+        let (left, top, right, bottom) = self.bounds();
+        let mut out = String::new();
+        for y in top..bottom {
+            if y != top {
+                out.push('\n');
+            }
+            out.extend((left..right).map(|x| f(self.get(x, y))));
+        }
+        out
+    }
+}
+
+impl<T: Copy + Default + PartialEq, C: VecLike<T>> Grid<T, C> {
+    /// The cells other than the default and their positions, row by row.
+    pub fn occupied<'a>(&'a self) -> impl Iterator<Item = ((isize, isize), T)> + 'a {
+        self.cells().filter(|&(_, v)| v != T::default())
+    }
+
+    /// The smallest bounds holding every cell other than the default, if any.
+    pub fn extent(&self) -> Option<(isize, isize, isize, isize)> {
+        self.occupied().fold(None, |extent, ((x, y), _)| {
+            let (l, t, r, b) = extent.unwrap_or((x, y, x + 1, y + 1));
+            Some((l.min(x), t.min(y), r.max(x + 1), b.max(y + 1)))
+        })
+    }
+
+    /// Crops the grid to its `extent`.
+    pub fn trim(&mut self) {
+        let (left, top, right, bottom) = self.extent().unwrap_or((0, 0, 0, 0));
+        self.crop(left, top, right, bottom);
     }
 }
 
@@ -73,10 +168,53 @@ impl Grid<bool, BitVec> {
 
 impl<C: VecLike<bool>> fmt::Debug for Grid<bool, C> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", DebugMatrix(&self.matrix, "\n"))
+        let rendered = self.render(|b| if b { ON as char } else { OFF as char });
+        write!(f, "{}", rendered)
     }
 }
 
+#[test]
+fn grid_grows() {
+    let mut grid = Grid::<u8, Vec<u8>>::new();
+    grid.set(-10, 0, 1);
+    grid.set(5, 20, 2);
+    grid.set(0, -7, 3);
+    assert_eq!(grid.get(-10, 0), 1);
+    assert_eq!(grid.get(5, 20), 2);
+    assert_eq!(grid.get(0, -7), 3);
+    assert_eq!(grid.get(100, 100), 0);
+    assert_eq!(grid.extent(), Some((-10, -7, 6, 21)));
+    assert_eq!(grid.occupied().count(), 3);
+    grid.trim();
+    assert_eq!(grid.bounds(), (-10, -7, 6, 21));
+    assert_eq!(grid.get(5, 20), 2);
+    grid.crop(-10, -7, 1, 1);
+    assert_eq!(
+        grid.occupied().map(|(p, _)| p).collect::<Vec<_>>(),
+        [(0, -7), (-10, 0)]
+    );
+}
+
+#[test]
+fn grid_render() {
+    let mut grid = Grid::<u8, Vec<u8>>::from_matrix(Matrix::new(2, 3), (0, 0));
+    grid.set(0, 0, 1);
+    grid.set(2, 1, 2);
+    grid.set(3, -1, 1);
+    grid.trim();
+    assert_eq!(
+        grid.render(|v| b".#@"[v as usize] as char),
+        "...#\n#...\n..@."
+    );
+    let around = grid
+        .neighbours(1, 0)
+        .filter(|&(_, v)| v != 0)
+        .collect::<Vec<_>>();
+    assert_eq!(around, [((0, 0), 1), ((2, 1), 2)]);
+    let grid = Grid::<bool, BitVec>::from_matrix(Matrix::interpret_bool(".#/#."), (0, 0));
+    assert_eq!(format!("{:?}", grid), ".#\n#.");
+}
+
 #[derive(Hash, Clone, PartialEq, Eq)]
 pub struct Matrix<T, C> {
     matrix: C,
@@ -146,6 +284,7 @@ where
         }
     }
 
+    #[cfg(test)]
     fn insert_row_top(&mut self) {
         for _ in 0..self.cols {
             self.matrix.insert(0, T::default());
@@ -162,6 +301,7 @@ where
         self.rows += 1;
     }
 
+    #[cfg(test)]
     fn insert_column_left(&mut self) {
         let mut inserted = 0;
         for row in 0..self.rows {
@@ -0,0 +1,179 @@
+//! Mazes drawn with characters, and the distances between the points of
+//! interest marked in them.
+//!
+//! Puzzles that only care about getting from one marked point to another
+//! can work on the `Distances` between those points, a search from each
+//! over the cells, rather than on every cell.
+
+use geometry::Point;
+use search::{self, Search};
+use Matrix;
+
+/// Open cells and walls, with labelled points of interest.
+#[derive(Clone)]
+pub struct Maze<L> {
+    open: Matrix<bool, Vec<bool>>,
+    points: Vec<(L, Point)>,
+}
+
+impl<L: Copy + Ord> Maze<L> {
+    /// Reads a maze drawn a row per line, where `#` is a wall and anything
+    /// else is open; `label` picks out the points of interest.
+    pub fn parse<F>(s: &str, label: F) -> Maze<L>
+    where
+        F: FnMut(char) -> Option<L>,
+    {
+        Maze::parse_with(s, |c| c != '#', label)
+    }
+
+    /// Like `parse`, with `is_open` telling open cells from walls. Labelled
+    /// cells are always open, and cells past the end of a short line are
+    /// walls.
+    pub fn parse_with<O, F>(s: &str, mut is_open: O, mut label: F) -> Maze<L>
+    where
+        O: FnMut(char) -> bool,
+        F: FnMut(char) -> Option<L>,
+    {
+        let lines = s.trim_matches('\n').lines().collect::<Vec<_>>();
+        let columns = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut open = Matrix::new(lines.len(), columns);
+        let mut points = Vec::new();
+        for (row, line) in lines.iter().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                let label = label(c);
+                if let Some(label) = label {
+                    points.push((label, Point::from_index(row, col)));
+                }
+                open.set(row, col, label.is_some() || is_open(c));
+            }
+        }
+        points.sort();
+        Maze { open, points }
+    }
+
+    pub fn is_open(&self, p: Point) -> bool {
+        p.index_in(&self.open)
+            .map_or(false, |(row, col)| self.open.get(row, col).unwrap())
+    }
+
+    /// The open cells next to `p`, in reading order.
+    pub fn open_neighbours<'a>(&'a self, p: Point) -> impl Iterator<Item = Point> + 'a {
+        p.neighbours()
+            .to_vec()
+            .into_iter()
+            .filter(move |&n| self.is_open(n))
+    }
+
+    /// The points of interest, ordered by label.
+    pub fn points(&self) -> &[(L, Point)] {
+        &self.points
+    }
+
+    /// The first point with `label`.
+    pub fn point(&self, label: L) -> Option<Point> {
+        self.points.iter().find(|p| p.0 == label).map(|p| p.1)
+    }
+
+    /// Every cell reachable from `from`, with the steps to it.
+    pub fn search_from(&self, from: Point) -> Search<Point, usize> {
+        search::bfs(from, |&p| self.open_neighbours(p), |_| false)
+    }
+
+    /// The distances between every two points of interest.
+    pub fn distances(&self) -> Distances<L> {
+        let n = self.points.len();
+        let mut lengths = vec![None; n * n];
+        for (a, &(_, from)) in self.points.iter().enumerate() {
+            let search = self.search_from(from);
+            for (b, &(_, to)) in self.points.iter().enumerate() {
+                lengths[a * n + b] = search.cost(&to);
+            }
+        }
+        Distances {
+            labels: self.points.iter().map(|p| p.0).collect(),
+            lengths,
+        }
+    }
+}
+
+/// The length of the shortest path between each two points of interest of a
+/// maze, indexed like `Maze::points`: a complete weighted graph, with an
+/// edge wherever there is a path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Distances<L> {
+    labels: Vec<L>,
+    lengths: Vec<Option<usize>>,
+}
+
+impl<L: Copy + Ord> Distances<L> {
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    /// The index of the first point with `label`.
+    pub fn index(&self, label: L) -> Option<usize> {
+        self.labels.iter().position(|&l| l == label)
+    }
+
+    /// The distance between the points at indices `a` and `b`, if either
+    /// can be reached from the other.
+    pub fn get(&self, a: usize, b: usize) -> Option<usize> {
+        self.lengths[a * self.len() + b]
+    }
+
+    /// The distance between the first points labelled `a` and `b`.
+    pub fn between(&self, a: L, b: L) -> Option<usize> {
+        self.get(self.index(a)?, self.index(b)?)
+    }
+
+    /// The indices of each two connected points, lower first, and the
+    /// distance between them.
+    pub fn edges<'a>(&'a self) -> impl Iterator<Item = (usize, usize, usize)> + 'a {
+        let n = self.len();
+        (0..n).flat_map(move |a| (a + 1..n).filter_map(move |b| Some((a, b, self.get(a, b)?))))
+    }
+}
+
+#[test]
+fn maze_distances() {
+    let maze = Maze::parse(
+        "
+###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+#####.#####
+#5#
+",
+        |c| c.to_digit(10),
+    );
+    assert_eq!(maze.point(3), Some(Point::new(9, 3)));
+    assert!(maze.is_open(Point::new(5, 4)));
+    assert!(!maze.is_open(Point::new(5, 5)));
+    assert_eq!(
+        maze.open_neighbours(Point::new(1, 1)).collect::<Vec<_>>(),
+        [Point::new(2, 1), Point::new(1, 2)]
+    );
+
+    let distances = maze.distances();
+    assert_eq!(distances.labels(), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(distances.between(0, 0), Some(0));
+    assert_eq!(distances.between(0, 4), Some(2));
+    assert_eq!(distances.between(1, 2), Some(6));
+    assert_eq!(distances.between(2, 4), Some(10));
+    assert_eq!(distances.between(5, 0), None);
+    assert_eq!(distances.between(0, 7), None);
+    assert_eq!(distances.edges().count(), 10);
+    assert_eq!(
+        distances.edges().find(|e| e.0 == 3 && e.1 == 4),
+        Some((3, 4, 8))
+    );
+}
@@ -0,0 +1,650 @@
+//! A byte-at-a-time parser for puzzle inputs.
+//!
+//! Errors carry the span of input they are about, so that `render` can point
+//! at it. The combinators (`optional`, `alt`, `many` and `sep_by`) go back
+//! to where they started when a parser fails without consuming anything, and
+//! pass its error on otherwise: a line that starts right but goes wrong
+//! halfway is reported where it goes wrong, not as the end of a list.
+
+use std::any::{self, Any};
+use std::error::Error;
+use std::fmt;
+use std::str::{self, FromStr};
+
+use memchr::memchr;
+use memchr::memchr2;
+
+/// A range of byte offsets into the input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    Eof,
+    /// What was expected, and the character found instead, if any.
+    Unexpected(String, Option<char>),
+    /// A number too large for the type named.
+    OutOfRange(&'static str),
+    /// Text that the type named could not be parsed from, and why.
+    Invalid(&'static str, String),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ErrorKind::Eof => write!(f, "unexpected end of input"),
+            ErrorKind::Unexpected(ref expected, Some(found)) => {
+                write!(f, "expected {}, found {:?}", expected, found)
+            }
+            ErrorKind::Unexpected(ref expected, None) => {
+                write!(f, "expected {}, found end of input", expected)
+            }
+            ErrorKind::OutOfRange(ty) => write!(f, "number does not fit in {}", ty),
+            ErrorKind::Invalid(ty, ref error) => write!(f, "invalid {}: {}", ty, error),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParserError {
+    pub span: Span,
+    pub kind: ErrorKind,
+}
+
+impl ParserError {
+    /// Moves the span `offset` bytes on, for an error from a parser over part
+    /// of a larger input.
+    pub fn shifted(mut self, offset: usize) -> ParserError {
+        self.span.start += offset;
+        self.span.end += offset;
+        self
+    }
+
+    /// The line and column the error starts at, both counting from one.
+    pub fn line_column(&self, input: &str) -> (usize, usize) {
+        let before = &input[..floor_char_boundary(input, self.span.start)];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+
+    /// Describes the error with the line it is on, and carets under the span
+    /// it is about:
+    ///
+    /// ```text
+    /// line 2, column 5: expected "bot " or "output ", found 'x'
+    /// bot xyz gives low to bot 3 and high to bot 4
+    ///     ^
+    /// ```
+    pub fn render(&self, input: &str) -> String {
+        let (line, column) = self.line_column(input);
+        let start = floor_char_boundary(input, self.span.start);
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let text = &input[line_start..line_end];
+        let end = floor_char_boundary(input, self.span.end.max(start).min(line_end));
+        let indent = input[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let carets = "^".repeat(input[start..end].chars().count().max(1));
+        format!(
+            "line {}, column {}: {}\n{}\n{}{}",
+            line, column, self.kind, text, indent, carets
+        )
+    }
+}
+
+/// The error for text at `span` that `T::from_str` rejected with `error`;
+/// errors that are themselves `ParserError`s are moved to the text's offset.
+fn from_str_error<T>(error: T::Err, span: Span) -> ParserError
+where
+    T: FromStr,
+    T::Err: fmt::Display + 'static,
+{
+    match (&error as &dyn Any).downcast_ref::<ParserError>() {
+        Some(inner) => inner.clone().shifted(span.start),
+        None => ParserError {
+            span,
+            kind: ErrorKind::Invalid(type_name::<T>(), error.to_string()),
+        },
+    }
+}
+
+/// Parses every line of `s` that isn't blank, with errors placed in `s` so
+/// that `ParserError::render` can show which line is at fault.
+pub fn parse_lines<T>(s: &str) -> Result<Vec<T>, ParserError>
+where
+    T: FromStr,
+    T::Err: fmt::Display + 'static,
+{
+    let mut out = Vec::new();
+    let mut start = 0;
+    for line in s.split('\n') {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            let offset = start + (trimmed.as_ptr() as usize - line.as_ptr() as usize);
+            let span = Span {
+                start: offset,
+                end: offset + trimmed.len(),
+            };
+            out.push(trimmed.parse().map_err(|e| from_str_error::<T>(e, span))?);
+        }
+        start += line.len() + 1;
+    }
+    Ok(out)
+}
+
+/// The name of `T` without the path to it, as in `u8` or `Vec<u8>`.
+fn type_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+    let path = &name[..name.find('<').unwrap_or(name.len())];
+    &name[path.rfind("::").map_or(0, |i| i + 2)..]
+}
+
+fn floor_char_boundary(s: &str, mut i: usize) -> usize {
+    i = i.min(s.len());
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.span.start)
+    }
+}
+
+impl Error for ParserError {}
+
+#[derive(Copy, Clone)]
+pub struct Parser<'a> {
+    idx: usize,
+    input: &'a [u8],
+}
+
+impl<'a> Parser<'a> {
+    pub fn new<'b>(input: &'b [u8]) -> Parser<'b> {
+        Parser { idx: 0, input }
+    }
+
+    #[inline(always)]
+    pub fn at_end(&self) -> bool {
+        self.idx == self.input.len()
+    }
+
+    /// How many bytes have been consumed.
+    pub fn position(&self) -> usize {
+        self.idx
+    }
+
+    #[inline(always)]
+    pub fn cur(&self) -> Option<u8> {
+        self.input.get(self.idx).cloned()
+    }
+
+    #[inline(always)]
+    pub fn read(&mut self) -> Option<u8> {
+        let out = self.cur();
+        if out.is_some() {
+            self.idx += 1;
+        }
+        out
+    }
+
+    #[inline(always)]
+    pub fn advance(&mut self) {
+        if self.at_end() {
+            panic!("advanced past input length");
+        }
+        self.idx += 1;
+    }
+
+    /// An error about the input from `start` up to here.
+    pub fn error_from(&self, start: usize, kind: ErrorKind) -> ParserError {
+        ParserError {
+            span: Span {
+                start,
+                end: self.idx,
+            },
+            kind,
+        }
+    }
+
+    /// An error saying that `expected` should have come next.
+    pub fn unexpected(&self, expected: &str) -> ParserError {
+        let found = self.rest_str().chars().next();
+        let len = found.map_or(0, |c| c.len_utf8());
+        ParserError {
+            span: Span {
+                start: self.idx,
+                end: self.idx + len,
+            },
+            kind: ErrorKind::Unexpected(expected.to_string(), found),
+        }
+    }
+
+    fn rest_str(&self) -> &'a str {
+        let rest = &self.input[self.idx..];
+        match str::from_utf8(rest) {
+            Ok(s) => s,
+            Err(e) => str::from_utf8(&rest[..e.valid_up_to()]).unwrap(),
+        }
+    }
+
+    /// If needle isn't present, consumes until EOF.
+    pub fn consume_until(&mut self, c: u8) -> usize {
+        if let Some(idx) = memchr(c, &self.input[self.idx..]) {
+            self.idx += idx;
+            idx
+        } else {
+            let ret = self.input.len() - self.idx;
+            self.idx = self.input.len();
+            ret
+        }
+    }
+
+    pub fn consume_until_or_stop(&mut self, c: u8) -> Option<usize> {
+        if let Some(idx) = memchr(c, &self.input[self.idx..]) {
+            self.idx += idx;
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
+    pub fn consume_until2_or_stop(&mut self, a: u8, b: u8) -> Option<usize> {
+        if let Some(idx) = memchr2(a, b, &self.input[self.idx..]) {
+            self.idx += idx;
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
+    pub fn consume_bytes_until(&mut self, c: u8) -> &'a [u8] {
+        let orig_idx = self.idx;
+        let _ = self.consume_until(c);
+        &self.input[orig_idx..self.idx]
+    }
+
+    pub fn expect(&mut self, needle: &[u8]) -> Result<bool, ParserError> {
+        match self.consume_bytes(needle.len()).map(|b| b == needle) {
+            Ok(eq) => {
+                if !eq {
+                    self.idx -= needle.len();
+                }
+                Ok(eq)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Consumes `needle`, or fails if it doesn't come next.
+    pub fn literal(&mut self, needle: &str) -> Result<(), ParserError> {
+        if self.input[self.idx..].starts_with(needle.as_bytes()) {
+            self.idx += needle.len();
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("{:?}", needle)))
+        }
+    }
+
+    pub fn consume_bytes(&mut self, n: usize) -> Result<&'a [u8], ParserError> {
+        if self.idx + n > self.input.len() {
+            return Err(ParserError {
+                span: Span {
+                    start: self.input.len(),
+                    end: self.input.len(),
+                },
+                kind: ErrorKind::Eof,
+            });
+        }
+
+        let out = &self.input[self.idx..self.idx + n];
+        self.idx += n;
+        Ok(out)
+    }
+
+    pub fn consume(&mut self, x: u8) -> Result<(), ParserError> {
+        if self.cur() == Some(x) {
+            self.idx += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("{:?}", x as char)))
+        }
+    }
+
+    pub fn consume_signed_number(&mut self) -> Result<i64, ParserError> {
+        self.signed()
+    }
+
+    pub fn consume_number(&mut self) -> Result<u64, ParserError> {
+        self.unsigned()
+    }
+
+    /// Consumes bytes while `f` holds for them.
+    pub fn take_while<F: FnMut(u8) -> bool>(&mut self, mut f: F) -> &'a [u8] {
+        let start = self.idx;
+        while self.cur().map_or(false, &mut f) {
+            self.idx += 1;
+        }
+        &self.input[start..self.idx]
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(|c| c.is_ascii_whitespace());
+    }
+
+    fn number<T: FromStr>(&mut self, start: usize) -> Result<T, ParserError> {
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            let error = self.unexpected("number");
+            self.idx = start;
+            return Err(error);
+        }
+        let digits = str::from_utf8(&self.input[start..self.idx]).unwrap();
+        digits
+            .parse()
+            .map_err(|_| self.error_from(start, ErrorKind::OutOfRange(type_name::<T>())))
+    }
+
+    /// Reads an unsigned integer of any width.
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParserError> {
+        let start = self.idx;
+        self.number(start)
+    }
+
+    /// Reads an integer of any width, with an optional sign.
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParserError> {
+        let start = self.idx;
+        if self.cur() == Some(b'-') || self.cur() == Some(b'+') {
+            self.idx += 1;
+        }
+        self.number(start)
+    }
+
+    /// Reads a letter or underscore followed by any letters, digits and
+    /// underscores.
+    pub fn identifier(&mut self) -> Result<&'a str, ParserError> {
+        match self.cur() {
+            Some(c) if c.is_ascii_alphabetic() || c == b'_' => {}
+            _ => return Err(self.unexpected("identifier")),
+        }
+        let ident = self.take_while(|c| c.is_ascii_alphanumeric() || c == b'_');
+        Ok(str::from_utf8(ident).unwrap())
+    }
+
+    /// Reads everything up to the next whitespace.
+    pub fn word(&mut self) -> Result<&'a str, ParserError> {
+        let start = self.idx;
+        let word = self.take_while(|c| !c.is_ascii_whitespace());
+        if word.is_empty() {
+            return Err(self.unexpected("word"));
+        }
+        str::from_utf8(word).map_err(|e| {
+            let mut error = self.unexpected("UTF-8");
+            error.span.start = start + e.valid_up_to();
+            error
+        })
+    }
+
+    /// Reads up to `stop`, or to the end of the input if there is no stop;
+    /// a whitespace `stop` stands for any whitespace. Whitespace around the
+    /// text is skipped, and the text must not be empty.
+    pub fn field_text(&mut self, stop: Option<u8>) -> Result<&'a str, ParserError> {
+        self.field_of("field", stop)
+    }
+
+    /// Reads a value with `FromStr` from the text `field_text` would return.
+    pub fn field<T>(&mut self, stop: Option<u8>) -> Result<T, ParserError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display + 'static,
+    {
+        let text = self.field_of(type_name::<T>(), stop)?;
+        let span = Span {
+            start: self.idx - text.len(),
+            end: self.idx,
+        };
+        text.parse().map_err(|e| from_str_error::<T>(e, span))
+    }
+
+    fn field_of(&mut self, expected: &str, stop: Option<u8>) -> Result<&'a str, ParserError> {
+        self.skip_whitespace();
+        let start = self.idx;
+        let rest = &self.input[start..];
+        let len = match stop {
+            Some(c) if c.is_ascii_whitespace() => rest.iter().position(|c| c.is_ascii_whitespace()),
+            Some(c) => memchr(c, rest),
+            None => None,
+        }
+        .unwrap_or(rest.len());
+        let len = len
+            - rest[..len]
+                .iter()
+                .rev()
+                .take_while(|c| c.is_ascii_whitespace())
+                .count();
+        if len == 0 {
+            return Err(self.unexpected(expected));
+        }
+        self.idx += len;
+        str::from_utf8(&rest[..len])
+            .map_err(|_| self.error_from(start, ErrorKind::Unexpected("UTF-8".to_string(), None)))
+    }
+
+    /// Fails unless the whole input has been consumed.
+    pub fn end(&self) -> Result<(), ParserError> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected("end of input"))
+        }
+    }
+
+    /// Runs `f`, and goes back if it fails without consuming anything.
+    fn attempt<T, F>(&mut self, f: F) -> Result<Option<T>, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a>) -> Result<T, ParserError>,
+    {
+        let start = self.idx;
+        match f(self) {
+            Ok(v) => Ok(Some(v)),
+            Err(ref e) if e.span.start == start => {
+                self.idx = start;
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn optional<T, F>(&mut self, f: F) -> Result<Option<T>, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a>) -> Result<T, ParserError>,
+    {
+        self.attempt(f)
+    }
+
+    /// Runs `a`, or `b` if `a` fails without consuming anything. If both do,
+    /// the error says that either was expected.
+    pub fn alt<T, A, B>(&mut self, a: A, b: B) -> Result<T, ParserError>
+    where
+        A: FnOnce(&mut Parser<'a>) -> Result<T, ParserError>,
+        B: FnOnce(&mut Parser<'a>) -> Result<T, ParserError>,
+    {
+        let start = self.idx;
+        let first = match a(self) {
+            Ok(v) => return Ok(v),
+            Err(e) => e,
+        };
+        if first.span.start != start {
+            return Err(first);
+        }
+        self.idx = start;
+        match b(self) {
+            Ok(v) => Ok(v),
+            Err(second) => match (first.kind, second.kind) {
+                (ErrorKind::Unexpected(a, found), ErrorKind::Unexpected(b, _))
+                    if second.span.start == start =>
+                {
+                    Err(ParserError {
+                        span: second.span,
+                        kind: ErrorKind::Unexpected(format!("{} or {}", a, b), found),
+                    })
+                }
+                (_, kind) => Err(ParserError {
+                    span: second.span,
+                    kind,
+                }),
+            },
+        }
+    }
+
+    /// Runs `f` for as long as it succeeds.
+    pub fn many<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ParserError>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T, ParserError>,
+    {
+        let mut out = Vec::new();
+        while !self.at_end() {
+            match self.attempt(&mut f)? {
+                Some(v) => out.push(v),
+                None => break,
+            }
+        }
+        Ok(out)
+    }
+
+    /// Reads any number of `f`, separated by `separator`.
+    pub fn sep_by<T, F>(&mut self, separator: &str, mut f: F) -> Result<Vec<T>, ParserError>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T, ParserError>,
+    {
+        let mut out = Vec::new();
+        match self.attempt(&mut f)? {
+            Some(v) => out.push(v),
+            None => return Ok(out),
+        }
+        while self.attempt(|p| p.literal(separator))?.is_some() {
+            out.push(f(self)?);
+        }
+        Ok(out)
+    }
+}
+
+#[test]
+fn parser_number() {
+    assert_eq!(Parser::new(b"100").consume_number(), Ok(100));
+    let mut p = Parser::new(b"-128,300,+7");
+    assert_eq!(p.signed::<i8>(), Ok(-128));
+    p.literal(",").unwrap();
+    let error = p.unsigned::<u8>().unwrap_err();
+    assert_eq!(error.kind, ErrorKind::OutOfRange("u8"));
+    assert_eq!(error.span, Span { start: 5, end: 8 });
+    p.literal(",").unwrap();
+    assert_eq!(p.signed::<u128>(), Ok(7));
+    assert!(Parser::new(b"-").signed::<i32>().is_err());
+}
+
+#[test]
+fn parser_consume_until() {
+    assert_eq!(Parser::new(b"aaax").consume_until(b'x'), 3);
+}
+
+#[test]
+fn parser_consume_until_no_exist() {
+    assert_eq!(Parser::new(b"aaa").consume_until(b'x'), 3);
+}
+
+#[test]
+fn parser_words() {
+    let mut p = Parser::new(b"foo_1 2bar  baz");
+    assert_eq!(p.identifier(), Ok("foo_1"));
+    p.skip_whitespace();
+    assert!(p.identifier().is_err());
+    assert_eq!(p.word(), Ok("2bar"));
+    p.skip_whitespace();
+    assert_eq!(p.word(), Ok("baz"));
+    assert_eq!(p.end(), Ok(()));
+}
+
+#[test]
+fn parser_combinators() {
+    let input = "1,-2,3;x";
+    let mut p = Parser::new(input.as_bytes());
+    assert_eq!(p.sep_by(",", |p| p.signed::<i32>()), Ok(vec![1, -2, 3]));
+    assert_eq!(p.optional(|p| p.literal(",")), Ok(None));
+    assert_eq!(p.optional(|p| p.literal(";")), Ok(Some(())));
+    let error = p
+        .alt(|p| p.literal("y").map(|_| 0), |p| p.unsigned::<u32>())
+        .unwrap_err();
+    assert_eq!(
+        error.kind,
+        ErrorKind::Unexpected("\"y\" or number".to_string(), Some('x'))
+    );
+    assert_eq!(error.span, Span { start: 7, end: 8 });
+
+    // A separator must be followed by another item.
+    let mut p = Parser::new(b"1,2,");
+    let error = p.sep_by(",", |p| p.unsigned::<u8>()).unwrap_err();
+    assert_eq!(error.span.start, 4);
+
+    let mut p = Parser::new(b"ab ab ac");
+    let pairs = p.many(|p| {
+        p.literal("a")?;
+        let b = p.literal("b");
+        p.skip_whitespace();
+        b
+    });
+    assert_eq!(pairs.unwrap_err().span.start, 7);
+}
+
+#[test]
+fn parser_render() {
+    let input = "value 5 goes to bot 2\nbot 2 gives lw to bot 1\n";
+    let mut p = Parser::new(input.as_bytes());
+    p.consume_until(b'\n');
+    p.literal("\nbot 2 gives ").unwrap();
+    let error = p.literal("low").unwrap_err();
+    assert_eq!(error.line_column(input), (2, 13));
+    assert_eq!(
+        error.render(input),
+        "line 2, column 13: expected \"low\", found 'l'\nbot 2 gives lw to bot 1\n            ^"
+    );
+    let error = ParserError {
+        span: Span { start: 6, end: 7 },
+        kind: ErrorKind::OutOfRange("u8"),
+    };
+    assert_eq!(
+        error.render(input),
+        "line 1, column 7: number does not fit in u8\nvalue 5 goes to bot 2\n      ^"
+    );
+}
+
+#[test]
+fn parser_fields() {
+    let mut p = Parser::new(b"< 3, x>  9  rest of it ");
+    p.literal("<").unwrap();
+    assert_eq!(p.field::<i32>(Some(b',')), Ok(3));
+    p.literal(",").unwrap();
+    let error = p.field::<i32>(Some(b'>')).unwrap_err();
+    assert_eq!(error.span, Span { start: 5, end: 6 });
+    assert_eq!(
+        error.kind.to_string(),
+        "invalid i32: invalid digit found in string"
+    );
+    p.literal(">").unwrap();
+    assert_eq!(p.field::<u8>(Some(b' ')), Ok(9));
+    assert_eq!(p.field_text(None), Ok("rest of it"));
+    assert!(p.field_text(None).is_err());
+
+    let s = "1\n\n  2\n300\n";
+    assert_eq!(parse_lines::<u16>(s), Ok(vec![1, 2, 300]));
+    let error = parse_lines::<u8>(s).unwrap_err();
+    assert_eq!(error.line_column(s), (4, 1));
+    assert_eq!(error.span, Span { start: 7, end: 10 });
+}
@@ -0,0 +1,179 @@
+//! Permutations of `0..n`, for shuffles that are repeated many times (2017
+//! day 16's dance a billion times over).
+//!
+//! A permutation sends each `i` to `p[i]`. It can act on positions, moving
+//! the element at `i` to `p[i]`, or on labels, renaming `i` to `p[i]`. Either
+//! way, `a.then(&b)` is `a` followed by `b`.
+
+use std::ops::Index;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Permutation {
+    map: Vec<usize>,
+}
+
+impl Permutation {
+    /// Panics if `map` is not a permutation of `0..map.len()`.
+    pub fn new(map: Vec<usize>) -> Permutation {
+        let mut seen = vec![false; map.len()];
+        for &i in &map {
+            assert!(i < map.len() && !seen[i], "{:?} is not a permutation", map);
+            seen[i] = true;
+        }
+        Permutation { map }
+    }
+
+    pub fn identity(n: usize) -> Permutation {
+        Permutation {
+            map: (0..n).collect(),
+        }
+    }
+
+    /// The permutation moving the elements of `0..n` into `order`, such that
+    /// `order[j]` ends up at `j`.
+    pub fn arrangement(order: &[usize]) -> Permutation {
+        Permutation::new(order.to_vec()).inverse()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.map.iter().enumerate().all(|(i, &j)| i == j)
+    }
+
+    /// `self` followed by `other`.
+    pub fn then(&self, other: &Permutation) -> Permutation {
+        assert_eq!(self.len(), other.len());
+        Permutation {
+            map: self.map.iter().map(|&i| other.map[i]).collect(),
+        }
+    }
+
+    pub fn inverse(&self) -> Permutation {
+        let mut map = vec![0; self.len()];
+        for (i, &j) in self.map.iter().enumerate() {
+            map[j] = i;
+        }
+        Permutation { map }
+    }
+
+    /// `self` applied `k` times, by repeated squaring.
+    pub fn pow(&self, mut k: u64) -> Permutation {
+        let mut result = Permutation::identity(self.len());
+        let mut square = self.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                result = result.then(&square);
+            }
+            square = square.then(&square);
+            k >>= 1;
+        }
+        result
+    }
+
+    /// Moves the element at each position `i` of `xs` to `self[i]`.
+    pub fn permute<T: Clone>(&self, xs: &[T]) -> Vec<T> {
+        assert_eq!(self.len(), xs.len());
+        let mut out = xs.to_vec();
+        for (x, &i) in xs.iter().zip(&self.map) {
+            out[i] = x.clone();
+        }
+        out
+    }
+
+    /// Renames each label `l` in `labels` to `self[l]`.
+    pub fn relabel(&self, labels: &[usize]) -> Vec<usize> {
+        labels.iter().map(|&l| self.map[l]).collect()
+    }
+}
+
+impl Index<usize> for Permutation {
+    type Output = usize;
+
+    fn index(&self, i: usize) -> &usize {
+        &self.map[i]
+    }
+}
+
+/// Moves elements around and renames them. The two commute, so any sequence
+/// of moves and renames is a single `Shuffle`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Shuffle {
+    pub positions: Permutation,
+    pub labels: Permutation,
+}
+
+impl Shuffle {
+    pub fn identity(n: usize) -> Shuffle {
+        Shuffle {
+            positions: Permutation::identity(n),
+            labels: Permutation::identity(n),
+        }
+    }
+
+    pub fn then(&self, other: &Shuffle) -> Shuffle {
+        Shuffle {
+            positions: self.positions.then(&other.positions),
+            labels: self.labels.then(&other.labels),
+        }
+    }
+
+    pub fn inverse(&self) -> Shuffle {
+        Shuffle {
+            positions: self.positions.inverse(),
+            labels: self.labels.inverse(),
+        }
+    }
+
+    pub fn pow(&self, k: u64) -> Shuffle {
+        Shuffle {
+            positions: self.positions.pow(k),
+            labels: self.labels.pow(k),
+        }
+    }
+
+    pub fn apply(&self, labels: &[usize]) -> Vec<usize> {
+        self.labels.relabel(&self.positions.permute(labels))
+    }
+}
+
+#[test]
+fn permutation_algebra() {
+    let p = Permutation::new(vec![1, 2, 0, 4, 3]);
+    assert_eq!(
+        p.permute(&['a', 'b', 'c', 'd', 'e']),
+        ['c', 'a', 'b', 'e', 'd']
+    );
+    assert!(p.then(&p.inverse()).is_identity());
+    assert!(p.pow(6).is_identity());
+    assert_eq!(p.pow(7), p);
+    assert_eq!(p.pow(2), p.then(&p));
+    assert_eq!(p.pow(5), p.inverse());
+    let order = [2, 0, 1, 4, 3];
+    assert_eq!(
+        Permutation::arrangement(&order).permute(&[0, 1, 2, 3, 4]),
+        order
+    );
+}
+
+#[test]
+fn shuffle_pow() {
+    // Swap the first two positions, then rename 0 and 2.
+    let step = Shuffle {
+        positions: Permutation::new(vec![1, 0, 2]),
+        labels: Permutation::new(vec![2, 1, 0]),
+    };
+    let mut naive = vec![0, 1, 2];
+    for k in 0..10 {
+        assert_eq!(step.pow(k).apply(&[0, 1, 2]), naive);
+        naive.swap(0, 1);
+        naive = naive.iter().map(|&l| step.labels[l]).collect();
+    }
+    assert_eq!(step.then(&step.inverse()), Shuffle::identity(3));
+}
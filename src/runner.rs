@@ -0,0 +1,465 @@
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+
+use answers::{Answers, Verdict};
+use input::{InputError, Inputs};
+
+/// Computes a part's answer from input that is already loaded and parsed.
+pub type Answer = Box<dyn FnOnce() -> String>;
+
+/// Sets up a fresh `Answer` for each run, from input loaded once.
+pub type Setup = Box<dyn FnMut() -> Answer>;
+
+/// Loads a part's input, returning how to set up each run of it.
+pub type Load = Box<dyn Fn(&mut Inputs) -> Result<Setup, InputError>>;
+
+/// A single part of a single day, as registered with the `aoc` runner.
+///
+/// Only running the `Answer` is timed; loading and setting up are not.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub load: Load,
+}
+
+impl Solution {
+    pub fn name(&self) -> String {
+        format!("{}::day{:02}::part{}", self.year, self.day, self.part)
+    }
+}
+
+/// A day with no `Solution`, which `list` mentions with the reason.
+pub struct Excluded {
+    pub year: u16,
+    pub day: u8,
+    pub reason: &'static str,
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// One JSON object, on a single line, describing a solution's result.
+///
+/// `elapsed_ns` is per iteration when `iterations` is given.
+pub fn json_record(
+    solution: &Solution,
+    answer: &str,
+    verdict: &Verdict,
+    elapsed_ns: u64,
+    iterations: Option<u32>,
+) -> String {
+    let (expected, pass) = match *verdict {
+        Verdict::Correct => (json_string(answer), "true"),
+        Verdict::Wrong(ref expected) => (json_string(expected), "false"),
+        Verdict::Unknown => (String::from("null"), "null"),
+    };
+    let mut out = format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"expected\":{},\"pass\":{},\"elapsed_ns\":{}",
+        solution.year,
+        solution.day,
+        solution.part,
+        json_string(answer),
+        expected,
+        pass,
+        elapsed_ns
+    );
+    if let Some(iterations) = iterations {
+        out.push_str(&format!(",\"iterations\":{}", iterations));
+    }
+    out.push('}');
+    out
+}
+
+/// Which solutions a command applies to; `None` matches everything.
+#[derive(Default, Debug, PartialEq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+}
+
+impl Selection {
+    /// Parses `[YEAR [DAY [PART]]]`, where the day and part may be written as
+    /// `day05`/`5` and `part1`/`1`.
+    pub fn parse<S: AsRef<str>>(args: &[S]) -> Result<Selection, String> {
+        fn number<T: ::std::str::FromStr>(arg: &str, prefix: &str) -> Result<T, String> {
+            let digits = if arg.starts_with(prefix) {
+                &arg[prefix.len()..]
+            } else {
+                arg
+            };
+            digits
+                .parse()
+                .map_err(|_| format!("expected {}, found {:?}", prefix, arg))
+        }
+
+        if args.len() > 3 {
+            return Err(format!("unexpected argument {:?}", args[3].as_ref()));
+        }
+        let mut selection = Selection::default();
+        if let Some(year) = args.first() {
+            selection.year = Some(number(year.as_ref(), "year")?);
+        }
+        if let Some(day) = args.get(1) {
+            selection.day = Some(number(day.as_ref(), "day")?);
+        }
+        if let Some(part) = args.get(2) {
+            selection.part = Some(number(part.as_ref(), "part")?);
+        }
+        Ok(selection)
+    }
+
+    pub fn matches(&self, solution: &Solution) -> bool {
+        self.year.map(|y| y == solution.year).unwrap_or(true)
+            && self.day.map(|d| d == solution.day).unwrap_or(true)
+            && self.part.map(|p| p == solution.part).unwrap_or(true)
+    }
+}
+
+fn nanos(v: Duration) -> u64 {
+    v.as_secs() * 1_000_000_000 + (v.subsec_nanos() as u64)
+}
+
+struct Outcome {
+    answer: String,
+    elapsed: Duration,
+    verdict: Verdict,
+    /// Of the input loaded at runtime, if any.
+    fingerprint: Option<u64>,
+}
+
+/// Loads the input of `solution`, exiting if that fails, and returns the
+/// fingerprint of any loaded at runtime.
+fn load(solution: &Solution, inputs: &mut Inputs) -> (Setup, Option<u64>) {
+    match (solution.load)(inputs) {
+        Ok(setup) => (setup, inputs.take_fingerprint()),
+        Err(e) => {
+            eprintln!("{}: {}", solution.name(), e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Runs `solution` `iterations` times on input loaded once, timing only the
+/// runs themselves, and checks the last answer.
+fn run(solution: &Solution, inputs: &mut Inputs, answers: &Answers, iterations: u32) -> Outcome {
+    let (mut setup, fingerprint) = load(solution, inputs);
+    let mut answer = String::new();
+    let mut elapsed = Duration::new(0, 0);
+    for _ in 0..iterations.max(1) {
+        let run = setup();
+        let start = Instant::now();
+        answer = run();
+        elapsed += start.elapsed();
+    }
+    let verdict = answers.check(
+        solution.year,
+        solution.day,
+        solution.part,
+        fingerprint,
+        &answer,
+    );
+    Outcome {
+        answer,
+        elapsed,
+        verdict,
+        fingerprint,
+    }
+}
+
+const USAGE: &str = "usage: aoc <list|run|time|bench|verify> [--json] [YEAR [DAY [PART]]]";
+
+/// Entry point of the `aoc` binary.
+///
+/// `list` prints the selected solutions, and the `excluded` days among
+/// them, `run` prints their answers, `time`
+/// prints how long each took, and `bench` runs each `BENCH_TIMES` times
+/// (default 1000). `verify` reports whether each answer is correct, wrong or
+/// unknown according to `answers.txt`, and fails if any is wrong. Inputs may
+/// be overridden as described on `Inputs`.
+///
+/// With `--json`, `run`, `time` and `bench` instead print one `json_record`
+/// per solution.
+pub fn main(mut solutions: Vec<Solution>, excluded: &[Excluded]) {
+    solutions.sort_by_key(|s| (s.year, s.day, s.part));
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let json = args.iter().any(|a| a == "--json");
+    args.retain(|a| a != "--json");
+    let command = args.get(0).map(|s| s.as_str()).unwrap_or("run");
+    let selection = match Selection::parse(args.get(1..).unwrap_or(&[])) {
+        Ok(selection) => selection,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(1);
+        }
+    };
+    let selected = solutions
+        .iter()
+        .filter(|s| selection.matches(s))
+        .collect::<Vec<_>>();
+    let mut days = selected.iter().map(|s| (s.year, s.day)).collect::<Vec<_>>();
+    days.dedup();
+    let mut inputs = match Inputs::from_env(days.len()) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let answers = Answers::embedded();
+
+    match command {
+        "list" => {
+            for solution in selected {
+                println!("{}", solution.name());
+            }
+            let excluded = excluded.iter().filter(|e| {
+                selection.year.map(|y| y == e.year).unwrap_or(true)
+                    && selection.day.map(|d| d == e.day).unwrap_or(true)
+            });
+            for e in excluded {
+                println!(
+                    "{}::day{:02} is not registered: {}",
+                    e.year, e.day, e.reason
+                );
+            }
+        }
+        "run" => {
+            for solution in selected {
+                let Outcome {
+                    answer,
+                    elapsed,
+                    verdict,
+                    ..
+                } = run(solution, &mut inputs, &answers, 1);
+                if json {
+                    println!(
+                        "{}",
+                        json_record(solution, &answer, &verdict, nanos(elapsed), None)
+                    );
+                    continue;
+                }
+                println!(
+                    "{} = {} [{}] ({}s {}ns or {}s {}ms)",
+                    solution.name(),
+                    answer,
+                    verdict,
+                    elapsed.as_secs(),
+                    elapsed.subsec_nanos(),
+                    elapsed.as_secs(),
+                    elapsed.subsec_nanos() / 1_000_000
+                );
+            }
+        }
+        "time" => {
+            let mut total = Duration::new(0, 0);
+            for solution in selected {
+                let Outcome {
+                    answer,
+                    elapsed,
+                    verdict,
+                    ..
+                } = run(solution, &mut inputs, &answers, 1);
+                total += elapsed;
+                if json {
+                    println!(
+                        "{}",
+                        json_record(solution, &answer, &verdict, nanos(elapsed), None)
+                    );
+                } else {
+                    println!("{} {:10} μs", solution.name(), nanos(elapsed) / 1_000);
+                }
+            }
+            if json {
+                return;
+            }
+            println!("---------------------");
+            println!("Total {:10} μs", nanos(total) / 1_000);
+        }
+        "bench" => {
+            let iters: u32 = env::var("BENCH_TIMES")
+                .ok()
+                .and_then(|x| x.parse().ok())
+                .unwrap_or(1000);
+            for solution in selected {
+                if !json {
+                    println!("benching {} {} times", solution.name(), iters);
+                }
+                let outcome = run(solution, &mut inputs, &answers, iters);
+                let per_iter = nanos(outcome.elapsed) / u64::from(iters.max(1));
+                if json {
+                    println!(
+                        "{}",
+                        json_record(
+                            solution,
+                            &outcome.answer,
+                            &outcome.verdict,
+                            per_iter,
+                            Some(iters)
+                        )
+                    );
+                } else {
+                    println!("{} {:10} ns/iter", solution.name(), per_iter);
+                }
+            }
+        }
+        "verify" => {
+            let (mut correct, mut wrong, mut unknown) = (0, 0, 0);
+            for solution in selected {
+                let outcome = run(solution, &mut inputs, &answers, 1);
+                if json {
+                    println!(
+                        "{}",
+                        json_record(
+                            solution,
+                            &outcome.answer,
+                            &outcome.verdict,
+                            nanos(outcome.elapsed),
+                            None
+                        )
+                    );
+                }
+                match outcome.verdict {
+                    Verdict::Correct => correct += 1,
+                    Verdict::Wrong(_) => wrong += 1,
+                    Verdict::Unknown => unknown += 1,
+                }
+                if json {
+                    continue;
+                }
+                match (&outcome.verdict, outcome.fingerprint) {
+                    (Verdict::Correct, _) => println!("{}: correct", solution.name()),
+                    (Verdict::Wrong(expected), _) => println!(
+                        "{}: wrong, got {} but expected {}",
+                        solution.name(),
+                        outcome.answer,
+                        expected
+                    ),
+                    (Verdict::Unknown, None) => println!("{}: unknown", solution.name()),
+                    (Verdict::Unknown, Some(fingerprint)) => {
+                        println!("{}: unknown (input {:016x})", solution.name(), fingerprint)
+                    }
+                }
+            }
+            if !json {
+                println!("---------------------");
+                println!("{} correct, {} wrong, {} unknown", correct, wrong, unknown);
+            }
+            if wrong > 0 {
+                process::exit(1);
+            }
+        }
+        other => {
+            eprintln!("unknown command {:?}\n{}", other, USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+/// Declares the given day modules and a `solutions` function registering
+/// both parts of each with the `aoc` runner.
+#[macro_export]
+macro_rules! solutions {
+    ($year:expr; $($day:ident),+$(,)*) => {
+        $(
+            pub mod $day;
+        )+
+
+        pub fn solutions() -> Vec<$crate::runner::Solution> {
+            let mut solutions = Vec::new();
+            $(
+                let day = stringify!($day)[3..].parse().unwrap();
+                solutions.push($crate::runner::Solution {
+                    year: $year,
+                    day,
+                    part: 1,
+                    load: Box::new(|inputs: &mut $crate::input::Inputs| {
+                        let input = inputs.load(stringify!($day), $day::INPUT)?;
+                        Ok(Box::new(move || {
+                            Box::new(move || $day::part1(input).to_string()) as $crate::runner::Answer
+                        }))
+                    }),
+                });
+                solutions.push($crate::runner::Solution {
+                    year: $year,
+                    day,
+                    part: 2,
+                    load: Box::new(|inputs: &mut $crate::input::Inputs| {
+                        let input = inputs.load(stringify!($day), $day::INPUT)?;
+                        Ok(Box::new(move || {
+                            Box::new(move || $day::part2(input).to_string()) as $crate::runner::Answer
+                        }))
+                    }),
+                });
+            )+
+            solutions
+        }
+    };
+}
+
+#[test]
+fn selection_parse() {
+    assert_eq!(
+        Selection::parse(&["2016", "day05", "2"]),
+        Ok(Selection {
+            year: Some(2016),
+            day: Some(5),
+            part: Some(2),
+        })
+    );
+    assert_eq!(
+        Selection::parse(&["2018", "7"]),
+        Ok(Selection {
+            year: Some(2018),
+            day: Some(7),
+            part: None,
+        })
+    );
+    assert_eq!(Selection::parse::<&str>(&[]), Ok(Selection::default()));
+    assert!(Selection::parse(&["2016", "dayx"]).is_err());
+}
+
+#[test]
+fn json_record_escapes() {
+    let solution = Solution {
+        year: 2016,
+        day: 2,
+        part: 1,
+        load: Box::new(|_| Ok(Box::new(|| Box::new(String::new) as Answer))),
+    };
+    assert_eq!(
+        json_record(&solution, "a\"b", &Verdict::Correct, 12, None),
+        r#"{"year":2016,"day":2,"part":1,"answer":"a\"b","expected":"a\"b","pass":true,"elapsed_ns":12}"#
+    );
+    assert_eq!(
+        json_record(&solution, "1", &Verdict::Unknown, 12, None),
+        r#"{"year":2016,"day":2,"part":1,"answer":"1","expected":null,"pass":null,"elapsed_ns":12}"#
+    );
+    assert_eq!(
+        json_record(
+            &solution,
+            "x\n",
+            &Verdict::Wrong(String::from("a\"b")),
+            12,
+            Some(3)
+        ),
+        r#"{"year":2016,"day":2,"part":1,"answer":"x\n","expected":"a\"b","pass":false,"elapsed_ns":12,"iterations":3}"#
+    );
+}
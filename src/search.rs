@@ -0,0 +1,594 @@
+//! Searches over implicit graphs: the states are any hashable values, and a
+//! successor function lists the states one step on from each.
+//!
+//! Each search keeps the cheapest cost of every state it reaches and the
+//! state it reached it from, so the path to any of them can be rebuilt
+//! afterwards. The `_all` variants keep every such state instead of the
+//! first, so that all the cheapest paths can be.
+
+use fnv::{FnvHashMap, FnvHashSet};
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::ops::Add;
+
+/// How much work a search did.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were listed.
+    pub expanded: usize,
+    /// Distinct states reached, including the start.
+    pub reached: usize,
+}
+
+struct Node<S, C> {
+    cost: C,
+    parents: SmallVec<[S; 1]>,
+}
+
+/// The states a search reached and how it reached them.
+pub struct Search<S: Hash + Eq, C> {
+    /// The goal state the search stopped at, if it found one.
+    pub goal: Option<S>,
+    start: S,
+    nodes: FnvHashMap<S, Node<S, C>>,
+    expanded: usize,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        let mut nodes = FnvHashMap::default();
+        let node = Node {
+            cost: zero,
+            parents: SmallVec::new(),
+        };
+        nodes.insert(start.clone(), node);
+        Search {
+            goal: None,
+            start,
+            nodes,
+            expanded: 0,
+        }
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            expanded: self.expanded,
+            reached: self.nodes.len(),
+        }
+    }
+
+    /// The cheapest cost of reaching `state`, if the search did; only final
+    /// for states it expanded, and the goal.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.nodes.get(state).map(|n| n.cost)
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|g| self.cost(g))
+    }
+
+    /// Every state reached, with its cost.
+    pub fn reached<'a>(&'a self) -> impl Iterator<Item = (&'a S, C)> + 'a {
+        self.nodes.iter().map(|(s, n)| (s, n.cost))
+    }
+
+    /// The states `state` was reached from at its cheapest cost: one, or all
+    /// of them in an `_all` search.
+    pub fn parents(&self, state: &S) -> &[S] {
+        self.nodes.get(state).map_or(&[], |n| &n.parents[..])
+    }
+
+    /// A cheapest path from the start to `to`, both included.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        self.nodes.get(to)?;
+        let mut path = vec![to.clone()];
+        while let Some(parent) = self.parents(path.last().unwrap()).first() {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Every cheapest path from the start to `to`, from an `_all` search;
+    /// there may be exponentially many.
+    pub fn paths(&self, to: &S) -> Vec<Vec<S>> {
+        if !self.nodes.contains_key(to) {
+            return Vec::new();
+        }
+        let parents = self.parents(to);
+        if parents.is_empty() {
+            return vec![vec![to.clone()]];
+        }
+        let mut paths = Vec::new();
+        for parent in parents {
+            for mut path in self.paths(parent) {
+                path.push(to.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// The states after the start on every cheapest path to `to`, from an
+    /// `_all` search, without listing the paths.
+    pub fn first_steps(&self, to: &S) -> Vec<S> {
+        let mut seen = FnvHashSet::default();
+        let mut stack = vec![to.clone()];
+        let mut steps = Vec::new();
+        while let Some(state) = stack.pop() {
+            for parent in self.parents(&state) {
+                if *parent == self.start {
+                    steps.push(state.clone());
+                } else if seen.insert(parent.clone()) {
+                    stack.push(parent.clone());
+                }
+            }
+        }
+        steps
+    }
+}
+
+fn breadth_first<S, F, I, G>(
+    start: S,
+    mut successors: F,
+    mut goal: G,
+    limit: usize,
+    all: bool,
+) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        if cost == limit {
+            continue;
+        }
+        search.expanded += 1;
+        for next in successors(&state) {
+            match search.nodes.entry(next) {
+                Entry::Vacant(entry) => {
+                    queue.push_back((entry.key().clone(), cost + 1));
+                    let mut parents = SmallVec::new();
+                    parents.push(state.clone());
+                    entry.insert(Node {
+                        cost: cost + 1,
+                        parents,
+                    });
+                }
+                Entry::Occupied(mut entry) => {
+                    if all && entry.get().cost == cost + 1 {
+                        entry.get_mut().parents.push(state.clone());
+                    }
+                }
+            }
+        }
+    }
+    search
+}
+
+/// Searches outwards from `start` until it reaches a state that is a `goal`.
+pub fn bfs<S, F, I, G>(start: S, successors: F, goal: G) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(start, successors, goal, usize::max_value(), false)
+}
+
+pub fn bfs_all<S, F, I, G>(start: S, successors: F, goal: G) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(start, successors, goal, usize::max_value(), true)
+}
+
+/// Reaches every state at most `limit` steps from `start`.
+pub fn bfs_within<S, F, I>(start: S, limit: usize, successors: F) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, |_| false, limit, false)
+}
+
+/// A state waiting in the queue, ordered so the cheapest comes out first.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+fn best_first<S, C, F, I, H, G>(
+    start: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut goal: G,
+    all: bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::new();
+    queue.push(Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    });
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if cost > search.nodes[&state].cost {
+            // Queued again since, at a lower cost.
+            continue;
+        }
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        search.expanded += 1;
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let queue_next = match search.nodes.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next = entry.key().clone();
+                    let mut parents = SmallVec::new();
+                    parents.push(state.clone());
+                    entry.insert(Node {
+                        cost: next_cost,
+                        parents,
+                    });
+                    Some(next)
+                }
+                Entry::Occupied(mut entry) => {
+                    let node = entry.get_mut();
+                    if next_cost < node.cost {
+                        node.cost = next_cost;
+                        node.parents.clear();
+                        node.parents.push(state.clone());
+                        Some(entry.key().clone())
+                    } else {
+                        // A free step may lead back to the start or another
+                        // state on the way to `state`, making a cycle of
+                        // parents, so only one that costs something counts.
+                        if all && next_cost == node.cost && step > C::default() {
+                            node.parents.push(state.clone());
+                        }
+                        None
+                    }
+                }
+            };
+            if let Some(next) = queue_next {
+                queue.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+/// Searches for the cheapest path to a `goal`, where `successors` gives the
+/// cost of each step.
+pub fn dijkstra<S, C, F, I, G>(start: S, successors: F, goal: G) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, successors, |_| C::default(), goal, false)
+}
+
+pub fn dijkstra_all<S, C, F, I, G>(start: S, successors: F, goal: G) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, successors, |_| C::default(), goal, true)
+}
+
+/// Like `dijkstra`, but tries first the states that `heuristic` estimates
+/// are closest to a goal. The estimate must never be more than the actual
+/// cost, or the path found may not be the cheapest.
+pub fn astar<S, C, F, I, H, G>(start: S, successors: F, heuristic: H, goal: G) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, successors, heuristic, goal, false)
+}
+
+/// Searches outwards from both `start` and `goal` at once, a step at a
+/// time from whichever has fewer states at its edge, until they meet;
+/// `predecessors` lists the states one step before each. Returns a shortest
+/// path, if there is one.
+pub fn bidirectional<S, F, I, P, J>(
+    start: S,
+    goal: S,
+    mut successors: F,
+    mut predecessors: P,
+) -> (Option<Vec<S>>, Stats)
+where
+    S: Hash + Eq + Clone,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    P: FnMut(&S) -> J,
+    J: IntoIterator<Item = S>,
+{
+    // The distance to each state from the side that reached it, and the
+    // state before it on that side.
+    let mut forward = FnvHashMap::default();
+    let mut backward = FnvHashMap::default();
+    forward.insert(start.clone(), (0, None));
+    backward.insert(goal.clone(), (0, None));
+    let mut edges = (vec![start.clone()], vec![goal.clone()]);
+    let mut stats = Stats::default();
+    let mut meeting = if start == goal { Some(start) } else { None };
+    while meeting.is_none() && !edges.0.is_empty() && !edges.1.is_empty() {
+        let forwards = edges.0.len() <= edges.1.len();
+        let (edge, this, other) = if forwards {
+            (&mut edges.0, &mut forward, &backward)
+        } else {
+            (&mut edges.1, &mut backward, &forward)
+        };
+        let mut best: Option<(usize, S)> = None;
+        for state in ::std::mem::replace(edge, Vec::new()) {
+            stats.expanded += 1;
+            let distance = this[&state].0 + 1;
+            let next_states = if forwards {
+                successors(&state).into_iter().collect::<Vec<_>>()
+            } else {
+                predecessors(&state).into_iter().collect()
+            };
+            for next in next_states {
+                if this.contains_key(&next) {
+                    continue;
+                }
+                this.insert(next.clone(), (distance, Some(state.clone())));
+                if let Some(&(rest, _)) = other.get(&next) {
+                    if best.as_ref().map_or(true, |b| rest < b.0) {
+                        best = Some((rest, next.clone()));
+                    }
+                }
+                edge.push(next);
+            }
+        }
+        meeting = best.map(|(_, state)| state);
+    }
+    stats.reached = forward.len() + backward.len();
+    let meeting = match meeting {
+        Some(meeting) => meeting,
+        None => return (None, stats),
+    };
+    let walk = |side: &FnvHashMap<S, (usize, Option<S>)>| {
+        let mut path = vec![];
+        let mut at = side[&meeting].1.clone();
+        while let Some(state) = at {
+            at = side[&state].1.clone();
+            path.push(state);
+        }
+        path
+    };
+    let mut path = walk(&forward);
+    path.reverse();
+    path.push(meeting.clone());
+    path.extend(walk(&backward));
+    (Some(path), stats)
+}
+
+/// States that may be interchangeable with others, such as those that only
+/// differ by swapping two identical parts. A search over `Reduced` states
+/// reaches one of each class of interchangeable states, which is enough when
+/// the successors and goals of interchangeable states are too.
+pub trait Symmetric {
+    /// The same for exactly the states that are interchangeable.
+    type Canonical: Hash + Eq;
+
+    fn canonical(&self) -> Self::Canonical;
+}
+
+/// A state that hashes and compares by its canonical form.
+#[derive(Debug)]
+pub struct Reduced<S: Symmetric> {
+    pub state: S,
+    canonical: S::Canonical,
+}
+
+impl<S: Symmetric> Reduced<S> {
+    pub fn new(state: S) -> Reduced<S> {
+        let canonical = state.canonical();
+        Reduced { state, canonical }
+    }
+}
+
+impl<S: Symmetric + Clone> Clone for Reduced<S>
+where
+    S::Canonical: Clone,
+{
+    fn clone(&self) -> Self {
+        Reduced {
+            state: self.state.clone(),
+            canonical: self.canonical.clone(),
+        }
+    }
+}
+
+impl<S: Symmetric> PartialEq for Reduced<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical == other.canonical
+    }
+}
+
+impl<S: Symmetric> Eq for Reduced<S> {}
+
+impl<S: Symmetric> Hash for Reduced<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical.hash(state);
+    }
+}
+
+#[cfg(test)]
+fn grid_moves(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+    // An 8x8 grid with a wall along x = 3, open at y = 7.
+    vec![(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+        .into_iter()
+        .filter(|&(x, y)| x >= 0 && y >= 0 && x < 8 && y < 8 && (x != 3 || y == 7))
+        .collect()
+}
+
+#[test]
+fn search_bfs() {
+    let search = bfs((0, 0), grid_moves, |&p| p == (6, 0));
+    assert_eq!(search.goal_cost(), Some(20));
+    let path = search.goal_path().unwrap();
+    assert_eq!(path.len(), 21);
+    assert!(path.windows(2).all(|w| grid_moves(&w[0]).contains(&w[1])));
+    assert!(search.stats().expanded < search.stats().reached);
+
+    let search = bfs_within((0, 0), 2, grid_moves);
+    assert_eq!(search.reached().count(), 6);
+    assert_eq!(search.goal, None);
+
+    let search = bfs_all((0, 0), grid_moves, |&p| p == (2, 2));
+    assert_eq!(search.paths(&(2, 2)).len(), 6);
+    let mut steps = search.first_steps(&(2, 2));
+    steps.sort();
+    assert_eq!(steps, [(0, 1), (1, 0)]);
+}
+
+#[test]
+fn search_weighted() {
+    // Stepping right costs 1, and every other step 3.
+    let moves = |p: &(i32, i32)| {
+        grid_moves(p)
+            .into_iter()
+            .map(|n| (n, if n.0 > p.0 { 1 } else { 3 }))
+            .collect::<Vec<_>>()
+    };
+    let search = dijkstra((0, 0), moves, |&p| p == (7, 7));
+    assert_eq!(search.goal_cost(), Some(7 + 3 * 7));
+    let guess = |&(x, y): &(i32, i32)| (7 - x) + 3 * (7 - y);
+    let guided = astar((0, 0), moves, guess, |&p| p == (7, 7));
+    assert_eq!(guided.goal_cost(), Some(28));
+    assert!(guided.stats().expanded < search.stats().expanded);
+
+    let search = dijkstra_all((0, 0), moves, |&p| p == (2, 1));
+    assert_eq!(search.goal_cost(), Some(5));
+    assert_eq!(search.paths(&(2, 1)).len(), 3);
+
+    // Stepping back along a free step reaches the start again at its own
+    // cost, which must not make it a parent.
+    let free = |&s: &u32| match s {
+        0 => vec![(1, 0)],
+        1 => vec![(0, 0), (2, 1)],
+        _ => vec![],
+    };
+    let search = dijkstra_all(0, free, |&s| s == 2);
+    assert_eq!(search.parents(&0), &[] as &[u32]);
+    assert_eq!(search.parents(&1), [0]);
+    assert_eq!(search.goal_path(), Some(vec![0, 1, 2]));
+    assert_eq!(search.paths(&2), [vec![0, 1, 2]]);
+}
+
+#[test]
+fn search_bidirectional() {
+    let (path, stats) = bidirectional((0, 0), (6, 0), grid_moves, grid_moves);
+    let path = path.unwrap();
+    assert_eq!(path.len(), 21);
+    assert_eq!((path[0], path[20]), ((0, 0), (6, 0)));
+    assert!(path.windows(2).all(|w| grid_moves(&w[0]).contains(&w[1])));
+    assert!(stats.reached > 0);
+
+    let (path, _) = bidirectional((0, 0), (0, 0), grid_moves, grid_moves);
+    assert_eq!(path, Some(vec![(0, 0)]));
+    let (path, _) = bidirectional((0, 0), (9, 9), grid_moves, grid_moves);
+    assert_eq!(path, None);
+}
+
+#[test]
+fn search_reduced() {
+    // Two counters that each step up by one, where only how far the pair has
+    // got matters and not which counter is which.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Counters(u32, u32);
+
+    impl Symmetric for Counters {
+        type Canonical = (u32, u32);
+
+        fn canonical(&self) -> (u32, u32) {
+            (self.0.min(self.1), self.0.max(self.1))
+        }
+    }
+
+    let step = |&Counters(a, b): &Counters| vec![Counters(a + 1, b), Counters(a, b + 1)];
+    let search = bfs_within(Counters(0, 0), 3, step);
+    assert_eq!(search.stats().reached, 10);
+
+    let search = bfs_within(Reduced::new(Counters(0, 0)), 3, |c: &Reduced<Counters>| {
+        step(&c.state).into_iter().map(Reduced::new)
+    });
+    assert_eq!(search.stats().reached, 6);
+    let goal = |c: &Reduced<Counters>| c.state.0 + c.state.1 == 3;
+    let search = bfs(
+        Reduced::new(Counters(0, 0)),
+        |c: &Reduced<Counters>| step(&c.state).into_iter().map(Reduced::new),
+        goal,
+    );
+    assert_eq!(search.goal_cost(), Some(3));
+}
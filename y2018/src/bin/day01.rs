@@ -13,8 +13,7 @@ fn generator(input: &str) -> Vec<i32> {
 
 #[solution(part1,
     example_input = vec![1, 1, 1],
-    example = 3,
-    expect = 520)]
+    example = 3)]
 fn part1(input: Vec<i32>) -> i32 {
     input.into_iter().sum()
 }
@@ -37,8 +36,7 @@ struct Candidate {
 
 #[solution(part2,
     example_input = vec![3, 3, 4, -2, -4],
-    example = 10,
-    expect = 394)]
+    example = 10)]
 fn part2(input: Vec<i32>) -> i32 {
     let shift: i32 = input.iter().sum();
 
@@ -9,8 +9,7 @@ fn generator(input: &str) -> &str {
 
 #[solution(part1,
     example_input = &["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"].join("\n"),
-    example = 12,
-    expect = 8398)]
+    example = 12)]
 fn part1<'a>(input: &str) -> usize {
     let mut twice = 0;
     let mut three = 0;
@@ -37,8 +36,7 @@ wvxyz
 
 #[solution(part2,
     example_input = generator(EXAMPLE_2),
-    example = "fgij",
-    expect = "hhvsdkatysmiqjxunezgwcdpr")]
+    example = "fgij")]
 fn part2<'a>(input: &str) -> String {
     for line_a in input.lines() {
         'line_b: for line_b in input.lines() {
@@ -1,11 +1,12 @@
+use advent_of_code::{parse_lines, Pattern};
 use aoc_macro::{generator, solution};
 use bitvec::{BitVec, LittleEndian};
-use regex::Regex;
 use std::num::NonZeroU16;
 
 aoc_macro::day!();
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Pattern)]
+#[pattern = "#{id} @ {left},{top}: {width}x{height}"]
 struct Claim {
     id: NonZeroU16,
     left: u16,
@@ -16,20 +17,9 @@ struct Claim {
 
 #[generator]
 fn generator(input: &str) -> Vec<Claim> {
-    let r = Regex::new(r"#(\d+) @ (\d+),(\d+): (\d+)x(\d+)").unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            let c = r.captures(&line).unwrap();
-            Claim {
-                id: NonZeroU16::new(c[1].parse().unwrap()).unwrap(),
-                left: c[2].parse().unwrap(),
-                top: c[3].parse().unwrap(),
-                width: c[4].parse().unwrap(),
-                height: c[5].parse().unwrap(),
-            }
-        })
+    parse_lines::<Claim>(input)
+        .unwrap_or_else(|e| panic!("{}", e.render(input)))
+        .into_iter()
         .map(|c| Claim {
             id: c.id,
             left: c.left + 1,
@@ -45,8 +35,7 @@ fn generator(input: &str) -> Vec<Claim> {
     #1 @ 1,3: 4x4
     #2 @ 3,1: 4x4
     #3 @ 5,5: 2x2"),
-    example = 4,
-    expect = 113716)]
+    example = 4)]
 fn part1(input: Vec<Claim>) -> usize {
     let area_width = input.iter().map(|c| c.left + c.width).max().unwrap() as usize + 1;
     let area_height = input.iter().map(|c| c.top + c.height).max().unwrap() as usize + 1;
@@ -68,8 +57,7 @@ fn part1(input: Vec<Claim>) -> usize {
     #1 @ 1,3: 4x4
     #2 @ 3,1: 4x4
     #3 @ 5,5: 2x2"),
-    example = 3,
-    expect = 742)]
+    example = 3)]
 fn part2(input: Vec<Claim>) -> u16 {
     let area_width = input.iter().map(|c| c.left + c.width).max().unwrap() as usize + 1;
     let area_height = input.iter().map(|c| c.top + c.height).max().unwrap() as usize + 1;
@@ -1,20 +1,23 @@
+use advent_of_code::{parse_lines, Pattern};
 use aoc_macro::{generator, solution};
-use regex::Regex;
 
 aoc_macro::day!();
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Pattern)]
 enum Event {
+    #[pattern = "wakes up"]
     Wake,
+    #[pattern = "falls asleep"]
     Asleep,
+    #[pattern = "Guard #{id} begins shift"]
     BeginShift { id: u16 },
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Pattern)]
+#[pattern = "[1518-{month}-{day} {hour}:{minute}] {event}"]
 struct Record {
-    // month and date
-    date: (u8, u8),
-    // minute
+    month: u8,
+    day: u8,
     hour: u8,
     minute: u8,
     event: Event,
@@ -22,35 +25,7 @@ struct Record {
 
 #[generator]
 fn generator(input: &str) -> Vec<Record> {
-    let datetime = Regex::new(r"\[1518-(\d\d)-(\d\d) (\d\d):(\d\d)\]").unwrap();
-    let guard = Regex::new(r"Guard #(\d+) begins shift").unwrap();
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            let dtc = datetime.captures(&line).unwrap();
-
-            let mut base = Record {
-                date: (dtc[1].parse().unwrap(), dtc[2].parse().unwrap()),
-                hour: dtc[3].parse().unwrap(),
-                minute: dtc[4].parse().unwrap(),
-                event: Event::Wake,
-            };
-
-            if let Some(gc) = guard.captures(&line) {
-                base.event = Event::BeginShift {
-                    id: gc[1].parse().unwrap(),
-                };
-            } else if line.contains("falls asleep") {
-                base.event = Event::Asleep;
-            } else {
-                assert!(line.contains("wakes up"));
-                base.event = Event::Wake;
-            }
-
-            base
-        })
-        .collect()
+    parse_lines(input).unwrap_or_else(|e| panic!("{}", e.render(input)))
 }
 
 static EXAMPLE: &str = "
@@ -75,10 +50,9 @@ static EXAMPLE: &str = "
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 240,
-    expect = 14346)]
+    example = 240)]
 fn part1(mut input: Vec<Record>) -> usize {
-    input.sort_by_key(|r| (r.date, r.hour, r.minute));
+    input.sort_by_key(|r| (r.month, r.day, r.hour, r.minute));
 
     let mut guards: fnv::FnvHashMap<u16, (u16, [u16; 60])> = fnv::FnvHashMap::default();
     let mut current_guard: Option<u16> = None;
@@ -111,10 +85,9 @@ fn part1(mut input: Vec<Record>) -> usize {
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 4455,
-    expect = 5705)]
+    example = 4455)]
 fn part2(mut input: Vec<Record>) -> usize {
-    input.sort_by_key(|r| (r.date, r.hour, r.minute));
+    input.sort_by_key(|r| (r.month, r.day, r.hour, r.minute));
 
     let mut minutes = vec![fnv::FnvHashMap::default(); 60];
     let mut guards: fnv::FnvHashMap<u16, [u16; 60]> = fnv::FnvHashMap::default();
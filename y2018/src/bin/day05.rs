@@ -40,17 +40,12 @@ fn react(mut input: String) -> String {
     input
 }
 
-#[solution(
-    part1,
-    example_input = "dabAcCaCBAcCcaDA",
-    example = 10,
-    expect = 10598
-)]
+#[solution(part1, example_input = "dabAcCaCBAcCcaDA", example = 10)]
 fn part1(input: &str) -> usize {
     react(input.to_string()).len()
 }
 
-#[solution(part2, example_input = "dabAcCaCBAcCcaDA", example = 4, expect = 5312)]
+#[solution(part2, example_input = "dabAcCaCBAcCcaDA", example = 4)]
 fn part2(input: &str) -> usize {
     let mut best = input.len();
     for unit in b'a'..=b'z' {
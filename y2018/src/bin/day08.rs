@@ -71,8 +71,7 @@ impl Node {
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 138,
-    expect = 47244)]
+    example = 138)]
 fn part1(input: Out) -> u32 {
     let mut input = Buffer { v: &input[..] };
     let mut sum = 0;
@@ -90,8 +89,7 @@ fn part1(input: Out) -> u32 {
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 66,
-    expect = 17267)]
+    example = 66)]
 fn part2(input: Out) -> u32 {
     let mut input = Buffer { v: &input[..] };
     let node = Node::parse(&mut input);
@@ -96,8 +96,7 @@ impl fmt::Debug for Circle {
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 32,
-    expect = 428690)]
+    example = 32)]
 fn part1((players, marbles): Out) -> u32 {
     let mut elfs: Vec<u32> = vec![0; players as usize];
     let mut circle = Circle::with_capacity(marbles as usize);
@@ -38,16 +38,14 @@ fn power_square(size: u32, serial: u32) -> (i32, (u32, u32)) {
 
 #[solution(part1,
     example_input = 18,
-    example = (33, 45),
-    expect = (33,54))]
+    example = (33, 45))]
 fn part1(serial: u32) -> (u32, u32) {
     power_square(3, serial).1
 }
 
 #[solution(part2,
     example_input = 18,
-    example = (90, 269, 16),
-    expect = (232, 289, 8))]
+    example = (90, 269, 16))]
 fn part2(serial: u32) -> (u32, u32, u32) {
     let mut max = 0;
     let mut at = (0, 0, 0);
@@ -1,3 +1,4 @@
+use advent_of_code::geometry::{Direction, Point};
 use aoc_macro::{generator, solution};
 use fnv::FnvHashMap as HashMap;
 use fnv::FnvHashSet as HashSet;
@@ -8,52 +9,18 @@ aoc_macro::day!();
 
 type Out = State;
 
-#[derive(Copy, Clone, Debug)]
-enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
-}
-
-impl Direction {
-    fn turn_left(self) -> Direction {
-        match self {
-            Direction::Right => Direction::Up,
-            Direction::Up => Direction::Left,
-            Direction::Left => Direction::Down,
-            Direction::Down => Direction::Right,
-        }
-    }
-
-    fn turn_right(self) -> Direction {
-        match self {
-            Direction::Right => Direction::Down,
-            Direction::Up => Direction::Right,
-            Direction::Left => Direction::Up,
-            Direction::Down => Direction::Left,
-        }
-    }
-}
-
 struct State {
-    corners: HashMap<CartPosition, Corner>,
-    intersections: HashSet<CartPosition>,
+    corners: HashMap<Point, Corner>,
+    intersections: HashSet<Point>,
     carts: CartMap,
 }
 
+/// Carts by position, which orders them by row and then column as they move.
 #[derive(Default)]
-struct CartMap(BTreeMap<CartPosition, Cart>);
-
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct CartPosition {
-    // note that y must be first because they must be ordered by row and then column
-    y: usize,
-    x: usize,
-}
+struct CartMap(BTreeMap<Point, Cart>);
 
 impl CartMap {
-    fn insert(&mut self, position: CartPosition, facing: Direction) {
+    fn insert(&mut self, position: Point, facing: Direction) {
         self.0.insert(
             position,
             Cart {
@@ -88,7 +55,7 @@ impl ops::Index<Direction> for Corner {
 
 #[derive(Copy, Clone, Debug)]
 struct Cart {
-    position: CartPosition,
+    position: Point,
     facing: Direction,
     turn: u8,
 }
@@ -110,10 +77,7 @@ fn generator(input: &str) -> Out {
                 continue;
             }
 
-            let cur = CartPosition {
-                x: ch_idx,
-                y: line_idx,
-            };
+            let cur = Point::from_index(line_idx, ch_idx);
             match ch {
                 '-' | '>' | '<' => {}
                 '|' | '^' | 'v' => {}
@@ -146,10 +110,7 @@ fn generator(input: &str) -> Out {
             }
 
             let dir = match ch {
-                '>' => Direction::Right,
-                '<' => Direction::Left,
-                '^' => Direction::Up,
-                'v' => Direction::Down,
+                '>' | '<' | '^' | 'v' => Direction::from_char(ch).unwrap(),
                 _ => continue,
             };
             carts.insert(cur, dir);
@@ -182,22 +143,14 @@ impl Cart {
             self.turn += 1;
         }
 
-        let CartPosition { x, y } = self.position;
-        let (nx, ny) = match self.facing {
-            Direction::Down => (x, y + 1),
-            Direction::Up => (x, y - 1),
-            Direction::Left => (x - 1, y),
-            Direction::Right => (x + 1, y),
-        };
-        self.position = CartPosition { x: nx, y: ny };
+        self.position += self.facing;
     }
 }
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = (7, 3),
-    expect = (91, 69))]
-fn part1(mut input: State) -> (usize, usize) {
+    example = (7, 3))]
+fn part1(mut input: State) -> (isize, isize) {
     loop {
         let map = std::mem::replace(&mut input.carts.0, BTreeMap::new());
         let mut previous = map.keys().cloned().collect::<HashSet<_>>();
@@ -219,9 +172,8 @@ fn part1(mut input: State) -> (usize, usize) {
 
 #[solution(part2,
     example_input = generator(EXAMPLE_2),
-    example = (6, 4),
-    expect = (44, 87))]
-fn part2(mut input: Out) -> (usize, usize) {
+    example = (6, 4))]
+fn part2(mut input: Out) -> (isize, isize) {
     loop {
         let map = std::mem::replace(&mut input.carts.0, BTreeMap::new());
         let mut previous = map.keys().cloned().collect::<HashSet<_>>();
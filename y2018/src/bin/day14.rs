@@ -38,8 +38,7 @@ sol_test!(recombine_2: recombine(&[5, 6, 2, 3]), 5623);
 
 #[solution(part1,
     example_input = &[9],
-    example = [5, 1, 5, 8, 9, 1, 6, 7, 7, 9],
-    expect = [6, 1, 0, 7, 1, 0, 1, 5, 4, 4])]
+    example = [5, 1, 5, 8, 9, 1, 6, 7, 7, 9])]
 fn part1(count: &[usize]) -> [u8; 10] {
     let count = recombine(&count);
     let mut recipes: Vec<u8> = vec![3, 7];
@@ -63,8 +62,7 @@ sol_test!(part1_3: part1(&[2, 0, 1, 8]), [5, 9, 4, 1, 4, 2, 9, 8, 8, 2]);
 
 #[solution(part2,
     example_input = &[5, 1, 5, 8, 9],
-    example = 9,
-    expect = 20291131)]
+    example = 9)]
 fn part2(input: &[usize]) -> usize {
     let mut recipes: Vec<u8> = vec![3, 7];
     let mut a = 0;
@@ -1,56 +1,12 @@
+use advent_of_code::geometry::Point;
+use advent_of_code::search;
 use aoc_macro::{generator, sol_test, solution};
-use hashbrown::{HashMap, HashSet};
-use petgraph::algo::astar;
-use petgraph::graph::NodeIndex;
-use petgraph::visit::Bfs;
-use petgraph::Graph;
-use std::cmp::{self, Ordering};
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 
 aoc_macro::day!();
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Position {
-    y: usize,
-    x: usize,
-}
-
-impl fmt::Debug for Position {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
-impl Position {
-    fn up(self) -> Position {
-        let y = self.y.checked_sub(1).unwrap();
-        Position { y, x: self.x }
-    }
-    fn down(self) -> Position {
-        Position {
-            x: self.x,
-            y: self.y + 1,
-        }
-    }
-    fn left(self) -> Position {
-        Position {
-            x: self.x.checked_sub(1).unwrap(),
-            y: self.y,
-        }
-    }
-    fn right(self) -> Position {
-        Position {
-            x: self.x + 1,
-            y: self.y,
-        }
-    }
-
-    fn cross(self) -> [Position; 4] {
-        [self.up(), self.left(), self.right(), self.down()]
-    }
-}
-
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Cell {
     Goblin(usize, bool),
@@ -127,7 +83,7 @@ impl Cell {
 
 #[derive(Clone)]
 struct State {
-    cells: BTreeMap<Position, Cell>,
+    cells: BTreeMap<Point, Cell>,
     elf_power: usize,
 }
 
@@ -162,15 +118,15 @@ impl fmt::Debug for State {
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 enum Candidate {
     Path {
-        from: Position,
-        to: Position,
-        enemy: Position,
+        from: Point,
+        to: Point,
+        enemy: Point,
         distance: usize,
-        next: Position,
+        next: Point,
     },
     Immediate {
-        us: Position,
-        enemy: Position,
+        us: Point,
+        enemy: Point,
     },
 }
 
@@ -188,7 +144,7 @@ impl Ord for Candidate {
                 Candidate::Immediate { us: u2, enemy: e2 },
             ) => {
                 assert_eq!(u1, u2);
-                for pos in &u1.cross() {
+                for pos in &u1.neighbours() {
                     if pos == e1 {
                         return Ordering::Less;
                     } else if pos == e2 {
@@ -223,109 +179,28 @@ impl Ord for Candidate {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-struct Path {
-    length: usize,
-    next: Option<NodeIndex>,
-}
-
-impl Path {
-    fn new(_nx: NodeIndex) -> Path {
-        Path {
-            length: 1,
-            next: None,
-        }
-    }
-
-    fn len(&self) -> usize {
-        self.length
-    }
-
-    fn next(&self) -> NodeIndex {
-        self.next.unwrap()
-    }
-
-    fn push(&mut self, nx: NodeIndex) {
-        self.length += 1;
-        if self.length == 2 {
-            assert_eq!(self.next, None);
-            self.next = Some(nx);
-        }
-    }
-}
-
-fn path_length(
-    cells: &BTreeMap<Position, Cell>,
-    from: Position,
-    to: Position,
-) -> Vec<(usize, Position)> {
-    let mut g = Graph::new_undirected();
-    let mut map = HashMap::new();
-    for (&pos, &cell) in cells {
-        if !cell.is_empty() && pos != from {
-            continue;
-        }
-        let cur = *map.entry(pos).or_insert_with(|| g.add_node((pos, cell)));
-        for &cross_pos in pos.cross().iter() {
-            if !cells[&cross_pos].is_empty() {
-                continue;
-            }
-            let by = *map
-                .entry(cross_pos)
-                .or_insert_with(|| g.add_node((cross_pos, cells[&cross_pos])));
-            g.update_edge(cur, by, ());
-        }
-    }
-    if !map.contains_key(&to) {
-        return Vec::new();
-    }
-
-    let from_idx = map[&from];
-    let to_idx = map[&to];
-    let mut bfs = Bfs::new(&g, from_idx);
-    let mut paths: HashMap<NodeIndex, HashSet<Path>> = HashMap::default();
-    let mut first = HashSet::new();
-    first.insert(Path::new(from_idx));
-    paths.insert(from_idx, first);
-    let mut min_length = astar(&g, from_idx, |i| i == to_idx, |_| 1, |_| 0)
-        .map_or(usize::max_value(), |p| p.1.len());
-    while let Some(nx) = bfs.next(&g) {
-        for neighbor in g.neighbors(nx) {
-            let cur = &paths[&nx];
-            let mut next = HashSet::with_capacity(cur.len());
-            for path in cur {
-                let mut path = path.clone();
-                path.push(neighbor);
-                if neighbor == to_idx {
-                    min_length = cmp::min(path.len(), min_length);
-                }
-                if path.len() <= min_length {
-                    next.insert(path);
-                }
-            }
-            paths
-                .entry(neighbor)
-                .or_insert_with(HashSet::new)
-                .extend(next);
-        }
-    }
-    if !paths.contains_key(&to_idx) || paths[&to_idx].is_empty() {
-        return Vec::new();
-    }
-    let min_length = paths[&to_idx].iter().map(|v| v.len()).min().unwrap();
-    let mut best_paths = Vec::new();
-    for paths in paths.remove(&to_idx) {
-        for path in &paths {
-            if path.len() > min_length {
-                continue;
-            }
-            best_paths.push(path.to_owned());
-        }
-    }
-
-    best_paths
+/// The distance from `from` to `to`, with the first step along each of the
+/// shortest paths between them.
+fn path_length(cells: &BTreeMap<Point, Cell>, from: Point, to: Point) -> Vec<(usize, Point)> {
+    let search = search::bfs_all(
+        from,
+        |pos| {
+            pos.neighbours()
+                .iter()
+                .cloned()
+                .filter(|p| cells[p].is_empty())
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == to,
+    );
+    let distance = match search.goal_cost() {
+        Some(distance) => distance,
+        None => return Vec::new(),
+    };
+    search
+        .first_steps(&to)
         .into_iter()
-        .map(|path| (path.len(), g[path.next()].0))
+        .map(|next| (distance, next))
         .collect()
 }
 
@@ -362,7 +237,7 @@ impl State {
             let enemies = &enemies;
             let mut candidate = None;
             for &(enemy_pos, _) in enemies {
-                for &cross_pos in &enemy_pos.cross() {
+                for &cross_pos in &enemy_pos.neighbours() {
                     if cross_pos == pos {
                         // already at the enemy
                         let new = Candidate::Immediate {
@@ -380,8 +255,8 @@ impl State {
                 }
             }
             for &(enemy_pos, _) in enemies {
-                for cross_pos in &enemy_pos.cross() {
-                    let cross_pos: Position = *cross_pos;
+                for cross_pos in &enemy_pos.neighbours() {
+                    let cross_pos: Point = *cross_pos;
                     if !self.cells[&cross_pos].is_empty() {
                         continue;
                     }
@@ -419,7 +294,7 @@ impl State {
             };
 
             let enemy = pos
-                .cross()
+                .neighbours()
                 .iter()
                 .filter_map(|attack_pos| {
                     self.cells.get(&attack_pos).and_then(|e| {
@@ -482,10 +357,7 @@ fn generator(input: &str) -> State {
     let mut cells = BTreeMap::new();
     for (line_idx, line) in input.trim().lines().enumerate() {
         for (ch_idx, ch) in line.chars().enumerate() {
-            let pos = Position {
-                y: line_idx,
-                x: ch_idx,
-            };
+            let pos = Point::from_index(line_idx, ch_idx);
             cells.insert(
                 pos,
                 match ch {
@@ -506,8 +378,7 @@ fn generator(input: &str) -> State {
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 27730,
-    expect = 208960)]
+    example = 27730)]
 fn part1(mut state: State) -> usize {
     eprintln!("\n\n");
     let mut i = 0;
@@ -1,146 +1,68 @@
+use advent_of_code::{parse_lines, Pattern};
 use aoc_macro::{generator, solution};
-use hashbrown::HashSet;
 
 aoc_macro::day!();
 
-use y2018::device::op_codes::*;
+use y2018::device::infer::{infer, Sample};
 use y2018::device::{RawOp, Registers};
 
-#[derive(Copy, Clone, Debug)]
-struct Sample {
-    before: Registers,
-    op: RawOp,
-    after: Registers,
-}
+type Out = (Vec<Sample>, Vec<RawOp>);
 
-impl Sample {
-    fn check(&self, op: fn(RawOp, &mut Registers)) -> bool {
-        let mut regs = self.before;
-        op(self.op, &mut regs);
-        regs == self.after
-    }
+/// A line of the samples.
+#[derive(Pattern)]
+enum Line {
+    #[pattern = "Before: [{0}, {1}, {2}, {3}]"]
+    Before(u64, u64, u64, u64),
+    #[pattern = "After: [{0}, {1}, {2}, {3}]"]
+    After(u64, u64, u64, u64),
+    #[pattern = "{0}"]
+    Op(RawOp),
 }
 
-type Out = (Vec<Sample>, Vec<RawOp>);
-
 #[generator]
 fn generator((samples_input, raw_program): (&str, &str)) -> Out {
+    let lines =
+        parse_lines(samples_input).unwrap_or_else(|e| panic!("{}", e.render(samples_input)));
     let mut samples = Vec::new();
     let mut before = None;
     let mut op = None;
-    for line in samples_input.trim().lines() {
-        if line.is_empty() {
-            continue;
-        }
-        if line.starts_with("Before: ") || line.starts_with("After: ") {
-            let it = line[9..19].split(", ").map(|d| d.parse::<u32>().unwrap());
-            let reg = Registers::from(it);
-            if line.contains("Before") {
-                before = Some(reg);
-            } else {
-                samples.push(Sample {
-                    before: before.expect("found before"),
-                    op: op.expect("found op"),
-                    after: reg,
-                });
+    for line in lines {
+        match line {
+            Line::Before(a, b, c, d) => {
+                before = Some(Registers::from([a, b, c, d].iter().cloned()))
             }
-        } else {
-            let mut it = line
-                .split(' ')
-                .filter(|d| !d.is_empty())
-                .map(|d| d.parse::<u32>().unwrap());
-            op = Some(RawOp {
-                code: it.next().unwrap(),
-                a: it.next().unwrap(),
-                b: it.next().unwrap(),
-                c: it.next().unwrap(),
-            });
+            Line::Op(raw) => op = Some(raw),
+            Line::After(a, b, c, d) => samples.push(Sample {
+                before: before.expect("found before"),
+                op: op.expect("found op"),
+                after: Registers::from([a, b, c, d].iter().cloned()),
+            }),
         }
     }
-    let mut program = Vec::new();
-    for line in raw_program.trim().lines() {
-        let mut it = line
-            .split(' ')
-            .filter(|d| !d.is_empty())
-            .map(|d| d.parse::<u32>().unwrap());
-        program.push(RawOp {
-            code: it.next().unwrap(),
-            a: it.next().unwrap(),
-            b: it.next().unwrap(),
-            c: it.next().unwrap(),
-        });
-    }
+    let program = parse_lines(raw_program).unwrap_or_else(|e| panic!("{}", e.render(raw_program)));
     (samples, program)
 }
 
-static OPS: &[fn(RawOp, &mut Registers)] = &[
-    addr, addi, mulr, muli, banr, bani, borr, bori, setr, seti, gtir, gtri, gtrr, eqir, eqri, eqrr,
-];
-
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 1,
-    expect = 517)]
-fn part1((input, _): Out) -> u32 {
-    let mut count = 0;
-    for sample in input {
-        let mut worked = 0;
-        for op in OPS {
-            if sample.check(*op) {
-                worked += 1;
-            }
-        }
-        if worked >= 3 {
-            count += 1;
-        }
-    }
-    count
+    example = 1)]
+fn part1((input, _): Out) -> usize {
+    input
+        .iter()
+        .filter(|sample| sample.candidates().len() >= 3)
+        .count()
 }
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 0,
-    expect = 667)]
-fn part2((input, program): Out, example: bool) -> u32 {
+    example = 0)]
+fn part2((input, program): Out, example: bool) -> u64 {
     if example {
         return 0;
     }
-    let mut by_code = vec![vec![]; 16];
-    for sample in input {
-        by_code[sample.op.code as usize].push(sample);
-    }
-    let mut codes = vec![HashSet::new(); 16];
-    for (op_idx, op) in OPS.iter().enumerate() {
-        for samples in &by_code {
-            if samples.iter().all(|s| s.check(*op)) {
-                let code = samples[0].op.code as usize;
-                codes[code].insert(op_idx);
-            }
-        }
-    }
-    while codes.iter().any(|c| c.len() > 1) {
-        let unique = codes
-            .iter()
-            .filter(|c| c.len() == 1)
-            .flat_map(|x| x)
-            .cloned()
-            .collect::<HashSet<_>>();
-        for code in &mut codes {
-            if code.len() == 1 {
-                continue;
-            }
-            *code = code.difference(&unique).cloned().collect();
-        }
-    }
-    let mut code_map = vec![99; 16];
+    let mut device = infer(&input).unwrap().device(&program).unwrap();
     let mut regs = Registers::default();
-    for (code, valid_ops) in codes.iter().enumerate() {
-        let valid = *valid_ops.iter().next().unwrap();
-        code_map[code] = valid;
-    }
-    for raw in &program {
-        OPS[code_map[raw.code as usize]](*raw, &mut regs);
-    }
+    while device.step(&mut regs) {}
     regs[0]
 }
 
@@ -1,3 +1,5 @@
+use advent_of_code::geometry::Direction::{Down, Left, Right, Up};
+use advent_of_code::geometry::Point;
 use aoc_macro::{generator, solution};
 use hashbrown::{HashMap, HashSet};
 use std::fmt;
@@ -6,45 +8,6 @@ aoc_macro::day!();
 
 type Out = State;
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Position {
-    y: usize,
-    x: isize,
-}
-
-impl Position {
-    fn up(self) -> Position {
-        Position {
-            x: self.x,
-            y: self.y.checked_sub(1).expect("Cannot go up from y=0"),
-        }
-    }
-    fn down(self) -> Position {
-        Position {
-            x: self.x,
-            y: self.y + 1,
-        }
-    }
-    fn left(self) -> Position {
-        Position {
-            x: self.x.checked_sub(1).expect("cannot go left from x=0"),
-            y: self.y,
-        }
-    }
-    fn right(self) -> Position {
-        Position {
-            x: self.x + 1,
-            y: self.y,
-        }
-    }
-}
-
-impl fmt::Debug for Position {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
 fn parse_record(s: &str) -> Vec<usize> {
     if s.contains("..") {
         let start = s[..s.find('.').unwrap()].parse::<usize>().unwrap();
@@ -72,7 +35,7 @@ fn generator(input: &str) -> State {
         };
         for x in x {
             for &y in &y {
-                clay.insert(Position { x: x as isize, y });
+                clay.insert(Point::new(x as isize, y as isize));
             }
         }
     }
@@ -104,10 +67,10 @@ enum Water {
 }
 
 struct State {
-    max_y: usize,
-    clay: HashSet<Position>,
-    clays: HashMap<usize, Vec<isize>>,
-    water: HashMap<Position, Water>,
+    max_y: isize,
+    clay: HashSet<Point>,
+    clays: HashMap<isize, Vec<isize>>,
+    water: HashMap<Point, Water>,
     counts: Vec<usize>,
 }
 
@@ -119,7 +82,7 @@ impl fmt::Debug for State {
         for y in 0..=self.max_y {
             write!(f, "{:5} ", y)?;
             for x in min_x..=max_x {
-                let pos = Position { x, y };
+                let pos = Point::new(x, y);
                 if self.clay.contains(&pos) {
                     write!(f, "#")?;
                 } else if pos.x == 500 && pos.y == 0 {
@@ -148,7 +111,7 @@ enum Flow {
 }
 
 impl State {
-    fn can_flow_into(&self, pos: Position) -> Flow {
+    fn can_flow_into(&self, pos: Point) -> Flow {
         if pos.y > self.max_y {
             return Flow::Abyss;
         }
@@ -177,7 +140,7 @@ impl State {
     }
 
     fn insert_water(&mut self) {
-        let start_at = Position { x: 500, y: 1 };
+        let start_at = Point::new(500, 1);
         let mut to_visit = vec![start_at];
         let mut visited = 0;
         let mut current_visited = HashSet::new();
@@ -187,34 +150,34 @@ impl State {
             }
             visited += 1;
             self.water.insert(at, Water::Flow);
-            while self.can_flow_into(at.down()) == Flow::Yes {
-                at = at.down();
+            while self.can_flow_into(at + Down) == Flow::Yes {
+                at += Down;
                 self.water.insert(at, Water::Flow);
             }
 
             let center = at;
-            while self.can_flow_into(at.down()) == Flow::Bedrock
-                && self.can_flow_into(at.left()) != Flow::Bedrock
+            while self.can_flow_into(at + Down) == Flow::Bedrock
+                && self.can_flow_into(at + Left) != Flow::Bedrock
             {
-                at = at.left();
+                at += Left;
                 self.water.insert(at, Water::Flow);
             }
-            if self.can_flow_into(at.down()) == Flow::Yes {
-                to_visit.push(at.down());
+            if self.can_flow_into(at + Down) == Flow::Yes {
+                to_visit.push(at + Down);
             }
             at = center;
-            while self.can_flow_into(at.down()) == Flow::Bedrock
-                && self.can_flow_into(at.right()) != Flow::Bedrock
+            while self.can_flow_into(at + Down) == Flow::Bedrock
+                && self.can_flow_into(at + Right) != Flow::Bedrock
             {
-                at = at.right();
+                at += Right;
                 self.water.insert(at, Water::Flow);
             }
-            if self.can_flow_into(at.down()) == Flow::Yes {
-                to_visit.push(at.down());
+            if self.can_flow_into(at + Down) == Flow::Yes {
+                to_visit.push(at + Down);
             }
             if self.try_settle(center) {
-                self.try_settle(center.up());
-                to_visit.push(center.up());
+                self.try_settle(center + Up);
+                to_visit.push(center + Up);
             }
             if visited % 100_000 == 0 {
                 if !self.check_progress() {
@@ -224,7 +187,7 @@ impl State {
         }
     }
 
-    fn try_settle(&mut self, pos: Position) -> bool {
+    fn try_settle(&mut self, pos: Point) -> bool {
         let clays = if let Some(clays) = self.clays.get(&pos.y) {
             clays
         } else {
@@ -244,11 +207,11 @@ impl State {
                         let after = after - 1;
                         let between_clay = (before..=after)
                             .into_iter()
-                            .all(|x| self.water.contains_key(&Position { y: pos.y, x: x }));
+                            .all(|x| self.water.contains_key(&Point::new(x, pos.y)));
                         if between_clay {
                             let mut new = false;
                             for x in before..=after {
-                                let r = self.water.insert(Position { y: pos.y, x }, Water::Settled);
+                                let r = self.water.insert(Point::new(x, pos.y), Water::Settled);
                                 if r == Some(Water::Flow) {
                                     new = true;
                                 }
@@ -280,8 +243,7 @@ impl State {
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 57,
-    expect = 42429)]
+    example = 57)]
 fn part1(mut input: Out) -> usize {
     let mut last_count = [300; 600];
     while last_count.iter().any(|c| *c != input.water.len()) {
@@ -294,8 +256,7 @@ fn part1(mut input: Out) -> usize {
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 29,
-    expect = 35998)]
+    example = 29)]
 fn part2(mut input: Out) -> usize {
     let mut last_count = [300; 600];
     while last_count.iter().any(|c| *c != input.water.len()) {
@@ -8,10 +8,11 @@ type Out = Device;
 
 #[generator]
 fn generator(input: &str) -> Out {
-    Device::load(input)
+    Device::load(input).unwrap()
 }
 
-fn simulate(mut device: Device, r0: u32) -> u32 {
+fn simulate(mut device: Device, r0: u64) -> u64 {
+    device.optimize();
     let mut registers = Registers::default();
     registers[0] = r0;
     while device.step(&mut registers) {}
@@ -20,31 +21,16 @@ fn simulate(mut device: Device, r0: u32) -> u32 {
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 6,
-    expect = 3224)]
-fn part1(device: Out) -> u32 {
+    example = 6)]
+fn part1(device: Out) -> u64 {
     simulate(device, 0)
 }
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 6,
-    expect = 32188416)]
-fn part2(device: Out, example: bool) -> u32 {
-    if example {
-        return simulate(device, 1);
-    }
-    let mut r0 = 0;
-    let r3 = 10551408;
-
-    for r4 in 1..=r3 {
-        if r3 % r4 != 0 {
-            continue;
-        }
-        r0 += r4;
-    }
-
-    r0
+    example = 6)]
+fn part2(device: Out) -> u64 {
+    simulate(device, 1)
 }
 
 static EXAMPLE: &str = "
@@ -1,8 +1,8 @@
+use advent_of_code::geometry::{Direction, Point};
 use aoc_macro::{generator, sol_test, solution};
 use hashbrown::HashMap;
 use regex_syntax::hir::{self, Hir, HirKind};
 use regex_syntax::ParserBuilder;
-use std::fmt;
 
 aoc_macro::day!();
 
@@ -19,18 +19,17 @@ fn generator(input: &str) -> Out {
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 31,
-    expect = 4344)]
+    example = 31)]
 fn part1(hir: Out) -> usize {
     let mut map = HashMap::new();
 
-    map.insert(Position::default(), 0);
-    descend(&mut map, &hir, Position::default());
+    map.insert(Point::ORIGIN, 0);
+    descend(&mut map, &hir, Point::ORIGIN);
 
     map.values().cloned().max().unwrap()
 }
 
-fn descend(map: &mut HashMap<Position, usize>, hir: &Hir, pos: Position) -> Position {
+fn descend(map: &mut HashMap<Point, usize>, hir: &Hir, pos: Point) -> Point {
     match hir.kind() {
         HirKind::Anchor(_) | HirKind::Empty => pos,
         HirKind::Class(_)
@@ -41,7 +40,9 @@ fn descend(map: &mut HashMap<Position, usize>, hir: &Hir, pos: Position) -> Posi
         }
         HirKind::Literal(hir::Literal::Unicode(ch)) => {
             let dist_to_here = map[&pos] + 1;
-            let next_pos = pos.apply(*ch);
+            let dir = Direction::from_char(*ch)
+                .unwrap_or_else(|| unreachable!("unexpected character: {:?}", ch));
+            let next_pos = pos + dir;
             let entry = map.entry(next_pos).or_insert(dist_to_here);
             *entry = std::cmp::min(*entry, dist_to_here);
             next_pos
@@ -70,52 +71,15 @@ sol_test!(p1_4: part1(generator("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$")), 1
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 0,
-    expect = 8809)]
+    example = 0)]
 fn part2(hir: Out) -> usize {
     let mut map = HashMap::new();
 
-    map.insert(Position::default(), 0);
-    descend(&mut map, &hir, Position::default());
+    map.insert(Point::ORIGIN, 0);
+    descend(&mut map, &hir, Point::ORIGIN);
 
     map.values().cloned().filter(|v| *v >= 1000).count()
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-struct Position {
-    y: isize,
-    x: isize,
-}
-
-impl Position {
-    fn apply(self, ch: char) -> Position {
-        match ch {
-            'N' => Position {
-                y: self.y - 1,
-                x: self.x,
-            },
-            'E' => Position {
-                x: self.x + 1,
-                y: self.y,
-            },
-            'W' => Position {
-                x: self.x - 1,
-                y: self.y,
-            },
-            'S' => Position {
-                y: self.y + 1,
-                x: self.x,
-            },
-            _ => unreachable!("unexpected character: {:?}", ch),
-        }
-    }
-}
-
-impl fmt::Debug for Position {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
-
 static EXAMPLE: &str = "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$";
 static INPUT: &str = include_str!("day20.input");
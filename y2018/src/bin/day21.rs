@@ -2,6 +2,7 @@ use aoc_macro::{generator, solution};
 use hashbrown::HashSet;
 aoc_macro::day!();
 
+use y2018::device::debug::{Debugger, Stop};
 use y2018::device::{Device, Registers};
 
 type Out = Device;
@@ -11,54 +12,53 @@ fn generator(input: &str) -> Out {
     if input.is_empty() {
         return Device::default();
     }
-    Device::load(input)
+    Device::load(input).unwrap()
 }
 
 #[solution(part1,
     example_input = generator(EXAMPLE),
-    example = 0,
-    expect = 16457176)]
-fn part1(mut device: Out, example: bool) -> u32 {
+    example = 0)]
+fn part1(mut device: Out, example: bool) -> u64 {
     if example {
         return 0;
     }
 
-    let mut registers = Registers::default();
-    while device.step(&mut registers) {
-        if device.ip == 28 {
-            // this is the only instruction that mentions register 0; and the instruction is
-            // eqrr; r0 == r5 is the exit condition. As such, whatever the value of register 5 is
-            // the correct value to return at this point
-            return registers[5];
-        }
+    // The loop dividing r[4] by 256 runs as a single step; instruction 28
+    // comes after it.
+    device.optimize();
+    let mut debugger = Debugger::new(device, Registers::default());
+    // this is the only instruction that mentions register 0; and the instruction is
+    // eqrr; r0 == r5 is the exit condition. As such, whatever the value of register 5 is
+    // the correct value to return at this point
+    debugger.break_at(28).unwrap();
+    match debugger.run() {
+        Stop::Breakpoint(28) => debugger.registers[5],
+        _ => unreachable!("did not find instruction #28"),
     }
-
-    unreachable!("did not find instruction #28")
 }
 
 #[solution(part2,
     example_input = generator(EXAMPLE),
-    example = 0,
-    expect = 13625951)]
-fn part2(mut device: Out, example: bool) -> u32 {
+    example = 0)]
+fn part2(mut device: Out, example: bool) -> u64 {
     if example {
         return 0;
     }
-    let mut registers = Registers::default();
+    device.optimize();
+    let mut debugger = Debugger::new(device, Registers::default());
     let mut seen = HashSet::new();
-    let mut last = registers[5];
-    while device.step(&mut registers) {
-        // this is the only instruction that mentions register 0; and the instruction is
-        // eqrr; r0 == r5 is the exit condition.
-        //
-        // Presuming there's a cycle, we want the last value in that cycle.
-        if device.ip == 28 {
-            if seen.insert(registers[5]) {
-                last = registers[5];
-            } else {
-                // seen twice
-                return last;
-            }
+    let mut last = debugger.registers[5];
+    // this is the only instruction that mentions register 0; and the instruction is
+    // eqrr; r0 == r5 is the exit condition.
+    //
+    // Presuming there's a cycle, we want the last value in that cycle.
+    debugger.break_at(28).unwrap();
+    while let Stop::Breakpoint(28) = debugger.run() {
+        if seen.insert(debugger.registers[5]) {
+            last = debugger.registers[5];
+        } else {
+            // seen twice
+            return last;
         }
     }
     unreachable!()
@@ -0,0 +1,186 @@
+//! An interactive debugger for ElfCode programs (days 19 and 21).
+//!
+//! Run with `cargo run --release -p y2018 --bin debugger -- PROGRAM [R0]` and
+//! type `help` for the commands.
+
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+use std::str::FromStr;
+
+use y2018::device::debug::{Access, Debugger, Stop};
+use y2018::device::decompile::decompile;
+use y2018::device::{Device, Registers, REGISTERS};
+
+const HELP: &str = "\
+b IP          set a breakpoint
+d IP          delete a breakpoint
+w r|w REG     watch reads or writes of a register
+u r|w REG     stop watching a register
+s [N]         step N instructions (default 1)
+c             continue until a breakpoint, watchpoint or halt
+r             show the ip and registers
+set REG VAL   set a register
+t             toggle printing every instruction
+p             show the most run instructions
+l             list the program
+x             show the program decompiled
+q             quit";
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("usage: {} PROGRAM [R0]", args[0]);
+        process::exit(2);
+    }
+    let program = fs::read_to_string(&args[1]).unwrap_or_else(|e| {
+        eprintln!("{}: {}", args[1], e);
+        process::exit(1);
+    });
+    let device = Device::load(&program).unwrap_or_else(|e| {
+        eprintln!("{}:{}", args[1], e);
+        process::exit(1);
+    });
+    let mut registers = Registers::default();
+    if let Some(r0) = args.get(2) {
+        registers[0] = r0.parse().unwrap_or_else(|_| {
+            eprintln!("invalid R0 {:?}", r0);
+            process::exit(2);
+        });
+    }
+
+    let tracing = Cell::new(false);
+    let mut debugger = Debugger::new(device, registers);
+    debugger.trace(|t| {
+        if tracing.get() {
+            println!("{:3}: {:?}  {:?}", t.ip, t.instruction, t.after);
+        }
+    });
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let line = match lines.next() {
+            Some(line) => line.unwrap(),
+            None => break,
+        };
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        if words.is_empty() {
+            continue;
+        }
+        if words[0] == "q" {
+            break;
+        }
+        if let Err(e) = command(&mut debugger, &tracing, &words) {
+            println!("{}", e);
+        }
+    }
+}
+
+/// The number at `words[i]`.
+fn argument<T: FromStr>(words: &[&str], i: usize) -> Result<T, String> {
+    let word = words
+        .get(i)
+        .ok_or_else(|| String::from("missing argument"))?;
+    word.parse()
+        .map_err(|_| format!("expected a number, found {:?}", word))
+}
+
+fn command(
+    debugger: &mut Debugger<'_>,
+    tracing: &Cell<bool>,
+    words: &[&str],
+) -> Result<(), String> {
+    let number = |i: usize| argument::<u32>(words, i);
+    let register = |i: usize| -> Result<u32, String> {
+        let r = number(i)?;
+        if r as usize >= REGISTERS {
+            return Err(format!("{} is not a register", r));
+        }
+        Ok(r)
+    };
+    let access = || match words.get(1) {
+        Some(&"r") => Ok(Access::Read),
+        Some(&"w") => Ok(Access::Write),
+        _ => Err(String::from("expected r or w")),
+    };
+    match words[0] {
+        "help" => println!("{}", HELP),
+        "b" => debugger
+            .break_at(number(1)? as usize)
+            .map_err(|e| e.to_string())?,
+        "d" => debugger.clear_break(number(1)? as usize),
+        "w" => debugger
+            .watch(register(2)?, access()?)
+            .map_err(|e| e.to_string())?,
+        "u" => debugger
+            .unwatch(register(2)?, access()?)
+            .map_err(|e| e.to_string())?,
+        "s" => {
+            let n = if words.len() > 1 { number(1)? } else { 1 };
+            for _ in 0..n {
+                if let Some(stop) = debugger.step() {
+                    println!("{}", stop);
+                    break;
+                }
+            }
+            show(debugger);
+        }
+        "c" => {
+            let stop = debugger.run();
+            println!("{}", stop);
+            if stop != Stop::Halted {
+                show(debugger);
+            }
+        }
+        "r" => show(debugger),
+        "set" => {
+            let r = register(1)?;
+            debugger.registers[r] = argument(words, 2)?;
+            if Some(r) == debugger.device.ip_reg {
+                debugger.device.ip = debugger.registers[r] as usize;
+            }
+        }
+        "t" => {
+            tracing.set(!tracing.get());
+            println!("tracing {}", if tracing.get() { "on" } else { "off" });
+        }
+        "p" => {
+            let mut counts = debugger.profile().iter().enumerate().collect::<Vec<_>>();
+            counts.sort_by(|a, b| b.1.cmp(a.1));
+            for (ip, count) in counts.into_iter().take(10).filter(|c| *c.1 > 0) {
+                let instr = &debugger.device.instructions[ip];
+                println!("{:12} {:3}: {:?}", count, ip, instr);
+            }
+            println!("{} steps", debugger.steps());
+        }
+        "l" => {
+            let breakpoints = debugger.breakpoints().collect::<Vec<_>>();
+            for (ip, instr) in debugger.device.instructions.iter().enumerate() {
+                let mark = if ip == debugger.device.ip {
+                    "=>"
+                } else if breakpoints.contains(&ip) {
+                    " *"
+                } else {
+                    "  "
+                };
+                println!("{} {:3}: {:?}", mark, ip, instr);
+            }
+        }
+        "x" => print!("{}", decompile(&debugger.device)),
+        word => return Err(format!("unknown command {:?}; try help", word)),
+    }
+    Ok(())
+}
+
+fn show(debugger: &Debugger<'_>) {
+    match debugger.device.instructions.get(debugger.device.ip) {
+        Some(instr) => println!("ip {}: {:?}", debugger.device.ip, instr),
+        None => println!("ip {}: halted", debugger.device.ip),
+    }
+    println!("{:?}", debugger.registers);
+}
@@ -1,11 +1,21 @@
+use advent_of_code::Pattern;
+use failure::Fail;
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops;
 
+pub mod debug;
+pub mod decompile;
+pub mod infer;
 pub mod op_codes;
+pub mod optimize;
+
+/// The number of registers of a `Device`.
+pub const REGISTERS: usize = 6;
 
 #[derive(Default, Copy, Clone, PartialEq, Eq)]
 pub struct Registers {
-    r: [u32; 6],
+    r: [u64; REGISTERS],
 }
 
 impl fmt::Debug for Registers {
@@ -16,7 +26,7 @@ impl fmt::Debug for Registers {
 
 impl<I> From<I> for Registers
 where
-    I: Iterator<Item = u32>,
+    I: Iterator<Item = u64>,
 {
     fn from(mut it: I) -> Registers {
         Registers {
@@ -33,25 +43,26 @@ where
 }
 
 impl ops::Index<u32> for Registers {
-    type Output = u32;
-    fn index(&self, idx: u32) -> &u32 {
+    type Output = u64;
+    fn index(&self, idx: u32) -> &u64 {
         match self.r.get(idx as usize) {
             Some(v) => v,
-            _ => panic!("idx {} is not a register"),
+            _ => panic!("idx {} is not a register", idx),
         }
     }
 }
 
 impl ops::IndexMut<u32> for Registers {
-    fn index_mut(&mut self, idx: u32) -> &mut u32 {
+    fn index_mut(&mut self, idx: u32) -> &mut u64 {
         match self.r.get_mut(idx as usize) {
             Some(v) => v,
-            _ => panic!("idx {} is not a register"),
+            _ => panic!("idx {} is not a register", idx),
         }
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Pattern)]
+#[pattern = "{code} {a} {b} {c}"]
 pub struct RawOp {
     pub code: u32,
     pub a: u32,
@@ -65,96 +76,434 @@ pub struct Instruction {
     inputs: RawOp,
 }
 
+impl Instruction {
+    pub fn name(&self) -> &'static str {
+        INSTRUCTIONS[self.inputs.code as usize].name
+    }
+
+    pub fn raw(&self) -> RawOp {
+        self.inputs
+    }
+
+    pub fn execute(&self, registers: &mut Registers) {
+        (self.op)(self.inputs, registers);
+    }
+
+    /// The registers this instruction reads.
+    pub fn reads(&self) -> impl Iterator<Item = u32> {
+        let kind = &INSTRUCTIONS[self.inputs.code as usize];
+        let a = Some(self.inputs.a).filter(|_| kind.a == Reg);
+        let b = Some(self.inputs.b).filter(|_| kind.b == Reg);
+        a.into_iter().chain(b)
+    }
+
+    /// The register this instruction writes.
+    pub fn writes(&self) -> u32 {
+        self.inputs.c
+    }
+
+    pub fn is_comparison(&self) -> bool {
+        match self.operator() {
+            "==" | ">" => true,
+            _ => false,
+        }
+    }
+
+    /// The operator combining `a` and `b`, e.g. `+` or `==`; empty for
+    /// `seti` and `setr`, which copy `a`.
+    pub fn operator(&self) -> &'static str {
+        INSTRUCTIONS[self.inputs.code as usize].operator
+    }
+
+    /// The `a` operand and any `b` operand, with registers rendered by
+    /// `register`.
+    fn operands(&self, register: impl Fn(u32) -> String) -> (String, Option<String>) {
+        let kind = &INSTRUCTIONS[self.inputs.code as usize];
+        let operand = |kind, value| match kind {
+            Reg => register(value),
+            _ => value.to_string(),
+        };
+        let a = operand(kind.a, self.inputs.a);
+        if kind.b == Ignored {
+            (a, None)
+        } else {
+            (a, Some(operand(kind.b, self.inputs.b)))
+        }
+    }
+
+    /// The value this instruction computes, e.g. `r[1] + 5`, with registers
+    /// rendered by `register`.
+    pub fn expression(&self, register: impl Fn(u32) -> String) -> String {
+        match self.operands(register) {
+            (a, None) => a,
+            (a, Some(b)) => format!("{} {} {}", a, self.operator(), b),
+        }
+    }
+}
+
 impl fmt::Debug for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let RawOp { a, b, c, .. } = self.inputs;
-        match self.inputs.code {
-            0 => write!(f, "r[{}] = r[{}] + {}", c, a, b)?,
-            1 => write!(f, "r[{}] = r[{}] + r[{}]", c, a, b)?,
-            2 => write!(f, "r[{}] = {} == r[{}]", c, a, b)?,
-            3 => write!(f, "r[{}] = r[{}] == {}", c, a, b)?,
-            4 => write!(f, "r[{}] = r[{}] == r[{}]", c, a, b)?,
-            5 => write!(f, "r[{}] = {} > r[{}]", c, a, b)?,
-            6 => write!(f, "r[{}] = r[{}] > {}", c, a, b)?,
-            7 => write!(f, "r[{}] = r[{}] > r[{}]", c, a, b)?,
-            8 => write!(f, "r[{}] = r[{}] * {}", c, a, b)?,
-            9 => write!(f, "r[{}] = r[{}] * r[{}]", c, a, b)?,
-            10 => write!(f, "r[{}] = {}", c, a)?,
-            11 => write!(f, "r[{}] = r[{}]", c, a)?,
-            12 => write!(f, "r[{}] = r[{}] & {}", c, a, b)?,
-            13 => write!(f, "r[{}] = r[{}] | {}", c, a, b)?,
-            _ => unimplemented!("unhandled code: {:?}", self.inputs.code),
-        }
-        Ok(())
+        write!(
+            f,
+            "r[{}] = {}",
+            self.inputs.c,
+            self.expression(|r| format!("r[{}]", r))
+        )
     }
 }
 
 pub type Op = fn(RawOp, &mut Registers);
 
-#[derive(Clone, Default)]
+/// Whether an operand names a register or is an immediate value.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Operand {
+    Reg,
+    Imm,
+    Ignored,
+}
+
+use self::Operand::{Ignored, Imm, Reg};
+
+#[derive(Copy, Clone)]
+struct Kind {
+    name: &'static str,
+    op: Op,
+    a: Operand,
+    b: Operand,
+    operator: &'static str,
+}
+
+macro_rules! kind {
+    ($name:ident, $a:ident, $b:ident, $operator:expr) => {
+        Kind {
+            name: stringify!($name),
+            op: op_codes::$name,
+            a: $a,
+            b: $b,
+            operator: $operator,
+        }
+    };
+}
+
+/// Every instruction, indexed by its code in `RawOp`, with the kinds of its
+/// `a` and `b` operands; `c` is always a register.
+static INSTRUCTIONS: [Kind; 16] = [
+    kind!(addi, Reg, Imm, "+"),
+    kind!(addr, Reg, Reg, "+"),
+    kind!(eqir, Imm, Reg, "=="),
+    kind!(eqri, Reg, Imm, "=="),
+    kind!(eqrr, Reg, Reg, "=="),
+    kind!(gtir, Imm, Reg, ">"),
+    kind!(gtri, Reg, Imm, ">"),
+    kind!(gtrr, Reg, Reg, ">"),
+    kind!(muli, Reg, Imm, "*"),
+    kind!(mulr, Reg, Reg, "*"),
+    kind!(seti, Imm, Ignored, ""),
+    kind!(setr, Reg, Ignored, ""),
+    kind!(bani, Reg, Imm, "&"),
+    kind!(bori, Reg, Imm, "|"),
+    kind!(banr, Reg, Reg, "&"),
+    kind!(borr, Reg, Reg, "|"),
+];
+
+#[derive(Fail, Debug, PartialEq, Eq)]
+pub enum DeviceErrorKind {
+    #[fail(display = "unknown instruction {:?}", _0)]
+    UnknownInstruction(String),
+    #[fail(display = "expected a number, found {:?}", _0)]
+    InvalidNumber(String),
+    #[fail(display = "{} is not a register", _0)]
+    InvalidRegister(u32),
+    #[fail(display = "missing operand")]
+    MissingOperand,
+    #[fail(display = "unexpected {:?}", _0)]
+    Unexpected(String),
+    #[fail(display = "duplicate #ip")]
+    DuplicateIp,
+    #[fail(display = "did not find #ip")]
+    MissingIp,
+}
+
+/// An error loading a program, at a 1-based line and column of its text.
+#[derive(Fail, Debug, PartialEq, Eq)]
+#[fail(display = "{}:{}: {}", line, column, kind)]
+pub struct DeviceError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: DeviceErrorKind,
+}
+
+#[derive(Clone, Default, Debug)]
 pub struct Device {
     pub ip: usize,
-    pub ip_reg: u32,
+    /// The register bound to the ip, if any.
+    pub ip_reg: Option<u32>,
     pub instructions: Vec<Instruction>,
+    fused: Vec<Option<optimize::Fused>>,
 }
 
 impl Device {
     pub fn step(&mut self, registers: &mut Registers) -> bool {
         if let Some(instr) = self.instructions.get(self.ip) {
-            registers[self.ip_reg] = self.ip as u32;
+            let ip_reg = match self.ip_reg {
+                Some(r) => r,
+                None => {
+                    (instr.op)(instr.inputs, registers);
+                    self.ip += 1;
+                    return true;
+                }
+            };
+            registers[ip_reg] = self.ip as u64;
+            let fused = self.fused.get(self.ip).and_then(|f| f.as_ref());
+            if let Some(ip) = fused.and_then(|f| f.execute(ip_reg, registers)) {
+                self.ip = ip;
+                return true;
+            }
             (instr.op)(instr.inputs, registers);
-            self.ip = registers[self.ip_reg] as usize;
-            self.ip += 1;
+            // An ip too big to follow is past the end as much as any other.
+            self.ip = usize::try_from(registers[ip_reg])
+                .ok()
+                .and_then(|ip| ip.checked_add(1))
+                .unwrap_or(usize::max_value());
             true
         } else {
             false
         }
     }
 
-    pub fn load(input: &str) -> Device {
+    /// Replaces the loops `optimize` recognizes with super-instructions, so
+    /// each runs as a single step.
+    pub fn optimize(&mut self) {
+        self.fused = optimize::fuse(self);
+    }
+
+    /// The instructions of the loop fused at `ip`, if one is.
+    pub fn fused_loop(&self, ip: usize) -> Option<ops::Range<usize>> {
+        let fused = self.fused.get(ip)?.as_ref()?;
+        Some(ip..fused.exit())
+    }
+
+    pub fn load(input: &str) -> Result<Device, DeviceError> {
         let mut ip_reg = None;
         let mut instructions = Vec::new();
-        for line in input.trim().lines() {
-            if line.starts_with("#ip ") {
-                ip_reg = Some(line[4..].parse::<u32>().unwrap());
-                continue;
-            }
-
-            let (code, op) = match &line[..4] {
-                "addi" => (0, op_codes::addi as Op),
-                "addr" => (1, op_codes::addr as Op),
-                "eqir" => (2, op_codes::eqir as Op),
-                "eqri" => (3, op_codes::eqri as Op),
-                "eqrr" => (4, op_codes::eqrr as Op),
-                "gtir" => (5, op_codes::gtir as Op),
-                "gtri" => (6, op_codes::gtri as Op),
-                "gtrr" => (7, op_codes::gtrr as Op),
-                "muli" => (8, op_codes::muli as Op),
-                "mulr" => (9, op_codes::mulr as Op),
-                "seti" => (10, op_codes::seti as Op),
-                "setr" => (11, op_codes::setr as Op),
-                "bani" => (12, op_codes::bani as Op),
-                "bori" => (13, op_codes::bori as Op),
-                other => unreachable!("unexpected instruction: {:?}", other),
+        for (line_idx, line) in input.lines().enumerate() {
+            let err = |column: usize, kind| DeviceError {
+                line: line_idx + 1,
+                column: column + 1,
+                kind,
+            };
+            let mut words = Words { line, idx: 0 };
+            let (column, word) = match words.next() {
+                Some(w) => w,
+                None => continue,
             };
 
-            let mut it = line[4..]
-                .split(' ')
-                .filter(|n| !n.is_empty())
-                .map(|n| n.parse::<u32>().unwrap());
-
-            let a = it.next().unwrap();
-            let b = it.next().unwrap();
-            let c = it.next().unwrap();
-
-            let raw = RawOp { code, a, b, c };
+            if word == "#ip" {
+                if ip_reg.is_some() {
+                    return Err(err(column, DeviceErrorKind::DuplicateIp));
+                }
+                ip_reg = Some(operand(&mut words, Reg, line.len()).map_err(|(c, k)| err(c, k))?);
+            } else {
+                let code = INSTRUCTIONS
+                    .iter()
+                    .position(|i| i.name == word)
+                    .ok_or_else(|| err(column, DeviceErrorKind::UnknownInstruction(word.into())))?;
+                let Kind {
+                    op,
+                    a: a_kind,
+                    b: b_kind,
+                    ..
+                } = INSTRUCTIONS[code];
+                let mut operands = || -> Result<RawOp, (usize, DeviceErrorKind)> {
+                    Ok(RawOp {
+                        code: code as u32,
+                        a: operand(&mut words, a_kind, line.len())?,
+                        b: operand(&mut words, b_kind, line.len())?,
+                        c: operand(&mut words, Reg, line.len())?,
+                    })
+                };
+                let inputs = operands().map_err(|(c, k)| err(c, k))?;
+                instructions.push(Instruction { inputs, op });
+            }
 
-            instructions.push(Instruction { inputs: raw, op });
+            if let Some((column, word)) = words.next() {
+                return Err(err(column, DeviceErrorKind::Unexpected(word.into())));
+            }
         }
-        Device {
+        let ip_reg = ip_reg.ok_or(DeviceError {
+            line: 1,
+            column: 1,
+            kind: DeviceErrorKind::MissingIp,
+        })?;
+        Ok(Device {
             ip: 0,
-            ip_reg: ip_reg.expect("did not find #ip"),
+            ip_reg: Some(ip_reg),
             instructions,
-        }
+            fused: Vec::new(),
+        })
+    }
+}
+
+/// The whitespace-separated words of a line, with their 0-based columns.
+struct Words<'a> {
+    line: &'a str,
+    idx: usize,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let rest = &self.line[self.idx..];
+        let start = self.idx + rest.find(|c: char| !c.is_whitespace())?;
+        let len = self.line[start..]
+            .find(char::is_whitespace)
+            .unwrap_or(self.line.len() - start);
+        self.idx = start + len;
+        Some((start, &self.line[start..start + len]))
+    }
+}
+
+fn operand(
+    words: &mut Words<'_>,
+    kind: Operand,
+    end: usize,
+) -> Result<u32, (usize, DeviceErrorKind)> {
+    let (column, word) = words.next().ok_or((end, DeviceErrorKind::MissingOperand))?;
+    let value = word
+        .parse::<u32>()
+        .map_err(|_| (column, DeviceErrorKind::InvalidNumber(word.into())))?;
+    if kind == Reg && value as usize >= REGISTERS {
+        return Err((column, DeviceErrorKind::InvalidRegister(value)));
+    }
+    Ok(value)
+}
+
+#[test]
+fn load_all_instructions() {
+    let program = INSTRUCTIONS
+        .iter()
+        .map(|i| format!("{} 1 2 3", i.name))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let device = Device::load(&format!("#ip 0\n{}", program)).unwrap();
+    assert_eq!(device.instructions.len(), 16);
+    for (code, instr) in device.instructions.iter().enumerate() {
+        assert_eq!(instr.inputs.code, code as u32);
+        assert_eq!(instr.name(), INSTRUCTIONS[code].name);
+    }
+}
+
+#[test]
+fn load_errors() {
+    fn error(program: &str) -> (usize, usize, DeviceErrorKind) {
+        let e = Device::load(program).unwrap_err();
+        (e.line, e.column, e.kind)
     }
+    assert_eq!(
+        error("#ip 0\nnope 1 2 3"),
+        (2, 1, DeviceErrorKind::UnknownInstruction("nope".into()))
+    );
+    assert_eq!(
+        error("#ip 0\naddi 1 x 3"),
+        (2, 8, DeviceErrorKind::InvalidNumber("x".into()))
+    );
+    assert_eq!(
+        error("#ip 0\naddr 1 6 3"),
+        (2, 8, DeviceErrorKind::InvalidRegister(6))
+    );
+    assert_eq!(
+        error("#ip 0\nseti 1 2"),
+        (2, 9, DeviceErrorKind::MissingOperand)
+    );
+    assert_eq!(
+        error("#ip 0\nseti 1 2 3 4"),
+        (2, 12, DeviceErrorKind::Unexpected("4".into()))
+    );
+    assert_eq!(error("#ip 7"), (1, 5, DeviceErrorKind::InvalidRegister(7)));
+    assert_eq!(error("#ip 1\n#ip 2"), (2, 1, DeviceErrorKind::DuplicateIp));
+    assert_eq!(error("seti 1 2 3"), (1, 1, DeviceErrorKind::MissingIp));
 }
+
+#[test]
+fn step_past_any_ip() {
+    let mut device = Device::load("#ip 3\nseti 4294967295 0 1\naddi 1 2 2\nmulr 1 2 3").unwrap();
+    let mut registers = Registers::default();
+    while device.step(&mut registers) {}
+    assert_eq!(registers[3], u64::max_value());
+    assert!(!device.step(&mut registers));
+}
+
+/// The programs of days 19 and 21, for the tests of the analyses.
+#[cfg(test)]
+const DAY19: &str = "#ip 1
+addi 1 16 1
+seti 1 4 4
+seti 1 1 2
+mulr 4 2 5
+eqrr 5 3 5
+addr 5 1 1
+addi 1 1 1
+addr 4 0 0
+addi 2 1 2
+gtrr 2 3 5
+addr 1 5 1
+seti 2 4 1
+addi 4 1 4
+gtrr 4 3 5
+addr 5 1 1
+seti 1 1 1
+mulr 1 1 1
+addi 3 2 3
+mulr 3 3 3
+mulr 1 3 3
+muli 3 11 3
+addi 5 7 5
+mulr 5 1 5
+addi 5 18 5
+addr 3 5 3
+addr 1 0 1
+seti 0 7 1
+setr 1 3 5
+mulr 5 1 5
+addr 1 5 5
+mulr 1 5 5
+muli 5 14 5
+mulr 5 1 5
+addr 3 5 3
+seti 0 7 0
+seti 0 6 1";
+
+#[cfg(test)]
+const DAY21: &str = "#ip 1
+seti 123 0 5
+bani 5 456 5
+eqri 5 72 5
+addr 5 1 1
+seti 0 0 1
+seti 0 2 5
+bori 5 65536 4
+seti 3935295 1 5
+bani 4 255 2
+addr 5 2 5
+bani 5 16777215 5
+muli 5 65899 5
+bani 5 16777215 5
+gtir 256 4 2
+addr 2 1 1
+addi 1 1 1
+seti 27 1 1
+seti 0 5 2
+addi 2 1 3
+muli 3 256 3
+gtrr 3 4 3
+addr 3 1 1
+addi 1 1 1
+seti 25 0 1
+addi 2 1 2
+seti 17 7 1
+setr 2 2 4
+seti 7 6 1
+eqrr 5 0 2
+addr 2 1 1
+seti 5 4 1";
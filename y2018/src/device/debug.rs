@@ -0,0 +1,336 @@
+//! Runs a `Device` under breakpoints, watchpoints and tracing hooks, and
+//! counts how often each instruction runs.
+
+use failure::Fail;
+use std::fmt;
+
+use super::{Device, Instruction, Registers, REGISTERS};
+
+#[derive(Fail, Debug, PartialEq, Eq)]
+pub enum DebugError {
+    #[fail(display = "there is no instruction {}", _0)]
+    NoInstruction(usize),
+    #[fail(display = "{} is inside the loop fused at {}", ip, head)]
+    InFusedLoop { ip: usize, head: usize },
+    #[fail(display = "{} is not a register", _0)]
+    InvalidRegister(u32),
+}
+
+/// How an instruction touches a watched register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Access {
+    Read,
+    Write,
+}
+
+/// Why `Debugger::run` returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction at the ip is about to run.
+    Breakpoint(usize),
+    /// The instruction at `ip` just accessed a watched register.
+    Watch {
+        ip: usize,
+        register: u32,
+        access: Access,
+    },
+    /// The condition passed to `run_until` held.
+    Condition,
+    /// The ip left the program.
+    Halted,
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stop::Breakpoint(ip) => write!(f, "breakpoint at {}", ip),
+            Stop::Watch {
+                ip,
+                register,
+                access: Access::Read,
+            } => write!(f, "{} read r[{}]", ip, register),
+            Stop::Watch {
+                ip,
+                register,
+                access: Access::Write,
+            } => write!(f, "{} wrote r[{}]", ip, register),
+            Stop::Condition => write!(f, "condition met"),
+            Stop::Halted => write!(f, "halted"),
+        }
+    }
+}
+
+/// One executed instruction, as passed to trace callbacks.
+pub struct Trace<'a> {
+    pub ip: usize,
+    pub instruction: &'a Instruction,
+    pub before: Registers,
+    pub after: Registers,
+}
+
+pub struct Debugger<'a> {
+    pub device: Device,
+    pub registers: Registers,
+    breakpoints: Vec<bool>,
+    watches: [[bool; 2]; REGISTERS],
+    tracers: Vec<Box<dyn FnMut(&Trace<'_>) + 'a>>,
+    profile: Vec<u64>,
+    steps: u64,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(device: Device, registers: Registers) -> Debugger<'a> {
+        let len = device.instructions.len();
+        Debugger {
+            device,
+            registers,
+            breakpoints: vec![false; len],
+            watches: [[false; 2]; REGISTERS],
+            tracers: Vec::new(),
+            profile: vec![0; len],
+            steps: 0,
+        }
+    }
+
+    /// Stops before the instruction at `ip` runs. A loop fused by
+    /// `Device::optimize` runs as one step of its head, so breaking inside
+    /// one is refused; set breakpoints after optimizing.
+    pub fn break_at(&mut self, ip: usize) -> Result<(), DebugError> {
+        if ip >= self.breakpoints.len() {
+            return Err(DebugError::NoInstruction(ip));
+        }
+        let inside = |head| self.device.fused_loop(head).map_or(false, |l| ip < l.end);
+        if let Some(head) = (0..ip).find(|&head| inside(head)) {
+            return Err(DebugError::InFusedLoop { ip, head });
+        }
+        self.breakpoints[ip] = true;
+        Ok(())
+    }
+
+    pub fn clear_break(&mut self, ip: usize) {
+        if let Some(b) = self.breakpoints.get_mut(ip) {
+            *b = false;
+        }
+    }
+
+    pub fn breakpoints<'b>(&'b self) -> impl Iterator<Item = usize> + 'b {
+        self.breakpoints
+            .iter()
+            .enumerate()
+            .filter(|&(_, &b)| b)
+            .map(|(ip, _)| ip)
+    }
+
+    /// Stops after any instruction accessing `register` as `access`. A fused
+    /// loop counts as accessing every register its instructions do.
+    pub fn watch(&mut self, register: u32, access: Access) -> Result<(), DebugError> {
+        self.set_watch(register, access, true)
+    }
+
+    pub fn unwatch(&mut self, register: u32, access: Access) -> Result<(), DebugError> {
+        self.set_watch(register, access, false)
+    }
+
+    fn set_watch(&mut self, register: u32, access: Access, on: bool) -> Result<(), DebugError> {
+        match self.watches.get_mut(register as usize) {
+            Some(w) => w[access as usize] = on,
+            None => return Err(DebugError::InvalidRegister(register)),
+        }
+        Ok(())
+    }
+
+    /// Calls `f` after every instruction the debugger runs.
+    pub fn trace(&mut self, f: impl FnMut(&Trace<'_>) + 'a) {
+        self.tracers.push(Box::new(f));
+    }
+
+    pub fn clear_traces(&mut self) {
+        self.tracers.clear();
+    }
+
+    /// How many times each instruction has run, by index. A loop fused by
+    /// `Device::optimize` counts as one run of its first instruction.
+    pub fn profile(&self) -> &[u64] {
+        &self.profile
+    }
+
+    /// How many instructions have run in total.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Runs one instruction, or a whole fused loop, ignoring breakpoints, and
+    /// returns the first watchpoint it hit, or `Stop::Halted` if there was
+    /// none to run.
+    pub fn step(&mut self) -> Option<Stop> {
+        let ip = self.device.ip;
+        let before = self.registers;
+        if !self.device.step(&mut self.registers) {
+            return Some(Stop::Halted);
+        }
+        // A fused loop that ran, rather than giving up, left at its exit.
+        let ran = match self.device.fused_loop(ip) {
+            Some(ref l) if self.device.ip == l.end => l.clone(),
+            _ => ip..ip + 1,
+        };
+        self.steps += 1;
+        self.profile[ip] += 1;
+        let instruction = &self.device.instructions[ip];
+        let trace = Trace {
+            ip,
+            instruction,
+            before,
+            after: self.registers,
+        };
+        for f in &mut self.tracers {
+            f(&trace);
+        }
+
+        let watches = &self.watches;
+        let instructions = &self.device.instructions[ran];
+        let read = instructions
+            .iter()
+            .flat_map(|i| i.reads())
+            .find(|&r| watches[r as usize][Access::Read as usize])
+            .map(|r| (r, Access::Read));
+        let written = instructions
+            .iter()
+            .map(|i| i.writes())
+            .find(|&r| watches[r as usize][Access::Write as usize])
+            .map(|r| (r, Access::Write));
+        read.or(written).map(|(register, access)| Stop::Watch {
+            ip,
+            register,
+            access,
+        })
+    }
+
+    /// Runs until a breakpoint, watchpoint or the end of the program. The
+    /// instruction at the ip always runs, so running again after stopping at
+    /// a breakpoint moves past it.
+    pub fn run(&mut self) -> Stop {
+        self.run_until(|_, _| false)
+    }
+
+    /// Like `run`, but also stops when `condition` holds for the ip and
+    /// registers before an instruction runs.
+    pub fn run_until(&mut self, mut condition: impl FnMut(usize, &Registers) -> bool) -> Stop {
+        if let Some(stop) = self.step() {
+            return stop;
+        }
+        loop {
+            let ip = self.device.ip;
+            if self.breakpoints.get(ip).cloned().unwrap_or(false) {
+                return Stop::Breakpoint(ip);
+            }
+            if condition(ip, &self.registers) {
+                return Stop::Condition;
+            }
+            if let Some(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+}
+
+#[test]
+fn debugger_breakpoints_and_watches() {
+    let device = Device::load(
+        "#ip 5
+        seti 3 0 0
+        muli 0 2 1
+        gtri 1 0 2
+        seti 99 0 5",
+    )
+    .unwrap();
+    let mut debugger = Debugger::new(device, Registers::default());
+    debugger.break_at(2).unwrap();
+    debugger.watch(1, Access::Read).unwrap();
+    assert_eq!(debugger.run(), Stop::Breakpoint(2));
+    assert_eq!(debugger.registers[1], 6);
+    assert_eq!(
+        debugger.run(),
+        Stop::Watch {
+            ip: 2,
+            register: 1,
+            access: Access::Read
+        }
+    );
+    debugger.watch(0, Access::Write).unwrap();
+    assert_eq!(debugger.run(), Stop::Halted);
+    assert_eq!(debugger.profile(), &[1, 1, 1, 1]);
+    assert_eq!(debugger.steps(), 4);
+}
+
+#[test]
+fn debugger_trace_and_condition() {
+    let device = Device::load(
+        "#ip 5
+        seti 0 0 0
+        addi 0 1 0
+        gtri 0 2 2
+        addr 2 5 5
+        seti 0 0 5",
+    )
+    .unwrap();
+    let mut written = Vec::new();
+    {
+        let mut debugger = Debugger::new(device, Registers::default());
+        debugger.trace(|t| written.push((t.ip, t.after[t.instruction.writes()])));
+        assert_eq!(
+            debugger.run_until(|ip, r| ip == 2 && r[0] == 2),
+            Stop::Condition
+        );
+        assert_eq!(debugger.run(), Stop::Halted);
+        assert_eq!(debugger.profile(), &[1, 3, 3, 3, 2]);
+    }
+    assert_eq!(written.len(), 12);
+    assert_eq!(written[..3], [(0, 0), (1, 1), (2, 0)]);
+}
+
+#[test]
+fn debugger_rejects_invalid_points() {
+    let device = Device::load("#ip 5\nseti 3 0 0").unwrap();
+    let mut debugger = Debugger::new(device, Registers::default());
+    assert_eq!(debugger.break_at(1), Err(DebugError::NoInstruction(1)));
+    assert_eq!(
+        debugger.watch(6, Access::Read),
+        Err(DebugError::InvalidRegister(6))
+    );
+    assert_eq!(
+        debugger.unwatch(7, Access::Write),
+        Err(DebugError::InvalidRegister(7))
+    );
+    assert_eq!(debugger.breakpoints().count(), 0);
+}
+
+#[test]
+fn debugger_fused_loops() {
+    let mut device = Device::load(super::DAY21).unwrap();
+    device.optimize();
+    assert_eq!(device.fused_loop(18), Some(18..26));
+    let mut debugger = Debugger::new(device, Registers::default());
+    assert_eq!(
+        debugger.break_at(20),
+        Err(DebugError::InFusedLoop { ip: 20, head: 18 })
+    );
+    assert_eq!(
+        debugger.break_at(25),
+        Err(DebugError::InFusedLoop { ip: 25, head: 18 })
+    );
+    debugger.break_at(18).unwrap();
+    debugger.break_at(26).unwrap();
+    assert_eq!(debugger.run(), Stop::Breakpoint(18));
+    // Register 3 is only written inside the loop, which runs as one step.
+    debugger.watch(3, Access::Write).unwrap();
+    assert_eq!(
+        debugger.run(),
+        Stop::Watch {
+            ip: 18,
+            register: 3,
+            access: Access::Write
+        }
+    );
+    assert_eq!(debugger.device.ip, 26);
+    assert_eq!(debugger.profile()[18..26], [1, 0, 0, 0, 0, 0, 0, 0]);
+}
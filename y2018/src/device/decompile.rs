@@ -0,0 +1,865 @@
+//! Recovers the control flow of ElfCode programs and renders it as
+//! structured pseudo-code.
+//!
+//! Any instruction writing the ip register is a jump. Its target is known if
+//! the instruction only reads the ip register, and `addr` of the ip register
+//! and a flag set by the comparison just before it is a conditional branch
+//! over the next instruction. Everything else jumping is indirect.
+
+use std::fmt;
+
+use super::{Device, Instruction, Registers};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Condition {
+    pub lhs: String,
+    /// `==` or `>`, or their negations `!=` and `<=`.
+    pub operator: &'static str,
+    pub rhs: String,
+}
+
+impl Condition {
+    /// The condition the comparison `instr` computes.
+    fn of(instr: &Instruction, register: impl Fn(u32) -> String) -> Condition {
+        let (lhs, rhs) = instr.operands(register);
+        Condition {
+            lhs,
+            operator: instr.operator(),
+            rhs: rhs.expect("comparisons have two operands"),
+        }
+    }
+
+    pub fn negate(&self) -> Condition {
+        let operator = match self.operator {
+            "==" => "!=",
+            "!=" => "==",
+            ">" => "<=",
+            _ => ">",
+        };
+        Condition {
+            lhs: self.lhs.clone(),
+            operator,
+            rhs: self.rhs.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.lhs, self.operator, self.rhs)
+    }
+}
+
+/// How control leaves a basic block. Targets are instruction indices, and
+/// those past the end of the program halt it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Exit {
+    Jump(usize),
+    Branch {
+        condition: Condition,
+        then: usize,
+        otherwise: usize,
+    },
+    /// Continues at the instruction after the one this expression computes.
+    Indirect(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Block {
+    /// The index of the first instruction, by which the block is labelled.
+    pub start: usize,
+    /// One past the last instruction, including any jump ending the block.
+    pub end: usize,
+    /// The assignments of the block, leaving out its jump and any comparison
+    /// folded into a branch's condition.
+    pub statements: Vec<String>,
+    pub exit: Exit,
+}
+
+/// The basic blocks of a program, in order.
+#[derive(Clone, Debug)]
+pub struct Cfg {
+    pub ip_reg: Option<u32>,
+    pub len: usize,
+    pub blocks: Vec<Block>,
+}
+
+enum Flow {
+    Next,
+    Goto(usize),
+    Branch(Condition),
+    Indirect(String),
+}
+
+impl Cfg {
+    pub fn new(device: &Device) -> Cfg {
+        let instrs = &device.instructions;
+        let ip_reg = device.ip_reg;
+        let len = instrs.len();
+        let register = |at: usize| {
+            move |r: u32| {
+                if Some(r) == ip_reg {
+                    at.to_string()
+                } else {
+                    format!("r[{}]", r)
+                }
+            }
+        };
+
+        let flow = |i: usize| {
+            let instr = &instrs[i];
+            let ip = match ip_reg {
+                Some(ip) if instr.writes() == ip => ip,
+                _ => return Flow::Next,
+            };
+            let others = instr.reads().filter(|&r| r != ip).collect::<Vec<_>>();
+            if others.is_empty() {
+                let mut registers = Registers::default();
+                registers[ip] = i as u64;
+                instr.execute(&mut registers);
+                return Flow::Goto(registers[ip] as usize + 1);
+            }
+            let flag = if i > 0 && instr.name() == "addr" && others.len() == 1 {
+                Some(&instrs[i - 1]).filter(|p| p.is_comparison() && p.writes() == others[0])
+            } else {
+                None
+            };
+            match flag {
+                Some(cmp) => Flow::Branch(Condition::of(cmp, register(i - 1))),
+                None => Flow::Indirect(instr.expression(register(i))),
+            }
+        };
+        let flows = (0..len).map(flow).collect::<Vec<_>>();
+
+        // The end of the program closes the last block.
+        let mut leader = vec![false; len + 1];
+        leader[0] = true;
+        leader[len] = true;
+        for (i, flow) in flows.iter().enumerate() {
+            match *flow {
+                Flow::Next => continue,
+                Flow::Goto(t) => leader[t.min(len)] = true,
+                Flow::Branch(_) => leader[(i + 2).min(len)] = true,
+                Flow::Indirect(_) => {}
+            }
+            leader[i + 1] = true;
+        }
+
+        let mut blocks = Vec::new();
+        let mut start = 0;
+        for end in 1..=len {
+            if !leader[end] {
+                continue;
+            }
+            let last = end - 1;
+            let mut statements = Vec::new();
+            let exit = match flows[last] {
+                Flow::Next => Exit::Jump(end),
+                Flow::Goto(t) => Exit::Jump(t),
+                // The flag may have been set elsewhere if the comparison is
+                // in another block.
+                Flow::Branch(_) if last == start => {
+                    Exit::Indirect(instrs[last].expression(register(last)))
+                }
+                Flow::Branch(ref condition) => Exit::Branch {
+                    condition: condition.clone(),
+                    then: last + 2,
+                    otherwise: last + 1,
+                },
+                Flow::Indirect(ref expr) => Exit::Indirect(expr.clone()),
+            };
+            let body_end = match exit {
+                Exit::Branch { .. } => last - 1,
+                _ if Some(instrs[last].writes()) == ip_reg => last,
+                _ => end,
+            };
+            for i in start..body_end {
+                let instr = &instrs[i];
+                let target = format!("r[{}]", instr.writes());
+                // `r[1] = r[1] + 2` reads better as `r[1] += 2`.
+                statements.push(match instr.operands(register(i)) {
+                    (ref a, Some(ref b)) if *a == target && !instr.is_comparison() => {
+                        format!("{} {}= {}", target, instr.operator(), b)
+                    }
+                    _ => format!("{} = {}", target, instr.expression(register(i))),
+                });
+            }
+            blocks.push(Block {
+                start,
+                end,
+                statements,
+                exit,
+            });
+            start = end;
+        }
+        Cfg {
+            ip_reg,
+            len,
+            blocks,
+        }
+    }
+
+    fn block_at(&self, target: usize) -> Option<usize> {
+        if target >= self.len {
+            return None;
+        }
+        self.blocks.binary_search_by_key(&target, |b| b.start).ok()
+    }
+
+    /// Whether `block` does nothing but jump; the entry block never counts.
+    fn is_forwarder(&self, block: usize) -> bool {
+        match self.blocks[block].exit {
+            Exit::Jump(_) => block != 0 && self.blocks[block].statements.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// The block control reaches from `target`, skipping forwarders; `None`
+    /// if it leaves the program.
+    fn forward(&self, target: usize) -> Option<usize> {
+        let mut block = self.block_at(target)?;
+        let mut seen = vec![block];
+        while self.is_forwarder(block) {
+            let next = match self.blocks[block].exit {
+                Exit::Jump(t) => self.block_at(t)?,
+                _ => unreachable!(),
+            };
+            if seen.contains(&next) {
+                break;
+            }
+            seen.push(next);
+            block = next;
+        }
+        Some(block)
+    }
+
+    /// The blocks following `block`; `None` stands for leaving the program.
+    fn successors(&self, block: usize) -> Vec<Option<usize>> {
+        match self.blocks[block].exit {
+            Exit::Jump(t) => vec![self.forward(t)],
+            Exit::Branch {
+                then, otherwise, ..
+            } => vec![self.forward(then), self.forward(otherwise)],
+            Exit::Indirect(_) => vec![None],
+        }
+    }
+}
+
+/// For each node, which nodes dominate it, given each node's predecessors;
+/// nodes unreachable from `entry` are dominated by everything.
+fn dominators(entry: usize, preds: &[Vec<usize>]) -> Vec<Vec<bool>> {
+    let n = preds.len();
+    let mut dom = vec![vec![true; n]; n];
+    dom[entry] = (0..n).map(|i| i == entry).collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for node in (0..n).filter(|&node| node != entry) {
+            let mut new = vec![true; n];
+            for &p in &preds[node] {
+                for (d, &is) in new.iter_mut().zip(&dom[p]) {
+                    *d &= is;
+                }
+            }
+            new[node] = true;
+            if new != dom[node] {
+                dom[node] = new;
+                changed = true;
+            }
+        }
+    }
+    dom
+}
+
+struct Loop {
+    body: Vec<bool>,
+    exit: Option<usize>,
+}
+
+enum Line {
+    /// The depth, block, and whether it has statements.
+    Label(usize, usize, bool),
+    Text(usize, String),
+}
+
+struct Emitter<'a> {
+    cfg: &'a Cfg,
+    loops: Vec<Option<Loop>>,
+    /// The immediate post-dominator of each block, if it is a block.
+    joins: Vec<Option<usize>>,
+    emitted: Vec<bool>,
+    /// The headers of the loops being emitted, innermost last, with the
+    /// depth of their bodies.
+    open: Vec<(usize, usize)>,
+    gotos: Vec<bool>,
+    lines: Vec<Line>,
+}
+
+impl<'a> Emitter<'a> {
+    fn new(cfg: &'a Cfg) -> Emitter<'a> {
+        let n = cfg.blocks.len();
+        let succs = (0..n).map(|b| cfg.successors(b)).collect::<Vec<_>>();
+
+        let mut preds = vec![Vec::new(); n];
+        for (b, s) in succs.iter().enumerate() {
+            for &t in s.iter().flatten() {
+                preds[t].push(b);
+            }
+        }
+        // Blocks only reached through indirect jumps are entered from a
+        // virtual root, node `n`, as is the entry block.
+        let roots = (0..n)
+            .filter(|&b| b == 0 || (preds[b].is_empty() && !cfg.is_forwarder(b)))
+            .collect::<Vec<_>>();
+        let mut root_preds = preds.clone();
+        root_preds.push(Vec::new());
+        for &r in &roots {
+            root_preds[r].push(n);
+        }
+        let dom = dominators(n, &root_preds);
+        let mut reachable = vec![false; n];
+        let mut stack = roots;
+        while let Some(b) = stack.pop() {
+            if !reachable[b] {
+                reachable[b] = true;
+                stack.extend(succs[b].iter().flatten());
+            }
+        }
+
+        // Natural loops, merging those sharing a header.
+        let mut loops = (0..n).map(|_| None::<Loop>).collect::<Vec<_>>();
+        for (b, s) in succs.iter().enumerate() {
+            for &h in s.iter().flatten() {
+                if !reachable[b] || !dom[b][h] {
+                    continue;
+                }
+                let body = &mut loops[h]
+                    .get_or_insert_with(|| Loop {
+                        body: vec![false; n],
+                        exit: None,
+                    })
+                    .body;
+                body[h] = true;
+                let mut stack = vec![b];
+                while let Some(m) = stack.pop() {
+                    if !body[m] {
+                        body[m] = true;
+                        stack.extend(&preds[m]);
+                    }
+                }
+            }
+        }
+
+        // Post-dominators, with node `n` standing for leaving the program.
+        let mut rpreds = vec![Vec::new(); n + 1];
+        for (b, s) in succs.iter().enumerate() {
+            for t in s {
+                rpreds[b].push(t.unwrap_or(n));
+            }
+        }
+        let pdom = dominators(n, &rpreds);
+        let mut exits = vec![false; n + 1];
+        let mut stack = vec![n];
+        let mut rsuccs = vec![Vec::new(); n + 1];
+        for (b, p) in rpreds.iter().enumerate() {
+            for &t in p {
+                rsuccs[t].push(b);
+            }
+        }
+        while let Some(b) = stack.pop() {
+            if !exits[b] {
+                exits[b] = true;
+                stack.extend(&rsuccs[b]);
+            }
+        }
+        let joins = (0..n)
+            .map(|b| {
+                if !exits[b] {
+                    return None;
+                }
+                let strict = (0..=n)
+                    .filter(|&p| p != b && pdom[b][p])
+                    .collect::<Vec<_>>();
+                strict
+                    .iter()
+                    .cloned()
+                    .find(|&p| strict.iter().all(|&q| pdom[p][q]))
+                    .filter(|&p| p != n)
+            })
+            .collect::<Vec<_>>();
+
+        for h in 0..n {
+            let exit = match loops[h] {
+                Some(ref l) => joins[h].filter(|&j| !l.body[j]).or_else(|| {
+                    (0..n)
+                        .filter(|&b| l.body[b])
+                        .flat_map(|b| succs[b].iter().flatten().cloned())
+                        .filter(|&t| !l.body[t])
+                        .min()
+                }),
+                None => continue,
+            };
+            loops[h].as_mut().unwrap().exit = exit;
+        }
+
+        Emitter {
+            cfg,
+            loops,
+            joins,
+            emitted: (0..n).map(|b| cfg.is_forwarder(b)).collect(),
+            open: Vec::new(),
+            gotos: vec![false; n],
+            lines: Vec::new(),
+        }
+    }
+
+    fn line(&mut self, depth: usize, text: String) {
+        self.lines.push(Line::Text(depth, text));
+    }
+
+    /// `break` or `continue` out of the open loop at `pos`, labelled unless
+    /// it is the innermost one.
+    fn loop_control(&mut self, depth: usize, keyword: &str, pos: usize) {
+        let start = self.cfg.blocks[self.open[pos].0].start;
+        if pos + 1 == self.open.len() {
+            self.line(depth, keyword.to_string());
+        } else {
+            self.line(depth, format!("{} 'L{}", keyword, start));
+        }
+    }
+
+    /// Emits the code from `block` until it reaches `stop`.
+    fn region(&mut self, mut block: Option<usize>, stop: Option<usize>, depth: usize) {
+        while let Some(b) = block {
+            if Some(b) == stop {
+                return;
+            }
+            if let Some(pos) = self.exit_of(b) {
+                self.loop_control(depth, "break", pos);
+                return;
+            }
+            if self.emitted[b] {
+                match self.open.iter().rposition(|&(h, _)| h == b) {
+                    // Reaching the end of the loop's body continues anyway.
+                    Some(pos) if pos + 1 == self.open.len() && self.open[pos].1 == depth => {}
+                    Some(pos) => self.loop_control(depth, "continue", pos),
+                    None => {
+                        self.gotos[b] = true;
+                        let start = self.cfg.blocks[b].start;
+                        self.line(depth, format!("goto L{}", start));
+                    }
+                }
+                return;
+            }
+            if self.loops[b].is_some() && !self.open.iter().any(|&(h, _)| h == b) {
+                let start = self.cfg.blocks[b].start;
+                self.line(depth, format!("'L{}: loop {{", start));
+                self.open.push((b, depth + 1));
+                self.region(Some(b), None, depth + 1);
+                self.open.pop();
+                self.line(depth, String::from("}"));
+                block = self.loops[b].as_ref().unwrap().exit;
+                continue;
+            }
+
+            self.emitted[b] = true;
+            let cfg = self.cfg;
+            let current = &cfg.blocks[b];
+            let has_statements = !current.statements.is_empty();
+            self.lines.push(Line::Label(depth, b, has_statements));
+            for statement in &current.statements {
+                self.line(depth, statement.clone());
+            }
+            match current.exit {
+                Exit::Jump(t) => match cfg.forward(t) {
+                    Some(t) => block = Some(t),
+                    None => {
+                        self.line(depth, String::from("halt"));
+                        return;
+                    }
+                },
+                Exit::Indirect(ref expr) => {
+                    self.line(depth, format!("goto {} + 1", expr));
+                    return;
+                }
+                Exit::Branch {
+                    ref condition,
+                    then,
+                    otherwise,
+                } => {
+                    let (then, otherwise) = (cfg.forward(then), cfg.forward(otherwise));
+                    let join = self.joins[b].filter(|&j| match self.open.last() {
+                        Some(&(h, _)) => self.loops[h].as_ref().unwrap().body[j],
+                        None => true,
+                    });
+                    // Without a join, an arm that just leaves goes first and
+                    // the other follows the `if`.
+                    if join.is_none() && (self.leaves(then) || self.leaves(otherwise)) {
+                        let (condition, leaving, rest) = if self.leaves(then) {
+                            (condition.clone(), then, otherwise)
+                        } else {
+                            (condition.negate(), otherwise, then)
+                        };
+                        self.line(depth, format!("if {} {{", condition));
+                        self.arm(leaving, None, depth + 1);
+                        self.line(depth, String::from("}"));
+                        match rest {
+                            Some(_) => block = rest,
+                            None => {
+                                self.line(depth, String::from("halt"));
+                                return;
+                            }
+                        }
+                        continue;
+                    }
+                    if join.is_some() && then == join {
+                        self.line(depth, format!("if {} {{", condition.negate()));
+                        self.arm(otherwise, join, depth + 1);
+                    } else {
+                        self.line(depth, format!("if {} {{", condition));
+                        self.arm(then, join, depth + 1);
+                        if join.is_none() || otherwise != join {
+                            self.line(depth, String::from("} else {"));
+                            self.arm(otherwise, join, depth + 1);
+                        }
+                    }
+                    self.line(depth, String::from("}"));
+                    block = join;
+                }
+            }
+        }
+    }
+
+    /// The position in `open` of the loop `block` exits.
+    fn exit_of(&self, block: usize) -> Option<usize> {
+        self.open
+            .iter()
+            .rposition(|&(h, _)| self.loops[h].as_ref().unwrap().exit == Some(block))
+    }
+
+    /// Whether control going to `block` just halts, breaks or continues.
+    fn leaves(&self, block: Option<usize>) -> bool {
+        match block {
+            None => true,
+            Some(b) => {
+                self.exit_of(b).is_some()
+                    || (self.emitted[b] && self.open.iter().any(|&(h, _)| h == b))
+            }
+        }
+    }
+
+    fn arm(&mut self, block: Option<usize>, join: Option<usize>, depth: usize) {
+        match block {
+            Some(_) => self.region(block, join, depth),
+            None => self.line(depth, String::from("halt")),
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.region(Some(0), None, 0);
+        for b in 0..self.cfg.blocks.len() {
+            if !self.emitted[b] {
+                self.line(0, String::new());
+                self.gotos[b] = true;
+                self.region(Some(b), None, 0);
+            }
+        }
+
+        let mut out = match self.cfg.ip_reg {
+            Some(ip) => format!("#ip {}\n", ip),
+            None => String::new(),
+        };
+        for line in &self.lines {
+            match *line {
+                Line::Label(d, b, statements) => {
+                    if statements || self.gotos[b] {
+                        let start = self.cfg.blocks[b].start;
+                        out.push_str(&format!("{:1$}L{2}:\n", "", d * 4, start));
+                    }
+                }
+                Line::Text(d, ref text) => {
+                    if text.is_empty() {
+                        out.push('\n');
+                    } else {
+                        out.push_str(&format!("{:1$}{2}\n", "", (d + 1) * 4, text));
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Renders `device`'s program as pseudo-code: `loop`s with labelled
+/// `break`/`continue`, `if`/`else`, and `goto` where the control flow is not
+/// structured. Blocks with statements or reached by a `goto` are labelled
+/// with the index of their first instruction.
+pub fn decompile(device: &Device) -> String {
+    Emitter::new(&Cfg::new(device)).finish()
+}
+
+#[test]
+fn cfg_branches() {
+    let device = Device::load(
+        "#ip 5
+        seti 0 0 0
+        seti 10 0 1
+        addi 0 1 0
+        gtrr 0 1 2
+        addr 2 5 5
+        seti 1 0 5
+        seti 99 0 5",
+    )
+    .unwrap();
+    let cfg = Cfg::new(&device);
+    let starts = cfg.blocks.iter().map(|b| b.start).collect::<Vec<_>>();
+    assert_eq!(starts, vec![0, 2, 5, 6]);
+    assert_eq!(cfg.blocks[0].exit, Exit::Jump(2));
+    assert_eq!(cfg.blocks[1].statements, vec!["r[0] += 1"]);
+    assert_eq!(
+        cfg.blocks[1].exit,
+        Exit::Branch {
+            condition: Condition {
+                lhs: String::from("r[0]"),
+                operator: ">",
+                rhs: String::from("r[1]"),
+            },
+            then: 6,
+            otherwise: 5,
+        }
+    );
+    assert_eq!(cfg.blocks[2].exit, Exit::Jump(2));
+    assert_eq!(cfg.blocks[3].exit, Exit::Jump(100));
+}
+
+#[test]
+fn decompile_loop() {
+    let device = Device::load(
+        "#ip 5
+        seti 0 0 0
+        seti 10 0 1
+        addi 0 1 0
+        gtrr 0 1 2
+        addr 2 5 5
+        seti 1 0 5
+        seti 99 0 5",
+    )
+    .unwrap();
+    assert_eq!(
+        decompile(&device),
+        "#ip 5
+L0:
+    r[0] = 0
+    r[1] = 10
+    'L2: loop {
+    L2:
+        r[0] += 1
+        if r[0] > r[1] {
+            halt
+        }
+    }
+"
+    );
+}
+
+#[test]
+fn decompile_if_else() {
+    let device = Device::load(
+        "#ip 5
+        gtri 0 5 1
+        addr 1 5 5
+        seti 4 0 5
+        seti 1 0 2
+        seti 5 0 5
+        seti 2 0 2
+        addi 2 10 3",
+    )
+    .unwrap();
+    assert_eq!(
+        decompile(&device),
+        "#ip 5
+    if r[0] > 5 {
+    L3:
+        r[2] = 1
+    } else {
+    L5:
+        r[2] = 2
+    }
+L6:
+    r[3] = r[2] + 10
+    halt
+"
+    );
+}
+
+#[test]
+fn decompile_nested_loops() {
+    let device = Device::load(
+        "#ip 5
+        seti 0 0 0
+        seti 0 0 1
+        addi 1 1 1
+        mulr 0 1 2
+        eqri 2 6 3
+        addr 3 5 5
+        seti 7 0 5
+        seti 12 0 5
+        gtri 1 3 3
+        addr 3 5 5
+        seti 1 0 5
+        addi 0 1 0
+        seti 0 0 5
+        addi 2 100 4",
+    )
+    .unwrap();
+    assert_eq!(
+        decompile(&device),
+        "#ip 5
+L0:
+    r[0] = 0
+    'L1: loop {
+    L1:
+        r[1] = 0
+        'L2: loop {
+        L2:
+            r[1] += 1
+            r[2] = r[0] * r[1]
+            if r[2] == 6 {
+                break
+            }
+            if r[1] > 3 {
+            L11:
+                r[0] += 1
+                continue 'L1
+            }
+        }
+        break
+    }
+L13:
+    r[4] = r[2] + 100
+    halt
+"
+    );
+}
+
+#[test]
+fn decompile_day19() {
+    let device = Device::load(super::DAY19).unwrap();
+    assert_eq!(
+        decompile(&device),
+        "#ip 1
+L17:
+    r[3] += 2
+    r[3] *= r[3]
+    r[3] = 19 * r[3]
+    r[3] *= 11
+    r[5] += 7
+    r[5] *= 22
+    r[5] += 18
+    r[3] += r[5]
+    goto 25 + r[0] + 1
+
+L1:
+    r[4] = 1
+    'L2: loop {
+    L2:
+        r[2] = 1
+        'L3: loop {
+        L3:
+            r[5] = r[4] * r[2]
+            if r[5] == r[3] {
+            L7:
+                r[0] = r[4] + r[0]
+            }
+        L8:
+            r[2] += 1
+            if r[2] > r[3] {
+                break
+            }
+        }
+    L12:
+        r[4] += 1
+        if r[4] > r[3] {
+            halt
+        }
+    }
+
+L27:
+    r[5] = 27
+    r[5] *= 28
+    r[5] = 29 + r[5]
+    r[5] = 30 * r[5]
+    r[5] *= 14
+    r[5] *= 32
+    r[3] += r[5]
+    r[0] = 0
+    goto L1
+"
+    );
+}
+
+#[test]
+fn decompile_day21() {
+    let device = Device::load(super::DAY21).unwrap();
+    let cfg = Cfg::new(&device);
+    let exits = cfg.blocks.iter().map(|b| &b.exit);
+    let conditions = exits
+        .filter_map(|exit| match *exit {
+            Exit::Branch { ref condition, .. } => Some(condition.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        conditions,
+        ["r[5] == 72", "256 > r[4]", "r[3] > r[4]", "r[5] == r[0]"]
+    );
+    assert_eq!(
+        decompile(&device),
+        "#ip 1
+L0:
+    r[5] = 123
+    'L1: loop {
+    L1:
+        r[5] &= 456
+        if r[5] == 72 {
+            break
+        }
+    }
+L5:
+    r[5] = 0
+    'L6: loop {
+    L6:
+        r[4] = r[5] | 65536
+        r[5] = 3935295
+        'L8: loop {
+        L8:
+            r[2] = r[4] & 255
+            r[5] += r[2]
+            r[5] &= 16777215
+            r[5] *= 65899
+            r[5] &= 16777215
+            if 256 > r[4] {
+                break
+            }
+        L17:
+            r[2] = 0
+            'L18: loop {
+            L18:
+                r[3] = r[2] + 1
+                r[3] *= 256
+                if r[3] > r[4] {
+                    break
+                }
+            L24:
+                r[2] += 1
+            }
+        L26:
+            r[4] = r[2]
+        }
+        if r[5] == r[0] {
+            halt
+        }
+    }
+"
+    );
+}
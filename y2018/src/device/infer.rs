@@ -0,0 +1,283 @@
+//! Works out which instruction each opcode number stands for from samples of
+//! what it does to the registers, as day 16 does.
+
+use failure::Fail;
+
+use super::{Device, Instruction, Kind, RawOp, Registers, INSTRUCTIONS, REGISTERS};
+
+/// One instruction, with its opcode number in `op.code`, and the registers
+/// before and after it ran.
+#[derive(Copy, Clone, Debug)]
+pub struct Sample {
+    pub before: Registers,
+    pub op: RawOp,
+    pub after: Registers,
+}
+
+impl Sample {
+    /// The instructions that could have run, by bit in `INSTRUCTIONS`.
+    fn mask(&self) -> u16 {
+        let mut mask = 0;
+        for (i, kind) in INSTRUCTIONS.iter().enumerate() {
+            if invalid_register(kind, &self.op).is_some() {
+                continue;
+            }
+            let mut registers = self.before;
+            (kind.op)(
+                RawOp {
+                    code: i as u32,
+                    ..self.op
+                },
+                &mut registers,
+            );
+            if registers == self.after {
+                mask |= 1 << i;
+            }
+        }
+        mask
+    }
+
+    /// The names of the instructions that could have run.
+    pub fn candidates(&self) -> Vec<&'static str> {
+        names(self.mask())
+    }
+}
+
+/// The first operand of `raw` that `kind` reads or writes as a register but
+/// is not one.
+fn invalid_register(kind: &Kind, raw: &RawOp) -> Option<u32> {
+    let mut registers = Some(raw.a)
+        .filter(|_| kind.a == super::Reg)
+        .into_iter()
+        .chain(Some(raw.b).filter(|_| kind.b == super::Reg))
+        .chain(Some(raw.c));
+    registers.find(|&r| r as usize >= REGISTERS)
+}
+
+fn names(mask: u16) -> Vec<&'static str> {
+    INSTRUCTIONS
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| mask & 1 << i != 0)
+        .map(|(_, kind)| kind.name)
+        .collect()
+}
+
+#[derive(Fail, Debug, PartialEq, Eq)]
+pub enum InferError {
+    #[fail(display = "opcode {} is not below 16", _0)]
+    InvalidOpcode(u32),
+    #[fail(display = "instruction {} names register {}", _0, _1)]
+    InvalidRegister(usize, u32),
+    #[fail(
+        display = "no instruction is left for opcode {} after sample {}",
+        code, sample
+    )]
+    Contradiction { code: u32, sample: usize },
+    #[fail(display = "no instruction is left for opcode {}", _0)]
+    Conflict(u32),
+    #[fail(display = "opcode {} could be any of {:?}", code, candidates)]
+    Ambiguous {
+        code: u32,
+        candidates: Vec<&'static str>,
+    },
+}
+
+/// The instruction each opcode number stands for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Mapping {
+    codes: [usize; 16],
+}
+
+impl Mapping {
+    pub fn name(&self, code: u32) -> &'static str {
+        INSTRUCTIONS[self.codes[code as usize]].name
+    }
+
+    /// A device running `program`, whose instructions have opcode numbers,
+    /// with no register bound to the ip.
+    pub fn device(&self, program: &[RawOp]) -> Result<Device, InferError> {
+        let mut instructions = Vec::new();
+        for (i, raw) in program.iter().enumerate() {
+            let code = *self
+                .codes
+                .get(raw.code as usize)
+                .ok_or(InferError::InvalidOpcode(raw.code))?;
+            let kind = &INSTRUCTIONS[code];
+            if let Some(r) = invalid_register(kind, raw) {
+                return Err(InferError::InvalidRegister(i, r));
+            }
+            instructions.push(Instruction {
+                op: kind.op,
+                inputs: RawOp {
+                    code: code as u32,
+                    ..*raw
+                },
+            });
+        }
+        Ok(Device {
+            ip: 0,
+            ip_reg: None,
+            instructions,
+            fused: Vec::new(),
+        })
+    }
+}
+
+/// Narrows each opcode down to the instructions consistent with all of its
+/// samples, then repeatedly assigns opcodes left with a single instruction,
+/// and instructions left to a single opcode, until every opcode is known.
+pub fn infer(samples: &[Sample]) -> Result<Mapping, InferError> {
+    let mut masks = [!0u16; 16];
+    for (i, sample) in samples.iter().enumerate() {
+        let code = sample.op.code;
+        let mask = masks
+            .get_mut(code as usize)
+            .ok_or(InferError::InvalidOpcode(code))?;
+        *mask &= sample.mask();
+        if *mask == 0 {
+            return Err(InferError::Contradiction { code, sample: i });
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for code in 0..16 {
+            let mask = masks[code];
+            if mask == 0 {
+                return Err(InferError::Conflict(code as u32));
+            }
+            let only = if mask.count_ones() == 1 {
+                Some(mask)
+            } else {
+                // An instruction no other opcode can be.
+                let others = (0..16)
+                    .filter(|&c| c != code)
+                    .fold(0, |acc, c| acc | masks[c]);
+                Some(mask & !others).filter(|m| m.count_ones() == 1)
+            };
+            if let Some(only) = only {
+                for (c, other) in masks.iter_mut().enumerate() {
+                    if c != code && *other & only != 0 {
+                        *other &= !only;
+                        changed = true;
+                    }
+                }
+                if masks[code] != only {
+                    masks[code] = only;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    let mut codes = [0; 16];
+    for (code, &mask) in masks.iter().enumerate() {
+        if mask.count_ones() != 1 {
+            return Err(InferError::Ambiguous {
+                code: code as u32,
+                candidates: names(mask),
+            });
+        }
+        codes[code] = mask.trailing_zeros() as usize;
+    }
+    Ok(Mapping { codes })
+}
+
+#[cfg(test)]
+fn sample(before: [u64; 4], op: [u32; 4], after: [u64; 4]) -> Sample {
+    Sample {
+        before: Registers::from(before.iter().cloned()),
+        op: RawOp {
+            code: op[0],
+            a: op[1],
+            b: op[2],
+            c: op[3],
+        },
+        after: Registers::from(after.iter().cloned()),
+    }
+}
+
+#[test]
+fn infer_candidates() {
+    let s = sample([3, 2, 1, 1], [9, 2, 1, 2], [3, 2, 2, 1]);
+    assert_eq!(s.candidates(), vec!["addi", "mulr", "seti"]);
+}
+
+#[test]
+fn infer_mapping() {
+    // Opcode `n` runs instruction `15 - n`; run each on a few register sets.
+    let mut state = 7u64;
+    let mut random = |max: u64| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (state >> 33) % max
+    };
+    let mut samples = Vec::new();
+    for code in 0..16u32 {
+        let kind = &INSTRUCTIONS[15 - code as usize];
+        for _ in 0..10 {
+            let before = Registers::from((0..4).map(|_| random(6)));
+            let mut after = before;
+            let raw = RawOp {
+                code,
+                a: random(4) as u32,
+                b: random(4) as u32,
+                c: random(4) as u32,
+            };
+            (kind.op)(raw, &mut after);
+            samples.push(Sample {
+                before,
+                op: raw,
+                after,
+            });
+        }
+    }
+    let mapping = infer(&samples).unwrap();
+    for code in 0..16 {
+        assert_eq!(mapping.name(code), INSTRUCTIONS[15 - code as usize].name);
+    }
+
+    let device = mapping
+        .device(&[RawOp {
+            code: 5,
+            a: 7,
+            b: 0,
+            c: 0,
+        }])
+        .unwrap();
+    assert_eq!(device.instructions[0].name(), "seti");
+    let bad = RawOp {
+        code: 5,
+        a: 0,
+        b: 0,
+        c: 6,
+    };
+    assert_eq!(
+        mapping.device(&[bad]).unwrap_err(),
+        InferError::InvalidRegister(0, 6)
+    );
+}
+
+#[test]
+fn infer_contradiction() {
+    let samples = [
+        sample([3, 2, 1, 1], [9, 2, 1, 2], [3, 2, 2, 1]),
+        sample([0, 0, 0, 0], [9, 0, 0, 0], [5, 0, 0, 0]),
+    ];
+    assert_eq!(
+        infer(&samples),
+        Err(InferError::Contradiction { code: 9, sample: 1 })
+    );
+    assert_eq!(
+        infer(&[sample([0; 4], [16, 0, 0, 0], [0; 4])]),
+        Err(InferError::InvalidOpcode(16))
+    );
+    assert_eq!(
+        infer(&[]),
+        Err(InferError::Ambiguous {
+            code: 0,
+            candidates: names(!0),
+        })
+    );
+}
@@ -1,19 +1,19 @@
 use super::{RawOp, Registers};
 
 pub fn addr(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = regs[raw.a] + regs[raw.b];
+    regs[raw.c] = regs[raw.a].wrapping_add(regs[raw.b]);
 }
 
 pub fn addi(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = regs[raw.a] + raw.b;
+    regs[raw.c] = regs[raw.a].wrapping_add(u64::from(raw.b));
 }
 
 pub fn mulr(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = regs[raw.a] * regs[raw.b];
+    regs[raw.c] = regs[raw.a].wrapping_mul(regs[raw.b]);
 }
 
 pub fn muli(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = regs[raw.a] * raw.b;
+    regs[raw.c] = regs[raw.a].wrapping_mul(u64::from(raw.b));
 }
 
 pub fn banr(raw: RawOp, regs: &mut Registers) {
@@ -21,7 +21,7 @@ pub fn banr(raw: RawOp, regs: &mut Registers) {
 }
 
 pub fn bani(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = regs[raw.a] & raw.b;
+    regs[raw.c] = regs[raw.a] & u64::from(raw.b);
 }
 
 pub fn borr(raw: RawOp, regs: &mut Registers) {
@@ -29,7 +29,7 @@ pub fn borr(raw: RawOp, regs: &mut Registers) {
 }
 
 pub fn bori(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = regs[raw.a] | raw.b;
+    regs[raw.c] = regs[raw.a] | u64::from(raw.b);
 }
 
 pub fn setr(raw: RawOp, regs: &mut Registers) {
@@ -37,29 +37,29 @@ pub fn setr(raw: RawOp, regs: &mut Registers) {
 }
 
 pub fn seti(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = raw.a;
+    regs[raw.c] = u64::from(raw.a);
 }
 
 pub fn gtir(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = (raw.a > regs[raw.b]) as u32;
+    regs[raw.c] = (u64::from(raw.a) > regs[raw.b]) as u64;
 }
 
 pub fn gtri(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = (regs[raw.a] > raw.b) as u32;
+    regs[raw.c] = (regs[raw.a] > u64::from(raw.b)) as u64;
 }
 
 pub fn gtrr(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = (regs[raw.a] > regs[raw.b]) as u32;
+    regs[raw.c] = (regs[raw.a] > regs[raw.b]) as u64;
 }
 
 pub fn eqir(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = (raw.a == regs[raw.b]) as u32;
+    regs[raw.c] = (u64::from(raw.a) == regs[raw.b]) as u64;
 }
 
 pub fn eqri(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = (regs[raw.a] == raw.b) as u32;
+    regs[raw.c] = (regs[raw.a] == u64::from(raw.b)) as u64;
 }
 
 pub fn eqrr(raw: RawOp, regs: &mut Registers) {
-    regs[raw.c] = (regs[raw.a] == regs[raw.b]) as u32;
+    regs[raw.c] = (regs[raw.a] == regs[raw.b]) as u64;
 }
@@ -0,0 +1,422 @@
+//! Recognizes loops that ElfCode programs spend nearly all their time in and
+//! replaces each with a super-instruction computing its result directly.
+//!
+//! A fused loop runs as one step of the instruction at its head when the
+//! device reaches it; jumps into the middle of the loop still run it
+//! instruction by instruction. Fused loops give up, leaving the loop to run
+//! normally, whenever the interpreter would wrap around, so the result is
+//! always the interpreter's.
+
+use std::cmp;
+
+use super::{Device, Instruction, Registers};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Fused {
+    /// `q = max(q, n / k)`: counts `q` up until `(q + 1) * k > n`, as day 21
+    /// divides by 256.
+    Divide {
+        q: u32,
+        t: u32,
+        n: u32,
+        k: u64,
+        exit: usize,
+    },
+    /// Adds `a` to `acc` if `a * b == n` for a `b` from `b` up to `n`.
+    MulCompare {
+        a: u32,
+        b: u32,
+        t: u32,
+        n: u32,
+        acc: u32,
+        exit: usize,
+    },
+    /// Runs `MulCompare` from `b = 1` for each `a` from `a` up to `n`,
+    /// adding the divisors of `n` from `a` up to `acc`, as day 19 does.
+    DivisorSum {
+        a: u32,
+        b: u32,
+        t: u32,
+        n: u32,
+        acc: u32,
+        exit: usize,
+    },
+}
+
+impl Fused {
+    /// The ip the loop continues at; the loop is the instructions from its
+    /// head up to this.
+    pub fn exit(&self) -> usize {
+        match *self {
+            Fused::Divide { exit, .. }
+            | Fused::MulCompare { exit, .. }
+            | Fused::DivisorSum { exit, .. } => exit,
+        }
+    }
+
+    /// Runs the loop to its end and returns the ip it continues at, or
+    /// `None`, changing nothing, if the interpreter would wrap around.
+    pub fn execute(&self, ip_reg: u32, r: &mut Registers) -> Option<usize> {
+        let exit = match *self {
+            Fused::Divide { q, t, n, k, exit } => {
+                let quotient = cmp::max(r[q], r[n] / k);
+                quotient.checked_add(1)?.checked_mul(k)?;
+                r[q] = quotient;
+                r[t] = 1;
+                exit
+            }
+            Fused::MulCompare {
+                a,
+                b,
+                t,
+                n,
+                acc,
+                exit,
+            } => {
+                let last = cmp::max(r[b], r[n]);
+                last.checked_add(1)?;
+                r[a].checked_mul(last)?;
+                if r[a] != 0 && r[n] % r[a] == 0 && r[b] <= r[n] / r[a] && r[n] / r[a] <= last {
+                    r[acc] = r[acc].wrapping_add(r[a]);
+                }
+                r[b] = last + 1;
+                r[t] = 1;
+                exit
+            }
+            Fused::DivisorSum {
+                a,
+                b,
+                t,
+                n,
+                acc,
+                exit,
+            } => {
+                let last_a = cmp::max(r[a], r[n]);
+                let last_b = cmp::max(1, r[n]);
+                last_a.checked_add(1)?;
+                last_b.checked_add(1)?;
+                last_a.checked_mul(last_b)?;
+                let sum = divisors(r[n])
+                    .filter(|&d| d >= r[a])
+                    .fold(0u64, |sum, d| sum.wrapping_add(d));
+                r[acc] = r[acc].wrapping_add(sum);
+                r[a] = last_a + 1;
+                r[b] = last_b + 1;
+                r[t] = 1;
+                exit
+            }
+        };
+        r[ip_reg] = exit as u64 - 1;
+        Some(exit)
+    }
+}
+
+fn divisors(n: u64) -> impl Iterator<Item = u64> {
+    (1..)
+        .take_while(move |&i| i <= n / i)
+        .filter(move |&i| n % i == 0)
+        .flat_map(move |i| {
+            let pair = Some(n / i).filter(|&j| j != i);
+            Some(i).into_iter().chain(pair)
+        })
+}
+
+/// The super-instruction for each instruction that heads a loop it
+/// recognizes.
+pub fn fuse(device: &Device) -> Vec<Option<Fused>> {
+    let instrs = &device.instructions;
+    let ip = match device.ip_reg {
+        Some(ip) => ip,
+        None => return vec![None; instrs.len()],
+    };
+    (0..instrs.len())
+        .map(|at| {
+            divisor_sum(instrs, ip, at)
+                .or_else(|| mul_compare(instrs, ip, at))
+                .or_else(|| divide(instrs, ip, at))
+        })
+        .collect()
+}
+
+/// The operands of `instr` if it is a `name`.
+fn op(instr: &Instruction, name: &str) -> Option<(u32, u32, u32)> {
+    let raw = instr.raw();
+    Some((raw.a, raw.b, raw.c)).filter(|_| instr.name() == name)
+}
+
+/// Whether `instr` is a `name` of `a` and `b`, in either order, into `c`.
+fn either(instr: &Instruction, name: &str, a: u32, b: u32, c: u32) -> bool {
+    op(instr, name) == Some((a, b, c)) || op(instr, name) == Some((b, a, c))
+}
+
+/// Whether `instr` jumps to the instruction after `target`.
+fn jumps(instr: &Instruction, ip: u32, target: usize) -> bool {
+    op(instr, "seti").map_or(false, |(a, _, c)| c == ip && a as usize == target)
+}
+
+fn distinct(registers: &[u32]) -> bool {
+    registers
+        .iter()
+        .enumerate()
+        .all(|(i, r)| !registers[..i].contains(r))
+}
+
+/// ```text
+/// L:   addi q 1 t
+///      muli t K t
+///      gtrr t n t
+///      addr t ip ip
+///      addi ip 1 ip
+///      seti X _ ip
+///      addi q 1 q
+///      seti L-1 _ ip
+/// ```
+fn divide(instrs: &[Instruction], ip: u32, at: usize) -> Option<Fused> {
+    let w = instrs.get(at..at + 8)?;
+    let (q, one, t) = op(&w[0], "addi")?;
+    let (_, k, _) = op(&w[1], "muli").filter(|&(x, k, y)| x == t && y == t && k > 0)?;
+    let (_, n, _) = op(&w[2], "gtrr").filter(|&(x, _, y)| x == t && y == t)?;
+    let exit = op(&w[5], "seti").filter(|&(_, _, c)| c == ip)?.0 as usize + 1;
+    let matches = one == 1
+        && either(&w[3], "addr", t, ip, ip)
+        && op(&w[4], "addi") == Some((ip, 1, ip))
+        && op(&w[6], "addi") == Some((q, 1, q))
+        && jumps(&w[7], ip, at.checked_sub(1)?)
+        && distinct(&[q, t, n, ip]);
+    Some(Fused::Divide {
+        q,
+        t,
+        n,
+        k: u64::from(k),
+        exit,
+    })
+    .filter(|_| matches)
+}
+
+/// ```text
+/// L:   mulr a b t
+///      eqrr t n t
+///      addr t ip ip
+///      addi ip 1 ip
+///      addr a acc acc
+///      addi b 1 b
+///      gtrr b n t
+///      addr t ip ip
+///      seti L-1 _ ip
+/// ```
+fn mul_compare(instrs: &[Instruction], ip: u32, at: usize) -> Option<Fused> {
+    let w = instrs.get(at..at + 9)?;
+    let (x, y, t) = op(&w[0], "mulr")?;
+    let (_, n, _) = op(&w[6], "gtrr")?;
+    let (b, _, _) = op(&w[5], "addi")?;
+    let a = if b == x { y } else { x };
+    let (_, _, acc) = op(&w[4], "addr")?;
+    let matches = (b == x || b == y)
+        && either(&w[1], "eqrr", t, n, t)
+        && either(&w[2], "addr", t, ip, ip)
+        && op(&w[3], "addi") == Some((ip, 1, ip))
+        && either(&w[4], "addr", a, acc, acc)
+        && op(&w[5], "addi") == Some((b, 1, b))
+        && op(&w[6], "gtrr") == Some((b, n, t))
+        && either(&w[7], "addr", t, ip, ip)
+        && jumps(&w[8], ip, at.checked_sub(1)?)
+        && distinct(&[a, b, t, n, acc, ip]);
+    Some(Fused::MulCompare {
+        a,
+        b,
+        t,
+        n,
+        acc,
+        exit: at + 9,
+    })
+    .filter(|_| matches)
+}
+
+/// ```text
+/// L:   seti 1 _ b
+///      <`mul_compare`>
+///      addi a 1 a
+///      gtrr a n t
+///      addr t ip ip
+///      seti L-1 _ ip
+/// ```
+fn divisor_sum(instrs: &[Instruction], ip: u32, at: usize) -> Option<Fused> {
+    let w = instrs.get(at..at + 14)?;
+    let (a, b, t, n, acc) = match mul_compare(instrs, ip, at + 1)? {
+        Fused::MulCompare {
+            a, b, t, n, acc, ..
+        } => (a, b, t, n, acc),
+        _ => unreachable!(),
+    };
+    let matches = op(&w[0], "seti").map_or(false, |(one, _, c)| one == 1 && c == b)
+        && op(&w[10], "addi") == Some((a, 1, a))
+        && op(&w[11], "gtrr") == Some((a, n, t))
+        && either(&w[12], "addr", t, ip, ip)
+        && jumps(&w[13], ip, at.checked_sub(1)?);
+    Some(Fused::DivisorSum {
+        a,
+        b,
+        t,
+        n,
+        acc,
+        exit: at + 14,
+    })
+    .filter(|_| matches)
+}
+
+/// The loops of day 19, from 2, followed by that of day 21, which starts at
+/// 17 here but at 18 in day 21.
+#[cfg(test)]
+const LOOPS: &str = "#ip 1
+addi 1 16 1
+seti 1 4 4
+seti 1 1 2
+mulr 4 2 5
+eqrr 5 3 5
+addr 5 1 1
+addi 1 1 1
+addr 4 0 0
+addi 2 1 2
+gtrr 2 3 5
+addr 1 5 1
+seti 2 4 1
+addi 4 1 4
+gtrr 4 3 5
+addr 5 1 1
+seti 1 1 1
+mulr 1 1 1
+addi 2 1 3
+muli 3 256 3
+gtrr 3 4 3
+addr 3 1 1
+addi 1 1 1
+seti 24 0 1
+addi 2 1 2
+seti 16 7 1
+setr 2 2 4";
+
+#[test]
+fn fuse_finds_loops() {
+    let device = Device::load(LOOPS).unwrap();
+    let fused = fuse(&device)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(at, f)| f.map(|f| (at, f)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fused,
+        vec![
+            (
+                2,
+                Fused::DivisorSum {
+                    a: 4,
+                    b: 2,
+                    t: 5,
+                    n: 3,
+                    acc: 0,
+                    exit: 16
+                }
+            ),
+            (
+                3,
+                Fused::MulCompare {
+                    a: 4,
+                    b: 2,
+                    t: 5,
+                    n: 3,
+                    acc: 0,
+                    exit: 12
+                }
+            ),
+            (
+                17,
+                Fused::Divide {
+                    q: 2,
+                    t: 3,
+                    n: 4,
+                    k: 256,
+                    exit: 25
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn fuse_finds_real_loops() {
+    let heads = |program| {
+        let device = Device::load(program).unwrap();
+        fuse(&device)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(at, f)| f.map(|f| (at, f)))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        heads(super::DAY19)
+            .iter()
+            .map(|&(at, f)| (at, f.exit()))
+            .collect::<Vec<_>>(),
+        vec![(2, 16), (3, 12)]
+    );
+    assert_eq!(
+        heads(super::DAY21),
+        vec![(
+            18,
+            Fused::Divide {
+                q: 2,
+                t: 3,
+                n: 4,
+                k: 256,
+                exit: 26
+            }
+        )]
+    );
+}
+
+#[test]
+fn fused_matches_interpreter() {
+    let device = Device::load(LOOPS).unwrap();
+    let fused = fuse(&device);
+    // xorshift, to avoid a dependency on rand.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = |max: u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % max
+    };
+    for &(at, end) in &[(2, 16), (3, 12), (17, 25)] {
+        for _ in 0..100 {
+            let mut registers = Registers::default();
+            for r in 0..6 {
+                registers[r] = random(60);
+            }
+            registers[0] = random(u64::max_value());
+            registers[3] = random(100);
+            registers[4] = if at == 17 { random(5000) } else { random(60) };
+
+            let mut interpreted = registers;
+            let mut naive = device.clone();
+            naive.ip = at;
+            while naive.ip >= at && naive.ip < end && naive.step(&mut interpreted) {}
+
+            let ip = fused[at].unwrap().execute(1, &mut registers);
+            assert_eq!(ip, Some(naive.ip));
+            assert_eq!(registers, interpreted, "from {} at {}", at, naive.ip);
+        }
+    }
+}
+
+#[test]
+fn fused_gives_up_on_overflow() {
+    let device = Device::load(LOOPS).unwrap();
+    let fused = fuse(&device);
+    let mut registers = Registers::default();
+    registers[3] = u64::max_value();
+    let before = registers;
+    for &at in &[2, 3] {
+        assert_eq!(fused[at].unwrap().execute(1, &mut registers), None);
+        assert_eq!(registers, before);
+    }
+}
@@ -1 +1,18 @@
+#![feature(try_from)]
+
+// The days refer to this crate by name, as they do when built as binaries.
+extern crate self as y2018;
+
 pub mod device;
+
+/// Every day, for the `aoc` runner; each is also built as its own binary.
+///
+/// Day 10 is left out as it has no solution.
+#[cfg(not(test))]
+#[path = "bin"]
+pub mod days {
+    aoc_macro::days!(
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day11, day12, day13, day14,
+        day15, day16, day17, day18, day19, day20, day21, day22
+    );
+}